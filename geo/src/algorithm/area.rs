@@ -1,5 +1,8 @@
 use num_traits::Float;
-use {Bbox, Line, LineString, MultiPolygon, Polygon, Triangle};
+use {
+    Bbox, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Triangle,
+};
 
 use algorithm::winding_order::twice_signed_ring_area;
 
@@ -41,6 +44,15 @@ where
     twice_signed_ring_area(linestring) / (T::one() + T::one())
 }
 
+impl<T> Area<T> for Point<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+
 impl<T> Area<T> for Line<T>
 where
     T: Float,
@@ -50,6 +62,33 @@ where
     }
 }
 
+impl<T> Area<T> for LineString<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+
+impl<T> Area<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+
+impl<T> Area<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+
 impl<T> Area<T> for Polygon<T>
 where
     T: Float,
@@ -74,6 +113,35 @@ where
     }
 }
 
+impl<T> Area<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.area())
+    }
+}
+
+impl<T> Area<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn area(&self) -> T {
+        match *self {
+            Geometry::Point(ref g) => g.area(),
+            Geometry::Line(ref g) => g.area(),
+            Geometry::LineString(ref g) => g.area(),
+            Geometry::Polygon(ref g) => g.area(),
+            Geometry::MultiPoint(ref g) => g.area(),
+            Geometry::MultiLineString(ref g) => g.area(),
+            Geometry::MultiPolygon(ref g) => g.area(),
+            Geometry::GeometryCollection(ref g) => g.area(),
+        }
+    }
+}
+
 impl<T> Area<T> for Bbox<T>
 where
     T: Float,
@@ -97,7 +165,10 @@ where
 #[cfg(test)]
 mod test {
     use algorithm::area::Area;
-    use {Bbox, Coordinate, Line, LineString, MultiPolygon, Polygon, Triangle};
+    use {
+        Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiPolygon, Point,
+        Polygon, Triangle,
+    };
 
     // Area of the polygon
     #[test]
@@ -159,6 +230,23 @@ mod test {
         assert_eq!(line1.area(), 0.);
     }
 
+    #[test]
+    fn area_geometry_collection_test() {
+        let square = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]),
+            Vec::new(),
+        );
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(5., 5.)),
+            Geometry::LineString(LineString::from(vec![(0., 0.), (1., 1.), (1., 0.)])),
+            Geometry::Polygon(square.clone()),
+            Geometry::MultiPolygon(MultiPolygon(vec![square])),
+        ]);
+        assert_eq!(gc.area(), 8.);
+        assert_eq!(Geometry::GeometryCollection(gc).area(), 8.);
+        assert_eq!(GeometryCollection::<f64>(vec![]).area(), 0.);
+    }
+
     #[test]
     fn area_triangle_test() {
         let triangle = Triangle(
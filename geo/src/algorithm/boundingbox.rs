@@ -1,6 +1,6 @@
 use {
-    Bbox, Coordinate, CoordinateType, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Polygon, Triangle,
+    Bbox, Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Triangle,
 };
 
 /// Calculation of the bounding box of a geometry.
@@ -69,6 +69,22 @@ where
     None
 }
 
+impl<T> BoundingBox<T> for Point<T>
+where
+    T: CoordinateType,
+{
+    type Output = Bbox<T>;
+
+    fn bbox(&self) -> Self::Output {
+        Bbox {
+            xmin: self.x(),
+            xmax: self.x(),
+            ymin: self.y(),
+            ymax: self.y(),
+        }
+    }
+}
+
 impl<T> BoundingBox<T> for MultiPoint<T>
 where
     T: CoordinateType,
@@ -175,14 +191,67 @@ where
     }
 }
 
+impl<T> BoundingBox<T> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Bbox<T>>;
+
+    ///
+    /// Return the BoundingBox for a GeometryCollection
+    ///
+    fn bbox(&self) -> Self::Output {
+        self.0
+            .iter()
+            .filter_map(|geometry| geometry.bbox())
+            .fold(None, |total, bbox| match total {
+                Some(total) => Some(total + bbox),
+                None => Some(bbox),
+            })
+    }
+}
+
+impl<T> BoundingBox<T> for Geometry<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Bbox<T>>;
+
+    fn bbox(&self) -> Self::Output {
+        match *self {
+            Geometry::Point(ref g) => Some(g.bbox()),
+            Geometry::Line(ref g) => Some(g.bbox()),
+            Geometry::LineString(ref g) => g.bbox(),
+            Geometry::Polygon(ref g) => g.bbox(),
+            Geometry::MultiPoint(ref g) => g.bbox(),
+            Geometry::MultiLineString(ref g) => g.bbox(),
+            Geometry::MultiPolygon(ref g) => g.bbox(),
+            Geometry::GeometryCollection(ref g) => g.bbox(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use algorithm::boundingbox::BoundingBox;
     use {
-        Bbox, Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-        Polygon,
+        Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Point, Polygon,
     };
 
+    #[test]
+    fn point_test() {
+        let bbox = Point::new(1., 2.).bbox();
+        assert_eq!(
+            bbox,
+            Bbox {
+                xmin: 1.,
+                xmax: 1.,
+                ymin: 2.,
+                ymax: 2.,
+            }
+        );
+    }
     #[test]
     fn empty_linestring_test() {
         let linestring: LineString<f32> = LineString(vec![]);
@@ -294,4 +363,28 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn geometry_collection_test() {
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(-3., 5.)),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+            Geometry::Line(Line::from([(0., 1.), (2., 3.)])),
+            Geometry::LineString(LineString::from(vec![(1., -2.), (1., 0.)])),
+        ]);
+        let bbox = Bbox {
+            xmin: -3.,
+            xmax: 2.,
+            ymin: -2.,
+            ymax: 5.,
+        };
+        assert_eq!(gc.bbox(), Some(bbox));
+        assert_eq!(Geometry::GeometryCollection(gc).bbox(), Some(bbox));
+        assert_eq!(
+            Geometry::Point(Point::new(1., 2.)).bbox(),
+            Some(Point::new(1., 2.).bbox())
+        );
+        assert_eq!(GeometryCollection::<f64>(vec![]).bbox(), None);
+        assert_eq!(Geometry::LineString(LineString::<f64>(vec![])).bbox(), None);
+    }
 }
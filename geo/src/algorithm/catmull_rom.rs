@@ -0,0 +1,217 @@
+use num_traits::Float;
+use {Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Interpolates a smooth curve through the vertices of a geometry, using a Catmull-Rom spline.
+///
+/// Unlike `ChaikinSmoothing`, the curve passes through every original vertex: each segment is
+/// replaced by a cubic curve whose direction at a vertex is that of the line between its
+/// neighbours. The curve is continued straight past the ends of an open `LineString`, and
+/// wraps around closed rings, such as those of a `Polygon`, so that they stay smooth where
+/// they close.
+///
+/// Multi* objects are interpolated by interpolating all their constituent geometries
+/// individually.
+pub trait CatmullRom<T>
+where
+    T: Float,
+{
+    /// Returns the geometry with each segment split into `samples_per_segment` pieces along
+    /// a uniform Catmull-Rom spline.
+    ///
+    /// The original vertices are kept exactly, with `samples_per_segment - 1` new vertices
+    /// between each pair. A lone segment has no neighbours to bend it, so it's sampled along
+    /// the straight line. The geometry is returned unchanged if `samples_per_segment` is 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::catmull_rom::CatmullRom;
+    ///
+    /// let ls = LineString::from(vec![(0., 0.), (2., 2.), (4., 0.)]);
+    ///
+    /// let curve = ls.catmull_rom(2);
+    /// assert_eq!(
+    ///     curve,
+    ///     LineString::from(vec![(0., 0.), (1., 1.25), (2., 2.), (3., 1.25), (4., 0.)])
+    /// );
+    /// ```
+    fn catmull_rom(&self, samples_per_segment: usize) -> Self;
+}
+
+/// The point at `t` along the curve from `p1` to `p2`, where `p0` comes before `p1` and `p3`
+/// after `p2`
+fn interpolate<T>(
+    p0: Coordinate<T>,
+    p1: Coordinate<T>,
+    p2: Coordinate<T>,
+    p3: Coordinate<T>,
+    t: T,
+) -> Coordinate<T>
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    let three = two + T::one();
+    let (t2, t3) = (t * t, t * t * t);
+    // the cubic Hermite basis, with tangents half the difference between the neighbours
+    let h00 = two * t3 - three * t2 + T::one();
+    let h10 = (t3 - two * t2 + t) / two;
+    let h01 = three * t2 - two * t3;
+    let h11 = (t3 - t2) / two;
+    Coordinate {
+        x: h00 * p1.x + h10 * (p2.x - p0.x) + h01 * p2.x + h11 * (p3.x - p1.x),
+        y: h00 * p1.y + h10 * (p2.y - p0.y) + h01 * p2.y + h11 * (p3.y - p1.y),
+    }
+}
+
+/// `a` reflected through `b`
+fn reflect<T>(a: Coordinate<T>, b: Coordinate<T>) -> Coordinate<T>
+where
+    T: Float,
+{
+    Coordinate {
+        x: b.x + b.x - a.x,
+        y: b.y + b.y - a.y,
+    }
+}
+
+fn catmull_rom_coords<T>(coords: &[Coordinate<T>], samples_per_segment: usize) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let n = coords.len();
+    if n < 2 || samples_per_segment < 2 {
+        return coords.to_vec();
+    }
+    let closed = coords[0] == coords[n - 1];
+    let before = |i: usize| match i {
+        0 if closed => coords[n - 2],
+        0 => reflect(coords[1], coords[0]),
+        _ => coords[i - 1],
+    };
+    let after = |i: usize| match i {
+        _ if i + 1 < n => coords[i + 1],
+        _ if closed => coords[1],
+        _ => reflect(coords[n - 2], coords[n - 1]),
+    };
+    let steps = T::from(samples_per_segment).unwrap();
+    let mut curve = Vec::with_capacity((n - 1) * samples_per_segment + 1);
+    for i in 0..n - 1 {
+        let (p0, p1, p2, p3) = (before(i), coords[i], coords[i + 1], after(i + 1));
+        curve.push(p1);
+        for step in 1..samples_per_segment {
+            let t = T::from(step).unwrap() / steps;
+            curve.push(interpolate(p0, p1, p2, p3, t));
+        }
+    }
+    curve.push(coords[n - 1]);
+    curve
+}
+
+impl<T> CatmullRom<T> for LineString<T>
+where
+    T: Float,
+{
+    fn catmull_rom(&self, samples_per_segment: usize) -> LineString<T> {
+        LineString(catmull_rom_coords(&self.0, samples_per_segment))
+    }
+}
+
+impl<T> CatmullRom<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn catmull_rom(&self, samples_per_segment: usize) -> MultiLineString<T> {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|ls| ls.catmull_rom(samples_per_segment))
+                .collect(),
+        )
+    }
+}
+
+impl<T> CatmullRom<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn catmull_rom(&self, samples_per_segment: usize) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.catmull_rom(samples_per_segment),
+            self.interiors
+                .iter()
+                .map(|ring| ring.catmull_rom(samples_per_segment))
+                .collect(),
+        )
+    }
+}
+
+impl<T> CatmullRom<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn catmull_rom(&self, samples_per_segment: usize) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|p| p.catmull_rom(samples_per_segment))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn passes_through_vertices_test() {
+        let ls = LineString::from(vec![(0., 0.), (3., 5.), (7., -1.), (9., 4.), (12., 2.)]);
+        let curve = ls.catmull_rom(8);
+        assert_eq!(curve.0.len(), 4 * 8 + 1);
+        for (i, c) in ls.0.iter().enumerate() {
+            assert_eq!(curve.0[i * 8], *c);
+        }
+    }
+
+    #[test]
+    fn collinear_test() {
+        // evenly spaced points on a line stay on it, evenly spaced
+        let ls = LineString::from(vec![(0., 0.), (2., 1.), (4., 2.), (6., 3.)]);
+        let curve = ls.catmull_rom(4);
+        for (i, c) in curve.0.iter().enumerate() {
+            assert_relative_eq!(c.x, i as f64 * 0.5, epsilon = 1e-12);
+            assert_relative_eq!(c.y, i as f64 * 0.25, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn closed_ring_test() {
+        let square = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]),
+            vec![],
+        );
+        let curve = square.catmull_rom(2).exterior;
+        assert_eq!(curve.0.len(), 9);
+        assert_eq!(curve.0.first(), curve.0.last());
+        // the curve bulges out symmetrically between each pair of corners
+        assert_eq!(curve.0[1], Coordinate { x: 1., y: -0.25 });
+        assert_eq!(curve.0[7], Coordinate { x: -0.25, y: 1. });
+    }
+
+    #[test]
+    fn degenerate_test() {
+        // a single segment is sampled evenly along the line
+        let line = LineString::from(vec![(0., 0.), (2., 1.)]);
+        assert_eq!(
+            line.catmull_rom(4),
+            LineString::from(vec![(0., 0.), (0.5, 0.25), (1., 0.5), (1.5, 0.75), (2., 1.)])
+        );
+        let point = LineString::from(vec![(1., 1.)]);
+        assert_eq!(point.catmull_rom(4), point);
+        let ls = LineString::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+        assert_eq!(ls.catmull_rom(1), ls);
+        assert_eq!(ls.catmull_rom(0), ls);
+    }
+}
@@ -3,7 +3,10 @@ use std::iter::Sum;
 
 use algorithm::area::Area;
 use algorithm::euclidean_length::EuclideanLength;
-use {Bbox, Line, LineString, MultiPolygon, Point, Polygon};
+use {
+    Bbox, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
 
 /// Calculation of the centroid.
 /// The centroid is the arithmetic mean position of all points in the shape.
@@ -11,6 +14,10 @@ use {Bbox, Line, LineString, MultiPolygon, Point, Polygon};
 /// balanced on the tip of a pin.
 /// The geometric centroid of a convex object always lies in the object.
 /// A non-convex object might have a centroid that _is outside the object itself_.
+///
+/// The centroid of a collection only takes account of its parts of the highest dimension:
+/// points are ignored if there are lines, and lines if there are polygons. Lines of no
+/// length count as points, and polygons of no area as their exterior rings.
 pub trait Centroid<T: Float> {
     type Output;
 
@@ -67,6 +74,111 @@ where
     Some(Point::new(sum_x / (six * area), sum_y / (six * area)))
 }
 
+/// The centroids of the parts of a geometry, weighted by their size, and summed separately
+/// for each dimension
+struct CentroidSums<T>
+where
+    T: Float,
+{
+    /// The weighted sums of x and y, and the total weight, for points, lines and polygons
+    sums: [(T, T, T); 3],
+}
+
+impl<T> CentroidSums<T>
+where
+    T: Float,
+{
+    fn new() -> CentroidSums<T> {
+        CentroidSums {
+            sums: [(T::zero(), T::zero(), T::zero()); 3],
+        }
+    }
+
+    fn add(&mut self, dimension: usize, centroid: Point<T>, weight: T) {
+        let sum = &mut self.sums[dimension];
+        *sum = (
+            sum.0 + centroid.x() * weight,
+            sum.1 + centroid.y() * weight,
+            sum.2 + weight,
+        );
+    }
+
+    fn add_point(&mut self, point: Point<T>) {
+        self.add(0, point, T::one());
+    }
+
+    fn add_line(&mut self, line: &Line<T>) {
+        let length = line.euclidean_length();
+        if length > T::zero() {
+            self.add(1, line.centroid(), length);
+        } else {
+            self.add_point(Point(line.start));
+        }
+    }
+
+    fn add_linestring(&mut self, linestring: &LineString<T>) {
+        let length = linestring
+            .lines()
+            .fold(T::zero(), |total, line| total + line.euclidean_length());
+        match linestring.centroid() {
+            Some(centroid) if length > T::zero() => self.add(1, centroid, length),
+            Some(centroid) => self.add_point(centroid),
+            None => (),
+        }
+    }
+
+    /// The centroid of the parts of the highest dimension
+    fn centroid(&self) -> Option<Point<T>> {
+        self.sums
+            .iter()
+            .rev()
+            .find(|sum| sum.2 > T::zero())
+            .map(|sum| Point::new(sum.0 / sum.2, sum.1 / sum.2))
+    }
+}
+
+impl<T> CentroidSums<T>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        let area = polygon.area().abs();
+        match polygon.centroid() {
+            Some(centroid) if area > T::zero() => self.add(2, centroid, area),
+            _ => self.add_linestring(&polygon.exterior),
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+        match *geometry {
+            Geometry::Point(point) => self.add_point(point),
+            Geometry::Line(ref line) => self.add_line(line),
+            Geometry::LineString(ref ls) => self.add_linestring(ls),
+            Geometry::Polygon(ref polygon) => self.add_polygon(polygon),
+            Geometry::MultiPoint(ref mp) => {
+                for &point in &mp.0 {
+                    self.add_point(point);
+                }
+            }
+            Geometry::MultiLineString(ref mls) => {
+                for ls in &mls.0 {
+                    self.add_linestring(ls);
+                }
+            }
+            Geometry::MultiPolygon(ref mp) => {
+                for polygon in &mp.0 {
+                    self.add_polygon(polygon);
+                }
+            }
+            Geometry::GeometryCollection(ref gc) => {
+                for geometry in &gc.0 {
+                    self.add_geometry(geometry);
+                }
+            }
+        }
+    }
+}
+
 impl<T> Centroid<T> for Line<T>
 where
     T: Float,
@@ -107,6 +219,10 @@ where
                             accum.2 + segment_len,
                         )
                     });
+            if total_length == T::zero() {
+                // all the vertices are in the same place
+                return Some(Point(self.0[0]));
+            }
             Some(Point::new(sum_x / total_length, sum_y / total_length))
         }
     }
@@ -170,23 +286,11 @@ where
     type Output = Option<Point<T>>;
 
     fn centroid(&self) -> Self::Output {
-        let mut sum_x = T::zero();
-        let mut sum_y = T::zero();
-        let mut total_area = T::zero();
-        let vect = &self.0;
-        if vect.is_empty() {
-            return None;
+        let mut sums = CentroidSums::new();
+        for polygon in &self.0 {
+            sums.add_polygon(polygon);
         }
-        for poly in &self.0 {
-            // the area is signed
-            let area = poly.area().abs();
-            total_area = total_area + area;
-            if let Some(p) = poly.centroid() {
-                sum_x = sum_x + area * p.x();
-                sum_y = sum_y + area * p.y();
-            }
-        }
-        Some(Point::new(sum_x / total_area, sum_y / total_area))
+        sums.centroid()
     }
 }
 
@@ -213,11 +317,79 @@ where
     }
 }
 
+impl<T> Centroid<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut sums = CentroidSums::new();
+        for &point in &self.0 {
+            sums.add_point(point);
+        }
+        sums.centroid()
+    }
+}
+
+impl<T> Centroid<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut sums = CentroidSums::new();
+        for linestring in &self.0 {
+            sums.add_linestring(linestring);
+        }
+        sums.centroid()
+    }
+}
+
+impl<T> Centroid<T> for GeometryCollection<T>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut sums = CentroidSums::new();
+        for geometry in &self.0 {
+            sums.add_geometry(geometry);
+        }
+        sums.centroid()
+    }
+}
+
+impl<T> Centroid<T> for Geometry<T>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        match *self {
+            Geometry::Point(ref g) => Some(g.centroid()),
+            Geometry::Line(ref g) => Some(g.centroid()),
+            Geometry::LineString(ref g) => g.centroid(),
+            Geometry::Polygon(ref g) => g.centroid(),
+            Geometry::MultiPoint(ref g) => g.centroid(),
+            Geometry::MultiLineString(ref g) => g.centroid(),
+            Geometry::MultiPolygon(ref g) => g.centroid(),
+            Geometry::GeometryCollection(ref g) => g.centroid(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use algorithm::centroid::Centroid;
     use algorithm::euclidean_distance::EuclideanDistance;
-    use {Bbox, Coordinate, Line, LineString, MultiPolygon, Point, Polygon, COORD_PRECISION};
+    use {
+        Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Point, Polygon, COORD_PRECISION,
+    };
     // Tests: Centroid of LineString
     #[test]
     fn empty_linestring_test() {
@@ -377,4 +549,66 @@ mod test {
         let line1 = Line::new(c(0., 1.), c(1., 3.));
         assert_eq!(line1.centroid(), Point::new(0.5, 2.));
     }
+    #[test]
+    fn zero_length_linestring_test() {
+        let linestring = LineString::from(vec![(1., 1.), (1., 1.)]);
+        assert_eq!(linestring.centroid(), Some(Point::new(1., 1.)));
+    }
+    #[test]
+    fn multipoint_test() {
+        let mp = MultiPoint(vec![
+            Point::new(0., 0.),
+            Point::new(4., 0.),
+            Point::new(2., 3.),
+        ]);
+        assert_eq!(mp.centroid(), Some(Point::new(2., 1.)));
+        assert_eq!(MultiPoint::<f64>(vec![]).centroid(), None);
+    }
+    #[test]
+    fn multilinestring_test() {
+        let mls = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (3., 0.)]),
+            LineString::from(vec![(0., 2.), (1., 2.)]),
+            // no length, so ignored beside the others
+            LineString::from(vec![(10., 10.)]),
+        ]);
+        assert_eq!(mls.centroid(), Some(Point::new(1.25, 0.5)));
+        let points = MultiLineString(vec![LineString::from(vec![(2., 2.)]), LineString(vec![])]);
+        assert_eq!(points.centroid(), Some(Point::new(2., 2.)));
+        assert_eq!(MultiLineString::<f64>(vec![]).centroid(), None);
+    }
+    #[test]
+    fn flat_multipolygon_test() {
+        let flat = Polygon::new(LineString::from(vec![(0., 0.), (2., 0.), (0., 0.)]), vec![]);
+        assert_eq!(
+            MultiPolygon(vec![flat]).centroid(),
+            Some(Point::new(1., 0.))
+        );
+    }
+    #[test]
+    fn geometry_collection_test() {
+        let square = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]),
+            vec![],
+        );
+        let line = Line::new(Coordinate { x: 10., y: 10. }, Coordinate { x: 20., y: 10. });
+        let mut gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(-10., 5.)),
+            Geometry::Line(line),
+        ]);
+        // the point is ignored beside the line
+        assert_eq!(gc.centroid(), Some(Point::new(15., 10.)));
+        gc.0.push(Geometry::Polygon(square.clone()));
+        gc.0.push(Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPolygon(MultiPolygon(vec![square])),
+        ])));
+        // and the line beside the squares
+        assert_eq!(gc.centroid(), Some(Point::new(1., 1.)));
+        assert_eq!(
+            Geometry::GeometryCollection(gc).centroid(),
+            Some(Point::new(1., 1.))
+        );
+        assert_eq!(Geometry::Line(line).centroid(), Some(Point::new(15., 10.)));
+        assert_eq!(GeometryCollection::<f64>(vec![]).centroid(), None);
+    }
 }
@@ -0,0 +1,179 @@
+use num_traits::Float;
+use {Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Smooths a geometry using Chaikin's corner-cutting algorithm.
+///
+/// Each iteration replaces every segment by two new vertices, a quarter and three quarters of
+/// the way along it, cutting off the corners; the geometry converges on a quadratic B-spline.
+/// The ends of an open `LineString` stay where they are, and closed rings, such as those of a
+/// `Polygon`, stay closed. Each iteration roughly doubles the number of vertices.
+///
+/// Multi* objects are smoothed by smoothing all their constituent geometries individually.
+pub trait ChaikinSmoothing<T>
+where
+    T: Float,
+{
+    /// Returns the geometry after `n_iterations` rounds of Chaikin's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::chaikin_smoothing::ChaikinSmoothing;
+    ///
+    /// let ls = LineString::from(vec![(0., 0.), (8., 8.), (16., 0.)]);
+    ///
+    /// let smoothed = ls.chaikin_smoothing(1);
+    /// assert_eq!(
+    ///     smoothed,
+    ///     LineString::from(vec![(0., 0.), (2., 2.), (6., 6.), (10., 6.), (14., 2.), (16., 0.)])
+    /// );
+    /// ```
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self;
+}
+
+/// The point `t` of the way from `a` to `b`
+fn lerp<T>(a: Coordinate<T>, b: Coordinate<T>, t: T) -> Coordinate<T>
+where
+    T: Float,
+{
+    Coordinate {
+        x: a.x + (b.x - a.x) * t,
+        y: a.y + (b.y - a.y) * t,
+    }
+}
+
+fn smoothing_iteration<T>(coords: &[Coordinate<T>]) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let quarter = T::from(0.25).unwrap();
+    let three_quarters = T::from(0.75).unwrap();
+    let closed = coords.len() > 2 && coords.first() == coords.last();
+    let mut smoothed = Vec::with_capacity(coords.len() * 2);
+    if !closed {
+        smoothed.extend(coords.first());
+    }
+    for pair in coords.windows(2) {
+        smoothed.push(lerp(pair[0], pair[1], quarter));
+        smoothed.push(lerp(pair[0], pair[1], three_quarters));
+    }
+    if closed {
+        let first = smoothed[0];
+        smoothed.push(first);
+    } else {
+        smoothed.extend(coords.last());
+    }
+    smoothed
+}
+
+impl<T> ChaikinSmoothing<T> for LineString<T>
+where
+    T: Float,
+{
+    fn chaikin_smoothing(&self, n_iterations: usize) -> LineString<T> {
+        if self.0.len() < 3 {
+            // there are no corners to cut
+            return self.clone();
+        }
+        let mut coords = self.0.clone();
+        for _ in 0..n_iterations {
+            coords = smoothing_iteration(&coords);
+        }
+        LineString(coords)
+    }
+}
+
+impl<T> ChaikinSmoothing<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn chaikin_smoothing(&self, n_iterations: usize) -> MultiLineString<T> {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|ls| ls.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T> ChaikinSmoothing<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.chaikin_smoothing(n_iterations),
+            self.interiors
+                .iter()
+                .map(|ring| ring.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T> ChaikinSmoothing<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn chaikin_smoothing(&self, n_iterations: usize) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|p| p.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn closed_ring_test() {
+        let square = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+            vec![],
+        );
+        let smoothed = square.chaikin_smoothing(1);
+        assert_eq!(
+            smoothed.exterior,
+            LineString::from(vec![
+                (1., 0.),
+                (3., 0.),
+                (4., 1.),
+                (4., 3.),
+                (3., 4.),
+                (1., 4.),
+                (0., 3.),
+                (0., 1.),
+                (1., 0.),
+            ])
+        );
+        let smoothed = square.chaikin_smoothing(3);
+        assert_eq!(smoothed.exterior.0.len(), 33);
+        assert_eq!(smoothed.exterior.0.first(), smoothed.exterior.0.last());
+    }
+
+    #[test]
+    fn open_linestring_test() {
+        let ls = LineString::from(vec![(0., 0.), (4., 4.), (8., 0.), (12., 4.)]);
+        let smoothed = ls.chaikin_smoothing(2);
+        // 2 ends, and twice as many segment points each time
+        assert_eq!(smoothed.0.len(), 2 + 2 * (2 * 3 + 1));
+        assert_eq!(smoothed.0.first(), ls.0.first());
+        assert_eq!(smoothed.0.last(), ls.0.last());
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let line = LineString::from(vec![(0., 0.), (1., 1.)]);
+        assert_eq!(line.chaikin_smoothing(3), line);
+        let empty = MultiLineString::<f64>(vec![LineString(vec![])]);
+        assert_eq!(empty.chaikin_smoothing(3), empty);
+        let ls = LineString::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+        assert_eq!(ls.chaikin_smoothing(0), ls);
+    }
+}
@@ -0,0 +1,511 @@
+use algorithm::boundingbox::BoundingBox;
+use num_traits::Float;
+use {
+    Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+/// Clips a geometry to a rectangle, keeping only the parts of it inside.
+///
+/// This is much quicker than a general intersection, as every piece of the geometry can be
+/// clipped on its own. The rectangle includes its boundary.
+pub trait ClipToRect<T>
+where
+    T: Float,
+{
+    type Output;
+
+    /// Returns the parts of the geometry inside `rect`.
+    ///
+    /// Lines are clipped with the Liang–Barsky algorithm: a `LineString` which leaves the
+    /// rectangle and comes back is split into several lines, giving a `MultiLineString`.
+    /// Polygon rings, holes included, are clipped with the Sutherland–Hodgman algorithm, so
+    /// each ring stays a single ring: where a polygon leaves the rectangle and comes back, its
+    /// clipped ring runs along the edge of the rectangle in between, and may touch itself
+    /// there. Rings which clip away to nothing are dropped, as are polygons whose exterior
+    /// does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Bbox, LineString, MultiLineString};
+    /// use geo::algorithm::clip_to_rect::ClipToRect;
+    ///
+    /// let tile = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+    /// // a line which leaves the tile, and comes back
+    /// let line = LineString::from(vec![(5., 5.), (15., 5.), (15., 8.), (5., 8.)]);
+    ///
+    /// assert_eq!(
+    ///     line.clip_to_rect(&tile),
+    ///     MultiLineString(vec![
+    ///         LineString::from(vec![(5., 5.), (10., 5.)]),
+    ///         LineString::from(vec![(10., 8.), (5., 8.)]),
+    ///     ])
+    /// );
+    /// ```
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output;
+}
+
+fn contains<T>(rect: &Bbox<T>, c: Coordinate<T>) -> bool
+where
+    T: Float,
+{
+    c.x >= rect.xmin && c.x <= rect.xmax && c.y >= rect.ymin && c.y <= rect.ymax
+}
+
+/// How much of a geometry's bounding box lies inside the rectangle
+enum Overlap {
+    Inside,
+    Outside,
+    Partly,
+}
+
+fn overlap<T>(rect: &Bbox<T>, bbox: Option<Bbox<T>>) -> Overlap
+where
+    T: Float,
+{
+    match bbox {
+        None => Overlap::Outside,
+        Some(b) => {
+            if b.xmin >= rect.xmin
+                && b.xmax <= rect.xmax
+                && b.ymin >= rect.ymin
+                && b.ymax <= rect.ymax
+            {
+                Overlap::Inside
+            } else if b.xmin > rect.xmax
+                || b.xmax < rect.xmin
+                || b.ymin > rect.ymax
+                || b.ymax < rect.ymin
+            {
+                Overlap::Outside
+            } else {
+                Overlap::Partly
+            }
+        }
+    }
+}
+
+/// The part of the segment from `start` to `end` inside `rect`, and whether each end of the
+/// segment was clipped
+fn clip_segment<T>(
+    rect: &Bbox<T>,
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+) -> Option<(Coordinate<T>, Coordinate<T>, bool, bool)>
+where
+    T: Float,
+{
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    for &(p, q) in &[
+        (-dx, start.x - rect.xmin),
+        (dx, rect.xmax - start.x),
+        (-dy, start.y - rect.ymin),
+        (dy, rect.ymax - start.y),
+    ] {
+        if p == T::zero() {
+            if q < T::zero() {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < T::zero() {
+                if t > t1 {
+                    return None;
+                }
+                t0 = t0.max(t);
+            } else {
+                if t < t0 {
+                    return None;
+                }
+                t1 = t1.min(t);
+            }
+        }
+    }
+    // points found along the segment are kept within the rectangle, despite rounding
+    let at = |t: T| Coordinate {
+        x: (start.x + t * dx).max(rect.xmin).min(rect.xmax),
+        y: (start.y + t * dy).max(rect.ymin).min(rect.ymax),
+    };
+    let clipped_start = t0 > T::zero();
+    let clipped_end = t1 < T::one();
+    Some((
+        if clipped_start { at(t0) } else { start },
+        if clipped_end { at(t1) } else { end },
+        clipped_start,
+        clipped_end,
+    ))
+}
+
+fn clip_line_string<T>(rect: &Bbox<T>, line_string: &LineString<T>) -> Vec<LineString<T>>
+where
+    T: Float,
+{
+    match overlap(rect, line_string.bbox()) {
+        Overlap::Inside if line_string.0.len() > 1 => return vec![line_string.clone()],
+        Overlap::Outside => return vec![],
+        _ => {}
+    }
+    let mut lines = vec![];
+    let mut current: Vec<Coordinate<T>> = vec![];
+    for segment in line_string.0.windows(2) {
+        let (start, end, clipped_start, clipped_end) =
+            match clip_segment(rect, segment[0], segment[1]) {
+                Some(clipped) => clipped,
+                None => continue,
+            };
+        if current.is_empty() || clipped_start {
+            if current.len() > 1 {
+                lines.push(LineString(current));
+            }
+            current = vec![start];
+        }
+        if end != current[current.len() - 1] {
+            current.push(end);
+        }
+        if clipped_end {
+            if current.len() > 1 {
+                lines.push(LineString(current));
+            }
+            current = vec![];
+        }
+    }
+    if current.len() > 1 {
+        lines.push(LineString(current));
+    }
+    lines
+}
+
+/// One side of the rectangle, and the half-plane inside it
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+    Bottom,
+    Top,
+}
+
+impl Side {
+    fn inside<T>(self, rect: &Bbox<T>, c: Coordinate<T>) -> bool
+    where
+        T: Float,
+    {
+        match self {
+            Side::Left => c.x >= rect.xmin,
+            Side::Right => c.x <= rect.xmax,
+            Side::Bottom => c.y >= rect.ymin,
+            Side::Top => c.y <= rect.ymax,
+        }
+    }
+
+    /// Where the segment from `a` to `b` crosses this side
+    fn crossing<T>(self, rect: &Bbox<T>, a: Coordinate<T>, b: Coordinate<T>) -> Coordinate<T>
+    where
+        T: Float,
+    {
+        let across_x = |x: T| Coordinate {
+            x,
+            y: a.y + (x - a.x) * (b.y - a.y) / (b.x - a.x),
+        };
+        let across_y = |y: T| Coordinate {
+            x: a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y),
+            y,
+        };
+        match self {
+            Side::Left => across_x(rect.xmin),
+            Side::Right => across_x(rect.xmax),
+            Side::Bottom => across_y(rect.ymin),
+            Side::Top => across_y(rect.ymax),
+        }
+    }
+}
+
+fn clip_ring<T>(rect: &Bbox<T>, ring: &LineString<T>) -> Option<LineString<T>>
+where
+    T: Float,
+{
+    match overlap(rect, ring.bbox()) {
+        Overlap::Inside => return Some(ring.clone()),
+        Overlap::Outside => return None,
+        Overlap::Partly => {}
+    }
+    let mut points = ring.0.clone();
+    if points.first() == points.last() {
+        points.pop();
+    }
+    for &side in &[Side::Left, Side::Right, Side::Bottom, Side::Top] {
+        let input = points;
+        points = vec![];
+        let mut previous = match input.last() {
+            Some(&c) => c,
+            None => return None,
+        };
+        for &c in &input {
+            let (c_inside, previous_inside) = (side.inside(rect, c), side.inside(rect, previous));
+            if c_inside != previous_inside {
+                points.push(side.crossing(rect, previous, c));
+            }
+            if c_inside {
+                points.push(c);
+            }
+            previous = c;
+        }
+    }
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return None;
+    }
+    let first = points[0];
+    points.push(first);
+    Some(LineString(points))
+}
+
+impl<T> ClipToRect<T> for Point<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        if contains(rect, self.0) {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ClipToRect<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = MultiPoint<T>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        MultiPoint(self.0.iter().filter_map(|p| p.clip_to_rect(rect)).collect())
+    }
+}
+
+impl<T> ClipToRect<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = Option<Line<T>>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        let (start, end, _, _) = clip_segment(rect, self.start, self.end)?;
+        Some(Line::new(start, end))
+    }
+}
+
+impl<T> ClipToRect<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        MultiLineString(clip_line_string(rect, self))
+    }
+}
+
+impl<T> ClipToRect<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        MultiLineString(
+            self.0
+                .iter()
+                .flat_map(|line_string| clip_line_string(rect, line_string))
+                .collect(),
+        )
+    }
+}
+
+impl<T> ClipToRect<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        let exterior = clip_ring(rect, &self.exterior)?;
+        let interiors = self
+            .interiors
+            .iter()
+            .filter_map(|ring| clip_ring(rect, ring))
+            .collect();
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+impl<T> ClipToRect<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        MultiPolygon(self.0.iter().filter_map(|p| p.clip_to_rect(rect)).collect())
+    }
+}
+
+impl<T> ClipToRect<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = GeometryCollection<T>;
+
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        GeometryCollection(self.0.iter().filter_map(|g| g.clip_to_rect(rect)).collect())
+    }
+}
+
+impl<T> ClipToRect<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Option<Geometry<T>>;
+
+    /// Geometries which clip away to nothing, such as an empty `MultiLineString`, give `None`.
+    fn clip_to_rect(&self, rect: &Bbox<T>) -> Self::Output {
+        match *self {
+            Geometry::Point(ref p) => p.clip_to_rect(rect).map(Geometry::Point),
+            Geometry::Line(ref l) => l.clip_to_rect(rect).map(Geometry::Line),
+            Geometry::LineString(ref ls) => {
+                let clipped = ls.clip_to_rect(rect);
+                match clipped.0.len() {
+                    0 => None,
+                    1 => clipped.0.into_iter().next().map(Geometry::LineString),
+                    _ => Some(Geometry::MultiLineString(clipped)),
+                }
+            }
+            Geometry::Polygon(ref p) => p.clip_to_rect(rect).map(Geometry::Polygon),
+            Geometry::MultiPoint(ref mp) => Some(mp.clip_to_rect(rect))
+                .filter(|mp| !mp.0.is_empty())
+                .map(Geometry::MultiPoint),
+            Geometry::MultiLineString(ref mls) => Some(mls.clip_to_rect(rect))
+                .filter(|mls| !mls.0.is_empty())
+                .map(Geometry::MultiLineString),
+            Geometry::MultiPolygon(ref mp) => Some(mp.clip_to_rect(rect))
+                .filter(|mp| !mp.0.is_empty())
+                .map(Geometry::MultiPolygon),
+            Geometry::GeometryCollection(ref gc) => Some(gc.clip_to_rect(rect))
+                .filter(|gc| !gc.0.is_empty())
+                .map(Geometry::GeometryCollection),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rect() -> Bbox<f64> {
+        Bbox {
+            xmin: 0.,
+            xmax: 10.,
+            ymin: 0.,
+            ymax: 10.,
+        }
+    }
+
+    #[test]
+    fn point_and_line_test() {
+        assert_eq!(
+            Point::new(10., 5.).clip_to_rect(&rect()),
+            Some(Point::new(10., 5.))
+        );
+        assert_eq!(Point::new(11., 5.).clip_to_rect(&rect()), None);
+        let line = Line::from([(-5., 5.), (5., 15.)]);
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            Some(Line::from([(0., 10.), (0., 10.)]))
+        );
+        let line = Line::from([(-5., 0.), (15., 10.)]);
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            Some(Line::from([(0., 2.5), (10., 7.5)]))
+        );
+        let line = Line::from([(-5., -5.), (-1., 15.)]);
+        assert_eq!(line.clip_to_rect(&rect()), None);
+    }
+
+    #[test]
+    fn line_string_test() {
+        // starts outside, crosses a corner, and touches an edge on the way back
+        let line = LineString::from(vec![
+            (-5., 5.),
+            (5., 5.),
+            (5., 15.),
+            (10., 20.),
+            (10., 10.),
+            (20., 10.),
+            (20., 0.),
+            (8., 0.),
+        ]);
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            MultiLineString(vec![
+                LineString::from(vec![(0., 5.), (5., 5.), (5., 10.)]),
+                LineString::from(vec![(10., 0.), (8., 0.)]),
+            ])
+        );
+        // entirely inside
+        let line = LineString::from(vec![(1., 1.), (2., 2.)]);
+        assert_eq!(line.clip_to_rect(&rect()), MultiLineString(vec![line]));
+    }
+
+    #[test]
+    fn polygon_test() {
+        let exterior = LineString::from(vec![
+            (-5., -5.),
+            (15., -5.),
+            (15., 5.),
+            (-5., 5.),
+            (-5., -5.),
+        ]);
+        // one hole inside, one crossing the edge, and one outside
+        let interiors = vec![
+            LineString::from(vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)]),
+            LineString::from(vec![(8., 1.), (8., 2.), (12., 2.), (12., 1.), (8., 1.)]),
+            LineString::from(vec![(-4., 1.), (-4., 2.), (-2., 2.), (-2., 1.), (-4., 1.)]),
+        ];
+        let polygon = Polygon::new(exterior, interiors);
+        let clipped = polygon.clip_to_rect(&rect()).unwrap();
+        assert_eq!(
+            clipped.exterior,
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 5.), (0., 5.), (0., 0.)])
+        );
+        assert_eq!(clipped.interiors.len(), 2);
+        assert_eq!(clipped.interiors[0], polygon.interiors[0]);
+        assert_eq!(
+            clipped.interiors[1],
+            LineString::from(vec![(10., 1.), (8., 1.), (8., 2.), (10., 2.), (10., 1.)])
+        );
+
+        let outside = Polygon::new(
+            LineString::from(vec![(20., 20.), (30., 20.), (30., 30.), (20., 20.)]),
+            vec![],
+        );
+        assert_eq!(outside.clip_to_rect(&rect()), None);
+    }
+
+    #[test]
+    fn geometry_test() {
+        let line: Geometry<f64> = LineString::from(vec![(5., 5.), (15., 5.)]).into();
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            Some(LineString::from(vec![(5., 5.), (10., 5.)]).into())
+        );
+        let outside: Geometry<f64> = MultiPoint(vec![Point::new(20., 20.)]).into();
+        assert_eq!(outside.clip_to_rect(&rect()), None);
+    }
+}
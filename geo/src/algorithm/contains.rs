@@ -1,13 +1,24 @@
 use num_traits::{Float, ToPrimitive};
 
+use algorithm::area::Area;
 use algorithm::euclidean_distance::EuclideanDistance;
 use algorithm::intersects::Intersects;
-use {Bbox, CoordinateType, Line, LineString, MultiPolygon, Point, Polygon, COORD_PRECISION};
+use algorithm::predicates::{orient2d, Orientation};
+use {
+    Bbox, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, COORD_PRECISION,
+};
 
 ///  Checks if the geometry A is completely inside the B geometry
 pub trait Contains<Rhs = Self> {
     /// Checks if `rhs` is completely contained within `self`.
     ///
+    /// A `MultiPoint`, `MultiLineString`, `MultiPolygon` or `GeometryCollection` is contained
+    /// if it isn't empty and each of its members is, and contains a geometry if any one of its
+    /// members does. The members aren't unioned first, so a geometry that only fits across
+    /// several of them, such as a polygon straddling two adjacent polygons of a
+    /// `MultiPolygon`, isn't contained. A `Geometry` behaves as the geometry it wraps.
+    ///
     /// # Examples
     ///
     /// ```
@@ -59,20 +70,7 @@ where
         if self.0.contains(&p.0) {
             return true;
         }
-        for line in self.lines() {
-            if ((line.start.y == line.end.y)
-                && (line.start.y == p.y())
-                && (p.x() > line.start.x.min(line.end.x))
-                && (p.x() < line.start.x.max(line.end.x)))
-                || ((line.start.x == line.end.x)
-                    && (line.start.x == p.x())
-                    && (p.y() > line.start.y.min(line.end.y))
-                    && (p.y() < line.start.y.max(line.end.y)))
-            {
-                return true;
-            }
-        }
-        false
+        self.lines().any(|line| line.intersects(p))
     }
 }
 
@@ -163,18 +161,20 @@ where
         return PositionPoint::OnBoundary;
     }
 
-    let mut xints = T::zero();
     let mut crossings = 0;
     for line in linestring.lines() {
         if p.y() > line.start.y.min(line.end.y)
             && p.y() <= line.start.y.max(line.end.y)
             && p.x() <= line.start.x.max(line.end.x)
         {
-            if line.start.y != line.end.y {
-                xints = (p.y() - line.start.y) * (line.end.x - line.start.x)
-                    / (line.end.y - line.start.y) + line.start.x;
-            }
-            if (line.start.x == line.end.x) || (p.x() <= xints) {
+            // the ray cast from p towards +x crosses this edge iff p
+            // doesn't lie to the right of the edge, directed upwards
+            let (lower, upper) = if line.start.y < line.end.y {
+                (line.start, line.end)
+            } else {
+                (line.end, line.start)
+            };
+            if orient2d(lower, upper, p.0) != Orientation::Clockwise {
                 crossings += 1;
             }
         }
@@ -201,15 +201,6 @@ where
     }
 }
 
-impl<T> Contains<Point<T>> for MultiPolygon<T>
-where
-    T: Float,
-{
-    fn contains(&self, p: &Point<T>) -> bool {
-        self.0.iter().any(|poly| poly.contains(p))
-    }
-}
-
 impl<T> Contains<Line<T>> for Polygon<T>
 where
     T: Float,
@@ -275,10 +266,262 @@ where
     }
 }
 
+impl<T> Contains<Line<T>> for Point<T>
+where
+    T: Float,
+{
+    fn contains(&self, line: &Line<T>) -> bool {
+        self.contains(&line.start_point()) && self.contains(&line.end_point())
+    }
+}
+
+impl<T> Contains<LineString<T>> for Point<T>
+where
+    T: Float,
+{
+    fn contains(&self, linestring: &LineString<T>) -> bool {
+        !linestring.0.is_empty() && linestring.points_iter().all(|p| self.contains(&p))
+    }
+}
+
+impl<T> Contains<Polygon<T>> for Point<T>
+where
+    T: Float,
+{
+    fn contains(&self, polygon: &Polygon<T>) -> bool {
+        self.contains(&polygon.exterior)
+    }
+}
+
+impl<T> Contains<Bbox<T>> for Point<T>
+where
+    T: Float,
+{
+    fn contains(&self, bbox: &Bbox<T>) -> bool {
+        self.contains(&bbox.to_polygon())
+    }
+}
+
+impl<T> Contains<Polygon<T>> for Line<T>
+where
+    T: Float,
+{
+    fn contains(&self, polygon: &Polygon<T>) -> bool {
+        // only a polygon with no area can lie along a line
+        !polygon.exterior.0.is_empty() && self.contains(&polygon.exterior)
+    }
+}
+
+impl<T> Contains<Bbox<T>> for Line<T>
+where
+    T: Float,
+{
+    fn contains(&self, bbox: &Bbox<T>) -> bool {
+        self.contains(&bbox.to_polygon())
+    }
+}
+
+impl<T> Contains<LineString<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn contains(&self, linestring: &LineString<T>) -> bool {
+        match linestring.0.len() {
+            0 => false,
+            1 => self.contains(&Point(linestring.0[0])),
+            _ => linestring.lines().all(|line| self.contains(&line)),
+        }
+    }
+}
+
+impl<T> Contains<Polygon<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn contains(&self, polygon: &Polygon<T>) -> bool {
+        // a LineString can enclose a polygon's boundary, but never its area
+        polygon.area() == T::zero() && self.contains(&polygon.exterior)
+    }
+}
+
+impl<T> Contains<Bbox<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn contains(&self, bbox: &Bbox<T>) -> bool {
+        self.contains(&bbox.to_polygon())
+    }
+}
+
+impl<T> Contains<Bbox<T>> for Polygon<T>
+where
+    T: Float,
+{
+    fn contains(&self, bbox: &Bbox<T>) -> bool {
+        self.contains(&bbox.to_polygon())
+    }
+}
+
+impl<T> Contains<Line<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn contains(&self, line: &Line<T>) -> bool {
+        self.contains(&line.start_point()) && self.contains(&line.end_point())
+    }
+}
+
+impl<T> Contains<LineString<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn contains(&self, linestring: &LineString<T>) -> bool {
+        !linestring.0.is_empty() && linestring.points_iter().all(|p| self.contains(&p))
+    }
+}
+
+impl<T> Contains<Polygon<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn contains(&self, polygon: &Polygon<T>) -> bool {
+        self.contains(&polygon.exterior)
+    }
+}
+
+// Collections are contained if all of their members are
+macro_rules! contains_members {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> Contains<$rhs<T>> for $lhs<T>
+            where
+                T: Float,
+            {
+                fn contains(&self, rhs: &$rhs<T>) -> bool {
+                    !rhs.0.is_empty() && rhs.0.iter().all(|member| self.contains(member))
+                }
+            }
+        )*)*
+    };
+}
+
+contains_members! {
+    Point => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Line => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    LineString => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Polygon => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Bbox => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    MultiPoint => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    MultiLineString => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    MultiPolygon => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    GeometryCollection => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+}
+
+// and contain a single geometry if any of their members does; a geometry that's only covered
+// by the union of several members is reported as not contained
+macro_rules! contained_by_member {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> Contains<$rhs<T>> for $lhs<T>
+            where
+                T: Float,
+            {
+                fn contains(&self, rhs: &$rhs<T>) -> bool {
+                    self.0.iter().any(|member| member.contains(rhs))
+                }
+            }
+        )*)*
+    };
+}
+
+contained_by_member! {
+    MultiPoint => [Point, Line, LineString, Polygon, Bbox];
+    MultiLineString => [Point, Line, LineString, Polygon, Bbox];
+    MultiPolygon => [Point, Line, LineString, Polygon, Bbox];
+    GeometryCollection => [Point, Line, LineString, Polygon, Bbox];
+}
+
+// A Geometry contains, or is contained by, whatever the geometry it wraps does
+macro_rules! contains_geometry {
+    ($($other:ident),*) => {
+        $(
+            impl<T> Contains<$other<T>> for Geometry<T>
+            where
+                T: Float,
+            {
+                fn contains(&self, rhs: &$other<T>) -> bool {
+                    match *self {
+                        Geometry::Point(ref g) => g.contains(rhs),
+                        Geometry::Line(ref g) => g.contains(rhs),
+                        Geometry::LineString(ref g) => g.contains(rhs),
+                        Geometry::Polygon(ref g) => g.contains(rhs),
+                        Geometry::MultiPoint(ref g) => g.contains(rhs),
+                        Geometry::MultiLineString(ref g) => g.contains(rhs),
+                        Geometry::MultiPolygon(ref g) => g.contains(rhs),
+                        Geometry::GeometryCollection(ref g) => g.contains(rhs),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+contains_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    Bbox,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry
+);
+
+macro_rules! contains_in_geometry {
+    ($($other:ident),*) => {
+        $(
+            impl<T> Contains<Geometry<T>> for $other<T>
+            where
+                T: Float,
+            {
+                fn contains(&self, rhs: &Geometry<T>) -> bool {
+                    match *rhs {
+                        Geometry::Point(ref g) => self.contains(g),
+                        Geometry::Line(ref g) => self.contains(g),
+                        Geometry::LineString(ref g) => self.contains(g),
+                        Geometry::Polygon(ref g) => self.contains(g),
+                        Geometry::MultiPoint(ref g) => self.contains(g),
+                        Geometry::MultiLineString(ref g) => self.contains(g),
+                        Geometry::MultiPolygon(ref g) => self.contains(g),
+                        Geometry::GeometryCollection(ref g) => self.contains(g),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+contains_in_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    Bbox,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
 #[cfg(test)]
 mod test {
     use algorithm::contains::Contains;
-    use {Bbox, Coordinate, Line, LineString, MultiPolygon, Point, Polygon};
+    use {
+        Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiPoint, MultiPolygon,
+        Point, Polygon,
+    };
     #[test]
     // V doesn't contain rect because two of its edges intersect with V's exterior boundary
     fn polygon_does_not_contain_polygon() {
@@ -532,6 +775,14 @@ mod test {
         assert!(line3.contains(&Point(p0)));
     }
     #[test]
+    fn point_on_diagonal_linestring_segment_test() {
+        let linestring = LineString::from(vec![(0., 0.), (3., 3.), (6., 0.)]);
+        assert!(linestring.contains(&Point::new(1.5, 1.5)));
+        assert!(linestring.contains(&Point::new(4.5, 1.5)));
+        assert!(!linestring.contains(&Point::new(1.5, 1.5 + 2f64.powi(-52))));
+        assert!(!linestring.contains(&Point::new(7.5, -1.5)));
+    }
+    #[test]
     fn line_in_line_test() {
         let c = |x, y| Coordinate { x: x, y: y };
         let line0 = Line::new(c(0., 1.), c(3., 4.));
@@ -549,11 +800,11 @@ mod test {
     fn linestring_in_line_test() {
         let line = Line::from([(0., 1.), (3., 4.)]);
         // linestring0 in line
-        let linestring0 = LineString::from(vec![(0.1, 1.1), (1., 2.), (1.5, 2.5)]);
+        let linestring0 = LineString::from(vec![(0.5, 1.5), (1., 2.), (1.5, 2.5)]);
         // linestring1 starts and ends in line, but wanders in the middle
-        let linestring1 = LineString::from(vec![(0.1, 1.1), (2., 2.), (1.5, 2.5)]);
+        let linestring1 = LineString::from(vec![(0.5, 1.5), (2., 2.), (1.5, 2.5)]);
         // linestring2 is co-linear, but extends beyond line
-        let linestring2 = LineString::from(vec![(0.1, 1.1), (1., 2.), (4., 5.)]);
+        let linestring2 = LineString::from(vec![(0.5, 1.5), (1., 2.), (4., 5.)]);
         // no part of linestring3 is contained in line
         let linestring3 = LineString::from(vec![(1.1, 1.1), (2., 2.), (2.5, 2.5)]);
         assert!(line.contains(&linestring0));
@@ -625,4 +876,73 @@ mod test {
         };
         assert!(bbox.contains(&smaller_bbox));
     }
+
+    #[test]
+    fn collections_test() {
+        let square = |x: f64| {
+            Polygon::new(
+                LineString::from(vec![(x, 0.), (x + 2., 0.), (x + 2., 2.), (x, 2.), (x, 0.)]),
+                vec![],
+            )
+        };
+        let squares = MultiPolygon(vec![square(0.), square(5.)]);
+        let points = MultiPoint(vec![Point::new(1., 1.), Point::new(6., 1.)]);
+        // each point is in a different member
+        assert!(squares.contains(&points));
+        assert!(!square(0.).contains(&points));
+        assert!(!square(0.).contains(&MultiPoint(vec![])));
+        assert!(points.contains(&Point::new(6., 1.)));
+
+        let triangle = Polygon::new(
+            LineString::from(vec![(0.5, 0.5), (1.5, 0.5), (1.5, 1.5), (0.5, 0.5)]),
+            vec![],
+        );
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(triangle),
+            Geometry::MultiPoint(points.clone()),
+        ]);
+        assert!(squares.contains(&collection));
+        assert!(Geometry::MultiPolygon(squares).contains(&Geometry::MultiPoint(points)));
+        assert!(!collection.contains(&Line::from([(1., 1.), (6., 1.)])));
+    }
+
+    #[test]
+    fn straddling_members_test() {
+        let square = |x: f64| {
+            Polygon::new(
+                LineString::from(vec![(x, 0.), (x + 2., 0.), (x + 2., 2.), (x, 2.), (x, 0.)]),
+                vec![],
+            )
+        };
+        // the members share an edge, so their union covers the rectangle, but no single
+        // member does
+        let squares = MultiPolygon(vec![square(0.), square(2.)]);
+        let rectangle = Polygon::new(
+            LineString::from(vec![(1., 0.5), (3., 0.5), (3., 1.5), (1., 1.5), (1., 0.5)]),
+            vec![],
+        );
+        assert!(!squares.contains(&rectangle));
+        assert!(!squares.contains(&Line::from([(1., 1.), (3., 1.)])));
+        assert!(squares.contains(&Point::new(3., 1.)));
+    }
+
+    #[test]
+    fn degenerate_containers_test() {
+        let linestring = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let square = Polygon::new(linestring.clone(), vec![]);
+        // a LineString can hold a polygon's boundary, but not its area
+        assert!(linestring.contains(&linestring));
+        assert!(!linestring.contains(&square));
+        let flat = Polygon::new(LineString::from(vec![(0., 0.), (4., 0.), (0., 0.)]), vec![]);
+        assert!(linestring.contains(&flat));
+        assert!(Line::from([(0., 0.), (4., 0.)]).contains(&flat));
+        let bbox = Bbox {
+            xmin: 0.,
+            xmax: 4.,
+            ymin: 0.,
+            ymax: 4.,
+        };
+        assert!(bbox.contains(&square));
+        assert!(!square.contains(&bbox));
+    }
 }
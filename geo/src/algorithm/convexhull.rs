@@ -1,4 +1,5 @@
 use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::predicates::{orient2d, Orientation};
 use num_traits::Float;
 use std::mem;
 use {Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
@@ -37,23 +38,17 @@ fn partition<T, F: FnMut(&T) -> bool>(mut slice: &mut [T], mut pred: F) -> usize
 }
 
 // Determine whether a point lies on one side of a line segment, or the other.
-// The cross product v x w of two vectors v and w is a vector whose length is
-// |v||w|sin φ, (where |v| is the length of v and φ is the angle between the vectors),
-// and which is orthogonal (perpendicular) to both v and w.  Since there are two
-// such possible vectors, the definition arbitrarily selects the one that matches
-// the direction in which a screw would move if rotated from v to w
-
-// Mathematically, if the coordinates of vectors v and w are (vx, vy) and (wx, wy)
-// respectively, the cross product will be (vxwy - vywx). If a segment is
-// defined by points A B and, we wish to check on which side of AB a third point C falls,
-// we can compute the cross product AB x AC and check its sign:
-// If it's negative, it will be on the "right" side of AB
-// (when standing on A and looking towards B). If positive, it will be on the left side
+// If segment is defined by points A and B, and we wish to check on which side of AB
+// a third point C falls, we check the orientation of the triangle A → B → C:
+// if it's clockwise, C is on the "right" side of AB (when standing on A and looking
+// towards B). If it's counter-clockwise, C is on the left side.
+// The orientation test is exact, so nearly collinear points are classified consistently
+// across the recursive partitioning steps, and can't be dropped from the hull.
 fn point_location<T>(p_a: Point<T>, p_b: Point<T>, p_c: Point<T>) -> bool
 where
     T: Float,
 {
-    p_a.cross_prod(p_b, p_c) > T::zero()
+    orient2d(p_a.0, p_b.0, p_c.0) == Orientation::CounterClockwise
 }
 
 // Adapted from http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
@@ -124,6 +119,32 @@ where
     hull_set(p_a, *furthest_point, &mut set[..last], hull);
 }
 
+// The distinct vertices of a convex hull in counter-clockwise order, without the closing
+// coordinate. `quick_hull` returns inputs of fewer than four points unchanged, so these may
+// be unclosed, repeated, or clockwise.
+pub(crate) fn hull_vertices<T>(hull: &Polygon<T>) -> Vec<Point<T>>
+where
+    T: Float,
+{
+    let mut vertices: Vec<Point<T>> = vec![];
+    for point in hull.exterior.points_iter() {
+        if vertices.last() != Some(&point) {
+            vertices.push(point);
+        }
+    }
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    let twice_area = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .fold(T::zero(), |sum, (a, b)| sum + a.x() * b.y() - b.x() * a.y());
+    if twice_area < T::zero() {
+        vertices.reverse();
+    }
+    vertices
+}
+
 pub trait ConvexHull<T> {
     /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
     ///
@@ -0,0 +1,18 @@
+use num_traits::Float;
+use Coordinate;
+
+// Helpers for algorithms which need to match coordinates exactly, such as coverage
+// simplification, which finds the arcs shared by several rings.
+
+/// Identifies a coordinate exactly, treating `0.` and `-0.` as equal
+pub(crate) type Key = ((u64, i16, i8), (u64, i16, i8));
+
+pub(crate) fn key<T>(c: &Coordinate<T>) -> Key
+where
+    T: Float,
+{
+    (
+        (c.x + T::zero()).integer_decode(),
+        (c.y + T::zero()).integer_decode(),
+    )
+}
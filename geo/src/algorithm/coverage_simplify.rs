@@ -0,0 +1,456 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use algorithm::coordinate_key::{key, Key};
+use algorithm::simplify::SimplifyPreserve;
+use num_traits::Float;
+use spade::SpadeFloat;
+use {Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Simplifies a coverage: a set of polygons which share boundaries without overlapping, such
+/// as the counties of a state.
+///
+/// Simplifying each polygon on its own opens gaps and slivers along their shared boundaries,
+/// since each copy of a boundary is simplified differently. Instead, the rings of the coverage
+/// are split into arcs where three or more polygons meet, or where a polygon stops sharing a
+/// boundary; each arc is simplified once, and the polygons are put back together from the
+/// simplified arcs. Shared boundaries therefore stay identical, so the simplified polygons
+/// still meet exactly, without gaps or overlaps.
+///
+/// Adjacent polygons are expected to share their vertices along common boundaries. Rings are
+/// never reduced below four points: an arc is left as it was if simplifying it would collapse
+/// any ring it belongs to.
+pub trait CoverageSimplify<T, Epsilon = T> {
+    /// Returns the simplified coverage, simplifying each arc using the topology-preserving
+    /// variant of the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm, as `SimplifyPreserve` does.
+    ///
+    /// All the arcs are simplified together, so they don't cross one another either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, MultiPolygon, Polygon};
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::coverage_simplify::CoverageSimplify;
+    ///
+    /// // two fields, with a crooked hedge between them
+    /// let hedge = vec![(5., 0.), (5.2, 2.), (4.8, 4.), (5.1, 6.), (5., 8.)];
+    /// let mut west = vec![(0., 8.), (0., 0.)];
+    /// west.extend(hedge.iter().cloned());
+    /// west.push((0., 8.));
+    /// let mut east = vec![(10., 0.), (10., 8.)];
+    /// east.extend(hedge.iter().rev().cloned());
+    /// east.push((10., 0.));
+    /// let fields = MultiPolygon(vec![
+    ///     Polygon::new(LineString::from(west), vec![]),
+    ///     Polygon::new(LineString::from(east), vec![]),
+    /// ]);
+    ///
+    /// let simplified = fields.coverage_simplify(&0.5);
+    /// assert_eq!(
+    ///     simplified.0[0].exterior,
+    ///     LineString::from(vec![(0., 8.), (0., 0.), (5., 0.), (5., 8.), (0., 8.)])
+    /// );
+    /// assert_eq!(
+    ///     simplified.0[1].exterior,
+    ///     LineString::from(vec![(10., 0.), (10., 8.), (5., 8.), (5., 0.), (10., 0.)])
+    /// );
+    /// assert_eq!(simplified.area(), 80.);
+    /// ```
+    fn coverage_simplify(&self, epsilon: &T) -> Self
+    where
+        T: Float + SpadeFloat;
+}
+
+/// A ring of the coverage, as the arcs it runs along, each of which may be reversed
+type RingArcs = Vec<(usize, bool)>;
+
+/// The rings of a coverage, split into the distinct arcs they share
+struct Arcs<T>
+where
+    T: Float,
+{
+    arcs: Vec<LineString<T>>,
+    rings: Vec<RingArcs>,
+}
+
+impl<T> Arcs<T>
+where
+    T: Float,
+{
+    fn new(rings: &[Vec<Coordinate<T>>]) -> Arcs<T> {
+        let nodes = nodes(rings);
+        let mut arcs = Arcs {
+            arcs: vec![],
+            rings: vec![],
+        };
+        let mut index = BTreeMap::new();
+        for ring in rings {
+            let ring_arcs = split_ring(ring, &nodes)
+                .into_iter()
+                .map(|arc| arcs.add(arc, &mut index))
+                .collect();
+            arcs.rings.push(ring_arcs);
+        }
+        arcs
+    }
+
+    /// Adds an arc, unless it has been added already, returning its index and whether it is
+    /// reversed relative to the arc stored
+    fn add(
+        &mut self,
+        mut arc: Vec<Coordinate<T>>,
+        index: &mut BTreeMap<Vec<Key>, usize>,
+    ) -> (usize, bool) {
+        let forward: Vec<Key> = arc.iter().map(key).collect();
+        let backward: Vec<Key> = forward.iter().rev().cloned().collect();
+        let reversed = backward < forward;
+        let canonical = if reversed {
+            arc.reverse();
+            backward
+        } else {
+            forward
+        };
+        let arcs = &mut self.arcs;
+        let i = *index.entry(canonical).or_insert_with(|| {
+            arcs.push(LineString(arc));
+            arcs.len() - 1
+        });
+        (i, reversed)
+    }
+
+    /// Puts a ring back together from simplified arcs
+    fn ring(&self, ring: &[(usize, bool)], simplified: &[LineString<T>]) -> Vec<Coordinate<T>> {
+        let mut coords: Vec<Coordinate<T>> = vec![];
+        for &(arc, reversed) in ring {
+            let mut arc = simplified[arc].0.clone();
+            if reversed {
+                arc.reverse();
+            }
+            // consecutive arcs meet at a node
+            let skip = if coords.is_empty() { 0 } else { 1 };
+            coords.extend(arc.into_iter().skip(skip));
+        }
+        coords
+    }
+
+    /// Simplifies the arcs with `simplify`, keeping any arc which would collapse one of its
+    /// rings as it was, and reassembles the rings
+    fn simplify<F>(&self, simplify: F) -> Vec<Vec<Coordinate<T>>>
+    where
+        F: Fn(&MultiLineString<T>) -> MultiLineString<T>,
+    {
+        let mut kept = vec![false; self.arcs.len()];
+        loop {
+            let unkept = MultiLineString(
+                self.arcs
+                    .iter()
+                    .zip(kept.iter())
+                    .filter(|&(_, keep)| !*keep)
+                    .map(|(arc, _)| arc.clone())
+                    .collect(),
+            );
+            let mut simplified = simplify(&unkept).0.into_iter();
+            let arcs: Vec<_> = self
+                .arcs
+                .iter()
+                .zip(kept.iter())
+                .map(|(arc, keep)| {
+                    if *keep {
+                        arc.clone()
+                    } else {
+                        simplified.next().unwrap()
+                    }
+                })
+                .collect();
+            let rings: Vec<_> = self.rings.iter().map(|r| self.ring(r, &arcs)).collect();
+            let mut collapsed = false;
+            for (ring, ring_arcs) in rings.iter().zip(self.rings.iter()) {
+                if ring.len() < 4 {
+                    for &(arc, _) in ring_arcs {
+                        collapsed |= !kept[arc];
+                        kept[arc] = true;
+                    }
+                }
+            }
+            if !collapsed {
+                return rings;
+            }
+        }
+    }
+}
+
+/// The coordinates at which arcs start and end: those which don't have exactly two neighbours
+/// along the rings
+fn nodes<T>(rings: &[Vec<Coordinate<T>>]) -> BTreeSet<Key>
+where
+    T: Float,
+{
+    let mut neighbours: BTreeMap<Key, BTreeSet<Key>> = BTreeMap::new();
+    for ring in rings {
+        for pair in ring.windows(2) {
+            let (a, b) = (key(&pair[0]), key(&pair[1]));
+            neighbours.entry(a).or_default().insert(b);
+            neighbours.entry(b).or_default().insert(a);
+        }
+    }
+    neighbours
+        .into_iter()
+        .filter(|(_, n)| n.len() != 2)
+        .map(|(c, _)| c)
+        .collect()
+}
+
+/// Splits a closed ring, without repeated vertices, into arcs between nodes. A ring without
+/// nodes is a single closed arc, starting from its least coordinate.
+fn split_ring<T>(ring: &[Coordinate<T>], nodes: &BTreeSet<Key>) -> Vec<Vec<Coordinate<T>>>
+where
+    T: Float,
+{
+    if ring.len() < 2 {
+        return vec![ring.to_vec()];
+    }
+    let vertices = &ring[..ring.len() - 1];
+    let start = (0..vertices.len())
+        .find(|&i| nodes.contains(&key(&vertices[i])))
+        .unwrap_or_else(|| {
+            (0..vertices.len())
+                .min_by_key(|&i| key(&vertices[i]))
+                .unwrap()
+        });
+    let rotated: Vec<_> = vertices[start..]
+        .iter()
+        .chain(vertices[..start + 1].iter())
+        .cloned()
+        .collect();
+    let mut arcs = vec![];
+    let mut arc = vec![rotated[0]];
+    for c in &rotated[1..] {
+        arc.push(*c);
+        if nodes.contains(&key(c)) {
+            arcs.push(arc);
+            arc = vec![*c];
+        }
+    }
+    if arc.len() > 1 {
+        arcs.push(arc);
+    }
+    arcs
+}
+
+/// A closed ring, without consecutive repeated coordinates
+fn ring_coords<T>(ring: &LineString<T>) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let mut coords: Vec<Coordinate<T>> = vec![];
+    for c in ring.0.iter().chain(ring.0.first()) {
+        if coords.last() != Some(c) {
+            coords.push(*c);
+        }
+    }
+    coords
+}
+
+/// `ring`, starting from `first` if it's still there
+fn rotate_to<T>(mut ring: Vec<Coordinate<T>>, first: Option<&Coordinate<T>>) -> LineString<T>
+where
+    T: Float,
+{
+    if ring.len() > 1 {
+        if let Some(i) = first.and_then(|c| ring.iter().position(|r| r == c)) {
+            ring.pop();
+            ring.rotate_left(i);
+            let first = ring[0];
+            ring.push(first);
+        }
+    }
+    LineString(ring)
+}
+
+fn simplify_coverage<T, F>(polygons: &MultiPolygon<T>, simplify: F) -> MultiPolygon<T>
+where
+    T: Float,
+    F: Fn(&MultiLineString<T>) -> MultiLineString<T>,
+{
+    let originals: Vec<&LineString<T>> = polygons
+        .0
+        .iter()
+        .flat_map(|p| Some(&p.exterior).into_iter().chain(p.interiors.iter()))
+        .collect();
+    let rings: Vec<_> = originals.iter().map(|r| ring_coords(r)).collect();
+    let mut simplified = Arcs::new(&rings)
+        .simplify(simplify)
+        .into_iter()
+        .zip(originals.iter())
+        .map(|(ring, original)| rotate_to(ring, original.0.first()));
+    MultiPolygon(
+        polygons
+            .0
+            .iter()
+            .map(|p| {
+                let exterior = simplified.next().unwrap();
+                let interiors = simplified.by_ref().take(p.interiors.len()).collect();
+                Polygon::new(exterior, interiors)
+            })
+            .collect(),
+    )
+}
+
+impl<T> CoverageSimplify<T> for MultiPolygon<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn coverage_simplify(&self, epsilon: &T) -> MultiPolygon<T> {
+        simplify_coverage(self, |arcs| arcs.simplify_preserve(epsilon))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use algorithm::contains::Contains;
+    use algorithm::simplify::Simplify;
+
+    /// Two squares below a wider rectangle, with a crooked edge between the squares, and an
+    /// island in a lake in the rectangle
+    fn fields() -> MultiPolygon<f64> {
+        let island = LineString::from(vec![
+            (6., 11.),
+            (7., 10.9),
+            (10., 11.),
+            (10., 13.),
+            (6., 13.),
+            (6., 11.),
+        ]);
+        let mut lake = island.clone();
+        lake.0.reverse();
+        MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![
+                    (0., 0.),
+                    (8., 0.),
+                    (8.2, 2.),
+                    (7.8, 4.),
+                    (8.1, 6.),
+                    (8., 8.3),
+                    (0., 8.),
+                    (0., 0.),
+                ]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![
+                    (8., 0.),
+                    (16., 0.),
+                    (16., 8.),
+                    (8., 8.3),
+                    (8.1, 6.),
+                    (7.8, 4.),
+                    (8.2, 2.),
+                    (8., 0.),
+                ]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![
+                    (0., 8.),
+                    (8., 8.3),
+                    (16., 8.),
+                    (16., 16.),
+                    (0., 16.),
+                    (0., 8.),
+                ]),
+                vec![lake],
+            ),
+            Polygon::new(island, vec![]),
+        ])
+    }
+
+    fn total_area(polygons: &MultiPolygon<f64>) -> f64 {
+        let ring_area = |ring: &LineString<f64>| Polygon::new(ring.clone(), vec![]).area().abs();
+        polygons
+            .0
+            .iter()
+            .map(|p| ring_area(&p.exterior) - p.interiors.iter().map(ring_area).sum::<f64>())
+            .sum()
+    }
+
+    #[test]
+    fn shared_arcs_test() {
+        let fields = fields();
+        // simplifying the polygons one at a time, the rectangle loses the corner the squares
+        // meet it at, and overlaps them
+        assert!(total_area(&fields.simplify(&0.5)) - total_area(&fields) > 1.);
+        let simplified = fields.coverage_simplify(&0.5);
+        assert_relative_eq!(total_area(&simplified), 256., epsilon = 1e-9);
+        assert_eq!(
+            simplified.0[0].exterior,
+            LineString::from(vec![(0., 0.), (8., 0.), (8., 8.3), (0., 8.), (0., 0.)])
+        );
+        assert_eq!(
+            simplified.0[1].exterior,
+            LineString::from(vec![(8., 0.), (16., 0.), (16., 8.), (8., 8.3), (8., 0.)])
+        );
+        assert_eq!(simplified.0[2].exterior, fields.0[2].exterior);
+        let island = LineString::from(vec![
+            (6., 11.),
+            (10., 11.),
+            (10., 13.),
+            (6., 13.),
+            (6., 11.),
+        ]);
+        assert_eq!(simplified.0[3].exterior, island);
+        let mut lake = simplified.0[2].interiors[0].clone();
+        lake.0.reverse();
+        assert_eq!(lake, island);
+    }
+
+    #[test]
+    fn enclosed_polygon_test() {
+        // dropping the corner at (0., 10.) would leave the island outside the polygon
+        // surrounding it
+        let island = LineString::from(vec![(4., 4.), (6., 4.), (6., 6.), (4., 6.), (4., 4.)]);
+        let mut lake = island.clone();
+        lake.0.reverse();
+        let coverage = MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![
+                    (10., 10.),
+                    (0., 10.),
+                    (0., 0.),
+                    (10., 0.),
+                    (10., -30.),
+                    (40., -30.),
+                    (40., 10.),
+                    (10., 10.),
+                ]),
+                vec![lake],
+            ),
+            Polygon::new(island, vec![]),
+        ]);
+        let simplified = coverage.coverage_simplify(&12.);
+        let shell = Polygon::new(simplified.0[0].exterior.clone(), vec![]);
+        assert!(shell.contains(&simplified.0[1]));
+        let mut lake = simplified.0[0].interiors[0].clone();
+        lake.0.reverse();
+        assert_eq!(lake, simplified.0[1].exterior);
+    }
+
+    #[test]
+    fn collapse_test() {
+        // each triangle would collapse to a line if their shared arc were simplified
+        let coverage = MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.1), (2., 0.), (0., 0.)]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (2., 0.), (1., -0.1), (0., 0.)]),
+                vec![],
+            ),
+        ]);
+        assert_eq!(coverage.coverage_simplify(&1.), coverage);
+    }
+}
@@ -0,0 +1,377 @@
+use algorithm::euclidean_length::EuclideanLength;
+use algorithm::haversine_distance::HaversineDistance;
+use algorithm::haversine_intermediate::HaversineIntermediate;
+use num_traits::{Float, FromPrimitive};
+use {Coordinate, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Adds vertices to a geometry, so that none of its segments is longer than a given length.
+pub trait Densify<T>
+where
+    T: Float,
+{
+    type Output;
+
+    /// Returns the geometry with extra vertices, evenly spaced along each segment longer than
+    /// `max_segment_length`, so that none of its segments is longer.
+    ///
+    /// The original vertices are kept exactly. Segments are left as they are if
+    /// `max_segment_length` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Line, LineString};
+    /// use geo::algorithm::densify::Densify;
+    ///
+    /// let line = Line::from([(0., 0.), (0., 6.)]);
+    ///
+    /// let densified = line.densify(2.5);
+    /// assert_eq!(densified, LineString::from(vec![(0., 0.), (0., 2.), (0., 4.), (0., 6.)]));
+    /// ```
+    fn densify(&self, max_segment_length: T) -> Self::Output;
+}
+
+/// Adds vertices along great circles, so that none of a geometry's segments is longer than a
+/// given length.
+pub trait DensifyHaversine<T>
+where
+    T: Float,
+{
+    type Output;
+
+    /// Returns the geometry with extra vertices, evenly spaced along the great circle of each
+    /// segment longer than `max_segment_length` meters, so that none of its segments is longer.
+    ///
+    /// Coordinates are longitudes and latitudes in degrees, and lengths are measured with the
+    /// haversine formula, as with `HaversineDistance`. The original vertices are kept exactly.
+    /// Segments are left as they are if `max_segment_length` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::densify::DensifyHaversine;
+    /// use geo::algorithm::haversine_distance::HaversineDistance;
+    ///
+    /// // London to New York
+    /// let flight = LineString::from(vec![(-0.45, 51.47), (-73.78, 40.64)]);
+    ///
+    /// let path = flight.densify_haversine(500_000.);
+    /// assert_eq!(path.0.len(), 13);
+    /// assert_eq!(path.0[0], flight.0[0]);
+    /// assert_eq!(path.0[12], flight.0[1]);
+    /// // the great circle heads north of both ends
+    /// assert!(path.0[6].y > 52.);
+    /// for pair in path.0.windows(2) {
+    ///     assert!(Point(pair[0]).haversine_distance(&Point(pair[1])) <= 500_000.);
+    /// }
+    /// ```
+    fn densify_haversine(&self, max_segment_length: T) -> Self::Output;
+}
+
+/// The number of equal pieces to split a segment of `length` into, so that none is longer
+/// than `max_segment_length`
+fn pieces<T>(length: T, max_segment_length: Option<T>) -> usize
+where
+    T: Float,
+{
+    match max_segment_length {
+        Some(max) if max > T::zero() => (length / max).ceil().to_usize().unwrap_or(1),
+        _ => 1,
+    }
+}
+
+/// The coordinates strictly between the ends of `line` which split it into pieces no longer
+/// than `max_segment_length`
+pub(crate) fn densify_line<T>(line: Line<T>, max_segment_length: Option<T>) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let pieces = pieces(line.euclidean_length(), max_segment_length);
+    let n = T::from(pieces).unwrap();
+    (1..pieces)
+        .map(|i| {
+            let t = T::from(i).unwrap() / n;
+            Coordinate {
+                x: line.start.x + line.dx() * t,
+                y: line.start.y + line.dy() * t,
+            }
+        })
+        .collect()
+}
+
+/// The coordinates strictly between the ends of `line` which split its great circle into
+/// pieces no longer than `max_segment_length` meters
+fn densify_line_haversine<T>(line: Line<T>, max_segment_length: T) -> Vec<Coordinate<T>>
+where
+    T: Float + FromPrimitive,
+{
+    let (start, end) = line.points();
+    let pieces = pieces(start.haversine_distance(&end), Some(max_segment_length));
+    let n = T::from(pieces).unwrap();
+    (1..pieces)
+        .map(|i| {
+            start
+                .haversine_intermediate(&end, T::from(i).unwrap() / n)
+                .0
+        })
+        .collect()
+}
+
+/// Adds coordinates to a sequence, using `densify_line` to fill in each of its segments
+fn densify_coords_with<T, F>(coords: &[Coordinate<T>], densify_line: F) -> Vec<Coordinate<T>>
+where
+    T: Float,
+    F: Fn(Line<T>) -> Vec<Coordinate<T>>,
+{
+    let mut densified = vec![];
+    for pair in coords.windows(2) {
+        densified.push(pair[0]);
+        densified.extend(densify_line(Line::new(pair[0], pair[1])));
+    }
+    densified.extend(coords.last());
+    densified
+}
+
+/// Adds coordinates to a sequence, so that none of its segments is longer than
+/// `max_segment_length`
+pub(crate) fn densify_coords<T>(
+    coords: &[Coordinate<T>],
+    max_segment_length: T,
+) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    densify_coords_with(coords, |line| densify_line(line, Some(max_segment_length)))
+}
+
+impl<T> Densify<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = LineString<T>;
+
+    fn densify(&self, max_segment_length: T) -> LineString<T> {
+        LineString(densify_coords(&[self.start, self.end], max_segment_length))
+    }
+}
+
+impl<T> Densify<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = LineString<T>;
+
+    fn densify(&self, max_segment_length: T) -> LineString<T> {
+        LineString(densify_coords(&self.0, max_segment_length))
+    }
+}
+
+impl<T> Densify<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn densify(&self, max_segment_length: T) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.densify(max_segment_length),
+            self.interiors
+                .iter()
+                .map(|ring| ring.densify(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Densify<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = MultiLineString<T>;
+
+    fn densify(&self, max_segment_length: T) -> MultiLineString<T> {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|linestring| linestring.densify(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Densify<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = MultiPolygon<T>;
+
+    fn densify(&self, max_segment_length: T) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.densify(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T> DensifyHaversine<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_segment_length: T) -> LineString<T> {
+        LineString::from(vec![self.start, self.end]).densify_haversine(max_segment_length)
+    }
+}
+
+impl<T> DensifyHaversine<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_segment_length: T) -> LineString<T> {
+        LineString(densify_coords_with(&self.0, |line| {
+            densify_line_haversine(line, max_segment_length)
+        }))
+    }
+}
+
+impl<T> DensifyHaversine<T> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_segment_length: T) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.densify_haversine(max_segment_length),
+            self.interiors
+                .iter()
+                .map(|ring| ring.densify_haversine(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T> DensifyHaversine<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiLineString<T>;
+
+    fn densify_haversine(&self, max_segment_length: T) -> MultiLineString<T> {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|linestring| linestring.densify_haversine(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T> DensifyHaversine<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiPolygon<T>;
+
+    fn densify_haversine(&self, max_segment_length: T) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.densify_haversine(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::haversine_distance::HaversineDistance;
+    use Point;
+
+    #[test]
+    fn densify_coords_test() {
+        let coords = LineString::from(vec![(0., 0.), (3., 0.), (3., 1.)]).0;
+        assert_eq!(
+            densify_coords(&coords, 1.),
+            LineString::from(vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.), (3., 1.)]).0
+        );
+    }
+
+    #[test]
+    fn polygon_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 3.), (0., 3.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (2., 1.),
+                (2., 2.),
+                (1., 1.),
+            ])],
+        );
+        let densified = polygon.densify(2.);
+        assert_eq!(
+            densified.exterior,
+            LineString::from(vec![
+                (0., 0.),
+                (2., 0.),
+                (4., 0.),
+                (4., 1.5),
+                (4., 3.),
+                (2., 3.),
+                (0., 3.),
+                (0., 1.5),
+                (0., 0.),
+            ])
+        );
+        // the hole's edges are all short enough
+        assert_eq!(densified.interiors, polygon.interiors);
+        // nothing happens without a positive length
+        assert_eq!(polygon.densify(0.), polygon);
+        assert_eq!(polygon.densify(-1.), polygon);
+    }
+
+    #[test]
+    fn multi_test() {
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (0., 1.)]),
+            LineString::from(vec![(5., 5.)]),
+            LineString(vec![]),
+        ]);
+        assert_eq!(
+            lines.densify(0.5),
+            MultiLineString(vec![
+                LineString::from(vec![(0., 0.), (0., 0.5), (0., 1.)]),
+                LineString::from(vec![(5., 5.)]),
+                LineString(vec![]),
+            ])
+        );
+    }
+
+    #[test]
+    fn haversine_test() {
+        // a quarter of the equator
+        let line = Line::from([(0., 0.), (90., 0.)]);
+        let length = Point::new(0., 0.).haversine_distance(&Point::new(90., 0.));
+        let densified = line.densify_haversine(length / 3.);
+        assert_eq!(densified.0.len(), 4);
+        assert_relative_eq!(densified.0[1].x, 30., epsilon = 1e-9);
+        assert_relative_eq!(densified.0[2].x, 60., epsilon = 1e-9);
+        assert_relative_eq!(densified.0[1].y, 0., epsilon = 1e-9);
+        // the ends are kept exactly
+        let ring = LineString::from(vec![(0.1, 0.2), (10.3, 20.4), (-5.5, 30.6), (0.1, 0.2)]);
+        let densified = ring.densify_haversine(100_000.);
+        assert_eq!(densified.0.first(), ring.0.first());
+        assert_eq!(densified.0.last(), ring.0.last());
+        assert!(ring.0.iter().all(|c| densified.0.contains(c)));
+        for pair in densified.0.windows(2) {
+            assert!(Point(pair[0]).haversine_distance(&Point(pair[1])) <= 100_000. + 1e-6);
+        }
+    }
+}
@@ -2,11 +2,14 @@ use algorithm::contains::{get_position, Contains, PositionPoint};
 use algorithm::euclidean_length::EuclideanLength;
 use algorithm::intersects::Intersects;
 use algorithm::polygon_distance_fast_path::*;
+use algorithm::spatial_index::SpatialIndex;
 use num_traits::float::FloatConst;
 use num_traits::{Float, Signed, ToPrimitive};
-use {Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use {
+    Bbox, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
 
-use spade::rtree::RTree;
 use spade::SpadeFloat;
 
 /// Returns the distance between two geometries.
@@ -22,6 +25,11 @@ pub trait EuclideanDistance<T, Rhs = Self> {
     ///
     /// The distance between a `Point` and an empty `LineString` is `0.0`
     ///
+    /// The distance to a `MultiPoint`, `MultiLineString`, `MultiPolygon` or
+    /// `GeometryCollection` is the smallest distance to any of its members, and the distance
+    /// to a `Geometry` is the distance to the geometry it wraps. `a.euclidean_distance(&b)`
+    /// is always the same as `b.euclidean_distance(&a)`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -375,10 +383,12 @@ where
         if self.intersects(other) || self.contains(other) {
             return T::zero();
         }
-        // minimum of two Point-Line distances
+        // minimum of the four Point-Line distances
         self.start_point()
             .euclidean_distance(other)
             .min(self.end_point().euclidean_distance(other))
+            .min(other.start_point().euclidean_distance(self))
+            .min(other.end_point().euclidean_distance(self))
     }
 }
 
@@ -391,13 +401,10 @@ where
         if other.contains(self) || self.intersects(other) {
             return T::zero();
         }
-        // point-line distance between each exterior polygon point and the line
-        let exterior_min = other
-            .exterior
-            .points_iter()
-            .fold(T::max_value(), |acc, point| {
-                acc.min(self.euclidean_distance(&point))
-            });
+        // line-line distance between each exterior ring segment and the line
+        let exterior_min = other.exterior.lines().fold(T::max_value(), |acc, line| {
+            acc.min(self.euclidean_distance(&line))
+        });
         // point-line distance between each interior ring point and the line
         // if there are no rings this just evaluates to max_float
         let interior_min = other
@@ -462,33 +469,234 @@ where
     }
 }
 
-/// Uses an R* tree and nearest-neighbour lookups to calculate minimum distances
+// Point to Bbox distance
+impl<T> EuclideanDistance<T, Bbox<T>> for Point<T>
+where
+    T: Float,
+{
+    fn euclidean_distance(&self, bbox: &Bbox<T>) -> T {
+        self.euclidean_distance(&bbox.to_polygon())
+    }
+}
+
+// Bbox to Point distance
+impl<T> EuclideanDistance<T, Point<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        point.euclidean_distance(self)
+    }
+}
+
+// Bbox to Bbox distance
+impl<T> EuclideanDistance<T, Bbox<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn euclidean_distance(&self, other: &Bbox<T>) -> T {
+        // the gaps between the boxes along each axis, if they don't overlap
+        let dx = (other.xmin - self.xmax)
+            .max(self.xmin - other.xmax)
+            .max(T::zero());
+        let dy = (other.ymin - self.ymax)
+            .max(self.ymin - other.ymax)
+            .max(T::zero());
+        dx.hypot(dy)
+    }
+}
+
+// Line, LineString and Polygon to Bbox distances
+macro_rules! distance_to_bbox {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T> EuclideanDistance<T, Bbox<T>> for $geometry<T>
+            where
+                T: Float + FloatConst + Signed + SpadeFloat,
+            {
+                fn euclidean_distance(&self, bbox: &Bbox<T>) -> T {
+                    self.euclidean_distance(&bbox.to_polygon())
+                }
+            }
+
+            impl<T> EuclideanDistance<T, $geometry<T>> for Bbox<T>
+            where
+                T: Float + FloatConst + Signed + SpadeFloat,
+            {
+                fn euclidean_distance(&self, other: &$geometry<T>) -> T {
+                    other.euclidean_distance(&self.to_polygon())
+                }
+            }
+        )*
+    };
+}
+
+distance_to_bbox!(Line, LineString, Polygon);
+
+// The distance from a collection is the minimum distance from any of its members
+macro_rules! distance_to_members {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> EuclideanDistance<T, $rhs<T>> for $lhs<T>
+            where
+                T: Float + FloatConst + Signed + SpadeFloat,
+            {
+                fn euclidean_distance(&self, rhs: &$rhs<T>) -> T {
+                    self.0
+                        .iter()
+                        .map(|member| member.euclidean_distance(rhs))
+                        .fold(T::max_value(), |accum, val| accum.min(val))
+                }
+            }
+        )*)*
+    };
+}
+
+distance_to_members! {
+    MultiPoint => [Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString, MultiPolygon,
+        GeometryCollection, Geometry];
+    MultiLineString => [Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+    MultiPolygon => [LineString, Polygon, Bbox, MultiPoint, MultiLineString, MultiPolygon,
+        GeometryCollection, Geometry];
+    GeometryCollection => [Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+}
+
+// Distances to a Point need nothing more than Float, so they're implemented apart from the
+// macros
+impl<T> EuclideanDistance<T, Point<T>> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        self.0
+            .iter()
+            .map(|member| member.euclidean_distance(point))
+            .fold(T::max_value(), |accum, val| accum.min(val))
+    }
+}
+
+impl<T> EuclideanDistance<T, Point<T>> for Geometry<T>
+where
+    T: Float,
+{
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        match *self {
+            Geometry::Point(ref g) => g.euclidean_distance(point),
+            Geometry::Line(ref g) => g.euclidean_distance(point),
+            Geometry::LineString(ref g) => g.euclidean_distance(point),
+            Geometry::Polygon(ref g) => g.euclidean_distance(point),
+            Geometry::MultiPoint(ref g) => g.euclidean_distance(point),
+            Geometry::MultiLineString(ref g) => g.euclidean_distance(point),
+            Geometry::MultiPolygon(ref g) => g.euclidean_distance(point),
+            Geometry::GeometryCollection(ref g) => g.euclidean_distance(point),
+        }
+    }
+}
+
+// The other way round, for single geometries
+macro_rules! distance_reversed {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> EuclideanDistance<T, $rhs<T>> for $lhs<T>
+            where
+                T: Float + FloatConst + Signed + SpadeFloat,
+            {
+                fn euclidean_distance(&self, rhs: &$rhs<T>) -> T {
+                    rhs.euclidean_distance(self)
+                }
+            }
+        )*)*
+    };
+}
+
+distance_reversed! {
+    Point => [GeometryCollection];
+    Line => [MultiPoint, MultiLineString, GeometryCollection];
+    LineString => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Polygon => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Bbox => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+}
+
+// The distance from a Geometry is the distance from the geometry it wraps
+macro_rules! distance_to_geometry {
+    ($($other:ident),*) => {
+        $(
+            impl<T> EuclideanDistance<T, $other<T>> for Geometry<T>
+            where
+                T: Float + FloatConst + Signed + SpadeFloat,
+            {
+                fn euclidean_distance(&self, rhs: &$other<T>) -> T {
+                    match *self {
+                        Geometry::Point(ref g) => g.euclidean_distance(rhs),
+                        Geometry::Line(ref g) => g.euclidean_distance(rhs),
+                        Geometry::LineString(ref g) => g.euclidean_distance(rhs),
+                        Geometry::Polygon(ref g) => g.euclidean_distance(rhs),
+                        Geometry::MultiPoint(ref g) => g.euclidean_distance(rhs),
+                        Geometry::MultiLineString(ref g) => g.euclidean_distance(rhs),
+                        Geometry::MultiPolygon(ref g) => g.euclidean_distance(rhs),
+                        Geometry::GeometryCollection(ref g) => g.euclidean_distance(rhs),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+distance_to_geometry!(
+    Line,
+    LineString,
+    Polygon,
+    Bbox,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry
+);
+
+distance_reversed! {
+    Point => [Geometry];
+    Line => [Geometry];
+    LineString => [Geometry];
+    Polygon => [Geometry];
+    Bbox => [Geometry];
+}
+
+/// Uses a [`SpatialIndex`](../spatial_index/struct.SpatialIndex.html) and nearest-neighbour
+/// lookups to calculate minimum distances
 // This is somewhat slow and memory-inefficient, but certainly better than quadratic time
 pub fn nearest_neighbour_distance<T>(geom1: &LineString<T>, geom2: &LineString<T>) -> T
 where
     T: Float + SpadeFloat,
 {
-    let tree_a: RTree<Line<_>> = RTree::bulk_load(geom1.lines().collect());
-    let tree_b: RTree<Line<_>> = RTree::bulk_load(geom2.lines().collect());
+    let index_a = SpatialIndex::bulk_load(geom1.lines().map(|line| (line, ())));
+    let index_b = SpatialIndex::bulk_load(geom2.lines().map(|line| (line, ())));
     // Return minimum distance between all geom a points and all geom b points
     geom2
         .points_iter()
         .fold(T::max_value(), |acc, point| {
-            let nearest = tree_a.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
+            let nearest = index_a.nearest(&point).unwrap();
+            acc.min(nearest.distance(&point))
         })
         .min(geom1.points_iter().fold(T::max_value(), |acc, point| {
-            let nearest = tree_b.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
+            let nearest = index_b.nearest(&point).unwrap();
+            acc.min(nearest.distance(&point))
         }))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use algorithm::contains::Contains;
     use algorithm::convexhull::ConvexHull;
     use algorithm::euclidean_distance::{line_segment_distance, EuclideanDistance};
-    use {Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+    use algorithm::intersects::Intersects;
+    use {
+        Bbox, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+        MultiPolygon, Point, Polygon,
+    };
 
     #[test]
     fn line_segment_distance_test() {
@@ -969,4 +1177,146 @@ mod test {
         let ls: LineString<_> = vec![(3.0, 0.0), (1.0, 1.0), (3.0, 2.0)].into();
         assert_eq!(ls.euclidean_distance(&line), 1.0);
     }
+    #[test]
+    // Line-Line test: the closest point is an endpoint of the second line
+    fn test_line_line_distance() {
+        let a = Line::from([(-1., 0.), (1., 0.)]);
+        let b = Line::from([(0., 1.), (0., 5.)]);
+        assert_eq!(a.euclidean_distance(&b), 1.);
+        assert_eq!(b.euclidean_distance(&a), 1.);
+    }
+    #[test]
+    fn test_bbox_distance() {
+        let a = Bbox {
+            xmin: 0.,
+            xmax: 1.,
+            ymin: 0.,
+            ymax: 1.,
+        };
+        let b = Bbox {
+            xmin: 4.,
+            xmax: 5.,
+            ymin: 5.,
+            ymax: 6.,
+        };
+        assert_eq!(a.euclidean_distance(&b), 5.);
+        assert_eq!(a.euclidean_distance(&a), 0.);
+        assert_eq!(a.euclidean_distance(&b.to_polygon()), 5.);
+        assert_eq!(Point::new(3., 0.5).euclidean_distance(&a), 2.);
+    }
+
+    // Geometries of every type, some overlapping, some touching and some apart
+    fn geometries() -> Vec<Geometry<f64>> {
+        let square = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (3., 1.),
+                (3., 3.),
+                (1., 3.),
+                (1., 1.),
+            ])],
+        );
+        let triangle = Polygon::new(
+            LineString::from(vec![(6., 0.), (9., 0.), (6., 3.), (6., 0.)]),
+            vec![],
+        );
+        let notch = Polygon::new(
+            LineString::from(vec![(1.5, 1.5), (2.5, 1.5), (2., 2.5), (1.5, 1.5)]),
+            vec![],
+        );
+        let zigzag = LineString::from(vec![(-3., -1.), (-1., 1.), (1., -1.), (3., 1.)]);
+        let hook = LineString::from(vec![(5., 5.), (7., 2.), (10., 2.)]);
+        let diagonal = Line::from([(-2., -2.), (-1., -1.)]);
+        let crossing = Line::from([(5., -1.), (5., 3.)]);
+        let points = vec![
+            Point::new(2., 2.),
+            Point::new(4., 2.),
+            Point::new(0.5, 0.5),
+            Point::new(-4., 6.),
+        ];
+        let mut geometries = vec![
+            Geometry::Polygon(square.clone()),
+            Geometry::Polygon(triangle.clone()),
+            Geometry::Polygon(notch.clone()),
+            Geometry::LineString(zigzag.clone()),
+            Geometry::LineString(hook.clone()),
+            Geometry::Line(diagonal),
+            Geometry::Line(crossing),
+            Geometry::Line(Line::from([(2., 0.), (2., 4.)])),
+            Geometry::MultiPoint(MultiPoint(vec![points[2], points[3]])),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(8., 8.)])),
+            Geometry::MultiLineString(MultiLineString(vec![zigzag, hook])),
+            Geometry::MultiPolygon(MultiPolygon(vec![triangle, notch])),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Point(Point::new(-2., 7.)),
+                Geometry::Line(Line::from([(12., 0.), (12., 3.)])),
+            ])),
+        ];
+        geometries.extend(points.into_iter().map(Geometry::Point));
+        let nested = GeometryCollection(geometries.clone());
+        geometries.push(Geometry::GeometryCollection(nested));
+        geometries
+    }
+
+    #[test]
+    // Every pair of geometry types, through the Geometry enum
+    fn test_geometry_matrix() {
+        let geometries = geometries();
+        for a in &geometries {
+            for b in &geometries {
+                let distance = a.euclidean_distance(b);
+                assert_relative_eq!(distance, b.euclidean_distance(a), epsilon = 1e-9);
+                assert_eq!(a.intersects(b), b.intersects(a), "{:?} and {:?}", a, b);
+                assert_eq!(a.intersects(b), distance < 1e-9, "{:?} and {:?}", a, b);
+                if a.contains(b) {
+                    assert!(a.intersects(b), "{:?} contains {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    // Bbox against every type of geometry
+    fn test_bbox_matrix() {
+        let bboxes = vec![
+            Bbox {
+                xmin: -1.,
+                xmax: 5.,
+                ymin: -1.,
+                ymax: 5.,
+            },
+            Bbox {
+                xmin: 1.2,
+                xmax: 2.8,
+                ymin: 2.6,
+                ymax: 2.8,
+            },
+            Bbox {
+                xmin: 9.,
+                xmax: 11.,
+                ymin: 9.,
+                ymax: 11.,
+            },
+        ];
+        for bbox in &bboxes {
+            for g in &geometries() {
+                let distance = bbox.euclidean_distance(g);
+                assert_relative_eq!(distance, g.euclidean_distance(bbox), epsilon = 1e-9);
+                assert_eq!(bbox.intersects(g), g.intersects(bbox), "{:?}", g);
+                assert_eq!(bbox.intersects(g), distance < 1e-9, "{:?}", g);
+                if bbox.contains(g) {
+                    assert!(bbox.intersects(g), "{:?} contains {:?}", bbox, g);
+                }
+            }
+            for other in &bboxes {
+                let distance = bbox.euclidean_distance(other);
+                assert_eq!(bbox.intersects(other), other.intersects(bbox));
+                assert_eq!(bbox.intersects(other), distance < 1e-9, "{:?}", other);
+                if bbox.contains(other) {
+                    assert!(bbox.intersects(other), "{:?} contains {:?}", bbox, other);
+                }
+            }
+        }
+    }
 }
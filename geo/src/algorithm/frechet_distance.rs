@@ -0,0 +1,130 @@
+use algorithm::densify::densify_coords;
+use algorithm::euclidean_distance::EuclideanDistance;
+use num_traits::Float;
+use {Coordinate, LineString, Point};
+
+/// Returns the Fréchet distance between two line strings.
+pub trait FrechetDistance<T, Rhs = Self> {
+    /// Returns the discrete Fréchet distance between two line strings.
+    ///
+    /// This is the shortest leash which lets one walker visit each vertex of `self` in turn,
+    /// while another visits each vertex of `rhs`, neither ever stepping backwards. Unlike the
+    /// Hausdorff distance it takes the direction of the line strings into account. The
+    /// result is zero if either line string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::frechet_distance::FrechetDistance;
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    ///
+    /// let route = LineString::from(vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.)]);
+    /// let track = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.), (3., 0.)]);
+    ///
+    /// // the track backtracks over the route, which only the Fréchet distance notices
+    /// assert_eq!(route.hausdorff_distance(&track), 0.);
+    /// assert_eq!(route.frechet_distance(&track), 1.);
+    /// ```
+    fn frechet_distance(&self, rhs: &Rhs) -> T;
+
+    /// Returns the discrete Fréchet distance between two line strings, after adding vertices
+    /// to their segments so that none is longer than `max_segment_length`.
+    ///
+    /// This approaches the continuous Fréchet distance as `max_segment_length` is reduced.
+    /// Segments are left as they are if `max_segment_length` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::frechet_distance::FrechetDistance;
+    ///
+    /// let a = LineString::from(vec![(0., 0.), (10., 0.)]);
+    /// let b = LineString::from(vec![(0., 1.), (1., 1.), (10., 1.)]);
+    ///
+    /// assert_eq!(a.frechet_distance(&b), 2f64.sqrt());
+    /// assert_eq!(a.frechet_distance_densified(&b, 1.), 1.);
+    /// ```
+    fn frechet_distance_densified(&self, rhs: &Rhs, max_segment_length: T) -> T;
+}
+
+impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn frechet_distance(&self, rhs: &LineString<T>) -> T {
+        discrete_frechet_distance(&self.0, &rhs.0)
+    }
+
+    fn frechet_distance_densified(&self, rhs: &LineString<T>, max_segment_length: T) -> T {
+        discrete_frechet_distance(
+            &densify_coords(&self.0, max_segment_length),
+            &densify_coords(&rhs.0, max_segment_length),
+        )
+    }
+}
+
+/// The discrete Fréchet distance of Eiter and Mannila, computed one row at a time
+fn discrete_frechet_distance<T>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> T
+where
+    T: Float,
+{
+    if a.is_empty() || b.is_empty() {
+        return T::zero();
+    }
+    let distance = |i: usize, j: usize| Point(a[i]).euclidean_distance(&Point(b[j]));
+    // row[j] holds the leash needed to reach a[i] and b[j] together
+    let mut row: Vec<T> = Vec::with_capacity(b.len());
+    for j in 0..b.len() {
+        let previous = if j == 0 { T::zero() } else { row[j - 1] };
+        row.push(previous.max(distance(0, j)));
+    }
+    for i in 1..a.len() {
+        let mut diagonal = row[0];
+        row[0] = row[0].max(distance(i, 0));
+        for j in 1..b.len() {
+            let reach = diagonal.min(row[j]).min(row[j - 1]);
+            diagonal = row[j];
+            row[j] = reach.max(distance(i, j));
+        }
+    }
+    row[b.len() - 1]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn identical_test() {
+        let a = LineString::from(vec![(0., 0.), (1., 2.), (3., 1.)]);
+        assert_eq!(a.frechet_distance(&a), 0.);
+    }
+
+    #[test]
+    fn reversed_test() {
+        let a = LineString::from(vec![(0., 0.), (5., 0.)]);
+        let b = LineString::from(vec![(5., 0.), (0., 0.)]);
+        assert_eq!(a.frechet_distance(&b), 5.);
+    }
+
+    #[test]
+    fn offset_test() {
+        let a = LineString::from(vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.)]);
+        let b = LineString::from(vec![(0., 1.), (1.5, 1.), (3., 1.)]);
+        assert_relative_eq!(a.frechet_distance(&b), 0.5f64.hypot(1.));
+        assert_relative_eq!(b.frechet_distance(&a), 0.5f64.hypot(1.));
+        // the continuous distance is 1
+        assert_relative_eq!(a.frechet_distance_densified(&b, 0.01), 1., epsilon = 1e-2);
+    }
+
+    #[test]
+    fn different_lengths_test() {
+        let a = LineString::from(vec![(0., 0.)]);
+        let b = LineString::from(vec![(0., 1.), (4., 4.), (0., -2.)]);
+        assert_relative_eq!(a.frechet_distance(&b), 32f64.sqrt());
+        assert_eq!(b.frechet_distance(&a), a.frechet_distance(&b));
+        assert_eq!(a.frechet_distance(&LineString(vec![])), 0.);
+    }
+}
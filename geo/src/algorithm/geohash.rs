@@ -0,0 +1,437 @@
+use std::{error, fmt};
+
+use algorithm::contains::Contains;
+use algorithm::intersects::Intersects;
+use num_traits::Float;
+use {Bbox, Point, Polygon};
+
+/// The characters of a geohash, each standing for five bits
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The reasons a geohash can fail to be encoded or decoded
+#[derive(PartialEq, Clone, Debug)]
+pub enum GeohashError {
+    /// The longitude wasn't between -180 and 180, or the latitude between -90 and 90
+    InvalidCoordinate,
+    /// The geohash contained a character which isn't one of its 32 digits
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeohashError::InvalidCoordinate => {
+                write!(f, "coordinate is not a valid longitude and latitude")
+            }
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character {:?}", c),
+        }
+    }
+}
+
+impl error::Error for GeohashError {
+    fn description(&self) -> &str {
+        "invalid geohash"
+    }
+}
+
+/// Encodes a point, whose coordinates are a longitude and latitude in degrees, as a geohash
+/// of `precision` characters.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::geohash::{decode, decode_bbox, encode};
+///
+/// let hash = encode(Point::new(-5.6, 42.6), 5).unwrap();
+/// assert_eq!(hash, "ezs42");
+///
+/// let cell = decode_bbox::<f64>(&hash).unwrap();
+/// assert_eq!((cell.xmin, cell.xmax), (-5.625, -5.5810546875));
+/// assert_eq!((cell.ymin, cell.ymax), (42.5830078125, 42.626953125));
+/// assert_eq!(decode(&hash).unwrap(), Point::new(-5.60302734375, 42.60498046875));
+/// ```
+pub fn encode<T>(point: Point<T>, precision: usize) -> Result<String, GeohashError>
+where
+    T: Float,
+{
+    let (lon, lat) = match (point.x().to_f64(), point.y().to_f64()) {
+        (Some(lon), Some(lat))
+            if (-180. ..=180.).contains(&lon) && (-90. ..=90.).contains(&lat) =>
+        {
+            (lon, lat)
+        }
+        _ => return Err(GeohashError::InvalidCoordinate),
+    };
+    let mut cell = world();
+    let mut hash = String::with_capacity(precision);
+    let mut index = 0;
+    for i in 0..precision * 5 {
+        // bits alternate between longitude and latitude, starting with longitude
+        let upper = if i % 2 == 0 {
+            halve(&mut cell.xmin, &mut cell.xmax, lon)
+        } else {
+            halve(&mut cell.ymin, &mut cell.ymax, lat)
+        };
+        index = index << 1 | upper as usize;
+        if i % 5 == 4 {
+            hash.push(BASE32[index] as char);
+            index = 0;
+        }
+    }
+    Ok(hash)
+}
+
+/// Narrows a range to the half containing `value`, returning whether it's the upper half
+fn halve(min: &mut f64, max: &mut f64, value: f64) -> bool {
+    let middle = (*min + *max) / 2.;
+    if value >= middle {
+        *min = middle;
+        true
+    } else {
+        *max = middle;
+        false
+    }
+}
+
+fn world() -> Bbox<f64> {
+    Bbox {
+        xmin: -180.,
+        xmax: 180.,
+        ymin: -90.,
+        ymax: 90.,
+    }
+}
+
+fn cell(hash: &str) -> Result<Bbox<f64>, GeohashError> {
+    let mut cell = world();
+    let mut i = 0;
+    for c in hash.chars() {
+        let index = BASE32
+            .iter()
+            .position(|&digit| digit as char == c)
+            .ok_or(GeohashError::InvalidCharacter(c))?;
+        for bit in (0..5).rev() {
+            let upper = index & (1 << bit) != 0;
+            let (min, max) = if i % 2 == 0 {
+                (&mut cell.xmin, &mut cell.xmax)
+            } else {
+                (&mut cell.ymin, &mut cell.ymax)
+            };
+            let middle = (*min + *max) / 2.;
+            if upper {
+                *min = middle;
+            } else {
+                *max = middle;
+            }
+            i += 1;
+        }
+    }
+    Ok(cell)
+}
+
+fn convert<T>(bbox: Bbox<f64>) -> Bbox<T>
+where
+    T: Float,
+{
+    Bbox {
+        xmin: T::from(bbox.xmin).unwrap(),
+        xmax: T::from(bbox.xmax).unwrap(),
+        ymin: T::from(bbox.ymin).unwrap(),
+        ymax: T::from(bbox.ymax).unwrap(),
+    }
+}
+
+/// Decodes a geohash to the cell it stands for, as longitudes and latitudes in degrees
+pub fn decode_bbox<T>(hash: &str) -> Result<Bbox<T>, GeohashError>
+where
+    T: Float,
+{
+    Ok(convert(cell(hash)?))
+}
+
+/// Decodes a geohash to the middle of the cell it stands for
+pub fn decode<T>(hash: &str) -> Result<Point<T>, GeohashError>
+where
+    T: Float,
+{
+    let cell = cell(hash)?;
+    Ok(Point::new(
+        T::from((cell.xmin + cell.xmax) / 2.).unwrap(),
+        T::from((cell.ymin + cell.ymax) / 2.).unwrap(),
+    ))
+}
+
+/// A direction from a geohash cell to one of its neighbours
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// How many cells east and north the direction goes
+    fn offset(self) -> (f64, f64) {
+        match self {
+            Direction::North => (0., 1.),
+            Direction::NorthEast => (1., 1.),
+            Direction::East => (1., 0.),
+            Direction::SouthEast => (1., -1.),
+            Direction::South => (0., -1.),
+            Direction::SouthWest => (-1., -1.),
+            Direction::West => (-1., 0.),
+            Direction::NorthWest => (-1., 1.),
+        }
+    }
+}
+
+/// The eight cells around a geohash cell, of the same precision.
+///
+/// Cells beyond a pole don't exist, so are `None`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Neighbours {
+    pub north: Option<String>,
+    pub north_east: Option<String>,
+    pub east: Option<String>,
+    pub south_east: Option<String>,
+    pub south: Option<String>,
+    pub south_west: Option<String>,
+    pub west: Option<String>,
+    pub north_west: Option<String>,
+}
+
+/// Returns the geohash of the cell next to `hash` in the given direction.
+///
+/// Cells wrap around the antimeridian, so the cell east of one at 180° longitude is at -180°.
+/// There is no cell beyond a pole, so `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::{neighbour, Direction};
+///
+/// assert_eq!(neighbour("ezs42", Direction::North).unwrap(), Some("ezs48".to_string()));
+/// assert_eq!(neighbour("ezs42", Direction::West).unwrap(), Some("ezefr".to_string()));
+/// ```
+pub fn neighbour(hash: &str, direction: Direction) -> Result<Option<String>, GeohashError> {
+    let cell = cell(hash)?;
+    let (east, north) = direction.offset();
+    let mut lon = (cell.xmin + cell.xmax) / 2. + east * (cell.xmax - cell.xmin);
+    let lat = (cell.ymin + cell.ymax) / 2. + north * (cell.ymax - cell.ymin);
+    if !(-90. ..=90.).contains(&lat) {
+        return Ok(None);
+    }
+    if lon > 180. {
+        lon -= 360.;
+    } else if lon < -180. {
+        lon += 360.;
+    }
+    encode(Point::new(lon, lat), hash.len()).map(Some)
+}
+
+/// Returns the geohashes of all eight cells around `hash`
+pub fn neighbours(hash: &str) -> Result<Neighbours, GeohashError> {
+    Ok(Neighbours {
+        north: neighbour(hash, Direction::North)?,
+        north_east: neighbour(hash, Direction::NorthEast)?,
+        east: neighbour(hash, Direction::East)?,
+        south_east: neighbour(hash, Direction::SouthEast)?,
+        south: neighbour(hash, Direction::South)?,
+        south_west: neighbour(hash, Direction::SouthWest)?,
+        west: neighbour(hash, Direction::West)?,
+        north_west: neighbour(hash, Direction::NorthWest)?,
+    })
+}
+
+/// Lists the geohashes which cover a geometry.
+pub trait GeohashCover<T>
+where
+    T: Float,
+{
+    /// Returns the fewest geohashes, of at most `precision` characters, whose cells cover all
+    /// the cells of `precision` characters which the geometry intersects, in order.
+    ///
+    /// Coordinates are longitudes and latitudes in degrees. Where every one of the 32 cells
+    /// making up a larger cell is needed, the larger cell's shorter geohash is used instead,
+    /// down to geohashes of one character, so covering the whole world gives all 32 of them.
+    /// Cells include their edges, so a geometry which only touches a cell needs it too. There
+    /// are no geohashes of no characters, so the cover is empty if `precision` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::geohash::{decode_bbox, GeohashCover};
+    ///
+    /// // just inside the cell "ezs", so touching all 32 of its parts but none of its neighbours
+    /// let cell = decode_bbox::<f64>("ezs").unwrap();
+    /// let (x0, x1, y0, y1) = (cell.xmin + 0.01, cell.xmax - 0.01, cell.ymin + 0.01, cell.ymax - 0.01);
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)]),
+    ///     vec![],
+    /// );
+    /// let cover = polygon.geohash_cover(4);
+    /// assert_eq!(cover, vec!["ezs"]);
+    /// ```
+    fn geohash_cover(&self, precision: usize) -> Vec<String>;
+}
+
+impl<T, G> GeohashCover<T> for G
+where
+    T: Float,
+    G: Intersects<Polygon<T>> + Contains<Polygon<T>>,
+{
+    fn geohash_cover(&self, precision: usize) -> Vec<String> {
+        let mut hashes = vec![];
+        if precision == 0 {
+            return hashes;
+        }
+        for &digit in BASE32.iter() {
+            cover(self, (digit as char).to_string(), precision, &mut hashes);
+        }
+        hashes
+    }
+}
+
+/// Adds the geohashes within `hash` which `geometry` needs to `hashes`, in order, returning
+/// whether `hash` itself was added
+fn cover<T, G>(geometry: &G, hash: String, precision: usize, hashes: &mut Vec<String>) -> bool
+where
+    T: Float,
+    G: Intersects<Polygon<T>> + Contains<Polygon<T>>,
+{
+    let bbox = convert(cell(&hash).unwrap()).to_polygon();
+    if !geometry.intersects(&bbox) {
+        return false;
+    }
+    // a cell inside the geometry needs all of its parts, so there's no need to look at them
+    if hash.len() >= precision || geometry.contains(&bbox) {
+        hashes.push(hash);
+        return true;
+    }
+    let start = hashes.len();
+    let mut all = true;
+    for &digit in BASE32.iter() {
+        let mut child = hash.clone();
+        child.push(digit as char);
+        all &= cover(geometry, child, precision, hashes);
+    }
+    // the whole cell is needed, so use it instead of its parts
+    if all {
+        hashes.truncate(start);
+        hashes.push(hash);
+    }
+    all
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Line, LineString};
+
+    #[test]
+    fn encode_decode_test() {
+        assert_eq!(encode(Point::new(180., 0.), 2).unwrap(), "xb");
+        assert_eq!(encode(Point::new(-180., -90.), 3).unwrap(), "000");
+        assert_eq!(encode(Point::new(0., 0.), 0).unwrap(), "");
+        assert_eq!(
+            encode(Point::new(0., 91.), 3),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            encode(Point::new(Float::nan(), 0.), 3),
+            Err(GeohashError::InvalidCoordinate)
+        );
+
+        let cell = decode_bbox::<f64>("b").unwrap();
+        assert_eq!(
+            cell,
+            Bbox {
+                xmin: -180.,
+                xmax: -135.,
+                ymin: 45.,
+                ymax: 90.,
+            }
+        );
+        assert_eq!(
+            decode::<f64>("ezs4a"),
+            Err(GeohashError::InvalidCharacter('a'))
+        );
+        // round trips through the middle of each cell
+        for hash in &["u4pruydqqvj", "9q8yyk8ytpxr", "s", "7zzzzzzzzzzz"] {
+            let point: Point<f64> = decode(hash).unwrap();
+            assert_eq!(encode(point, hash.len()).unwrap(), *hash);
+        }
+    }
+
+    #[test]
+    fn neighbours_test() {
+        assert_eq!(
+            neighbours("ezs42").unwrap(),
+            Neighbours {
+                north: Some("ezs48".to_string()),
+                north_east: Some("ezs49".to_string()),
+                east: Some("ezs43".to_string()),
+                south_east: Some("ezs41".to_string()),
+                south: Some("ezs40".to_string()),
+                south_west: Some("ezefp".to_string()),
+                west: Some("ezefr".to_string()),
+                north_west: Some("ezefx".to_string()),
+            }
+        );
+        // across the antimeridian, and beyond the pole
+        assert_eq!(
+            neighbour("x", Direction::East).unwrap(),
+            Some("8".to_string())
+        );
+        assert_eq!(neighbour("b", Direction::North).unwrap(), None);
+        assert_eq!(
+            neighbour("b", Direction::West).unwrap(),
+            Some("z".to_string())
+        );
+    }
+
+    #[test]
+    fn cover_test() {
+        // a line along the edge between two cells needs both
+        let line = Line::from([(-5.625, 42.59), (-5.625, 42.62)]);
+        assert_eq!(line.geohash_cover(5), vec!["ezefr", "ezs42"]);
+
+        // a polygon covering all of one cell, and part of another
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (-5.63, 42.58),
+                (-5.58, 42.58),
+                (-5.58, 42.63),
+                (-5.63, 42.63),
+                (-5.63, 42.58),
+            ]),
+            vec![],
+        );
+        let cover = polygon.geohash_cover(5);
+        assert!(cover.contains(&"ezs42".to_string()));
+        assert!(cover.iter().all(|hash| hash.len() == 5));
+
+        // the whole world, in one go
+        let world = Polygon::new(
+            LineString::from(vec![
+                (-180., -90.),
+                (180., -90.),
+                (180., 90.),
+                (-180., 90.),
+                (-180., -90.),
+            ]),
+            vec![],
+        );
+        let cover = world.geohash_cover(2);
+        assert_eq!(cover.len(), 32);
+        assert!(cover.iter().all(|hash| hash.len() == 1));
+        assert!(world.geohash_cover(0).is_empty());
+    }
+}
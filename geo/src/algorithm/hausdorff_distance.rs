@@ -0,0 +1,393 @@
+use algorithm::densify::densify_line;
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::prepared_geometry::PreparedGeometry;
+use algorithm::spatial_index::SpatialIndex;
+use num_traits::Float;
+use spade::SpadeFloat;
+use {
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+/// Returns the Hausdorff distance between two geometries.
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// Returns the discrete Hausdorff distance between two geometries.
+    ///
+    /// This is the greatest distance from a vertex of either geometry to the nearest point of
+    /// the other, using the same distances as `EuclideanDistance`: a vertex inside a polygon
+    /// is at no distance from it. The result is zero if either geometry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    ///
+    /// let route = LineString::from(vec![(0., 0.), (10., 0.)]);
+    /// let track = LineString::from(vec![(0., 1.), (5., -2.), (10., 0.5)]);
+    ///
+    /// assert_eq!(route.hausdorff_distance(&track), 2.);
+    /// ```
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T;
+
+    /// Returns the discrete Hausdorff distance between two geometries, after adding vertices
+    /// to their segments so that none is longer than `max_segment_length`.
+    ///
+    /// This approaches the continuous Hausdorff distance as `max_segment_length` is reduced.
+    /// Segments are left as they are if `max_segment_length` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, MultiPoint, Point};
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    ///
+    /// // the vertices of each lie on the other, but the middle of the line is 5 from its ends
+    /// let line = LineString::from(vec![(0., 0.), (10., 0.)]);
+    /// let ends = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+    ///
+    /// assert_eq!(line.hausdorff_distance(&ends), 0.);
+    /// assert_eq!(line.hausdorff_distance_densified(&ends, 1.), 5.);
+    /// ```
+    fn hausdorff_distance_densified(&self, rhs: &Rhs, max_segment_length: T) -> T;
+}
+
+/// The parts of a geometry, gathered from all of its members
+struct Parts<'a, T>
+where
+    T: Float + 'a,
+{
+    /// Points, and line strings with a single vertex
+    points: Vec<Point<T>>,
+    lines: Vec<Line<T>>,
+    polygons: Vec<&'a Polygon<T>>,
+}
+
+impl<'a, T> Parts<'a, T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_linestring(&mut self, linestring: &LineString<T>) {
+        if linestring.0.len() == 1 {
+            self.points.push(Point(linestring.0[0]));
+        }
+        self.lines.extend(linestring.lines());
+    }
+
+    fn add_geometry(&mut self, geometry: &'a Geometry<T>) {
+        match *geometry {
+            Geometry::Point(ref g) => g.add_parts(self),
+            Geometry::Line(ref g) => g.add_parts(self),
+            Geometry::LineString(ref g) => g.add_parts(self),
+            Geometry::Polygon(ref g) => g.add_parts(self),
+            Geometry::MultiPoint(ref g) => g.add_parts(self),
+            Geometry::MultiLineString(ref g) => g.add_parts(self),
+            Geometry::MultiPolygon(ref g) => g.add_parts(self),
+            Geometry::GeometryCollection(ref g) => g.add_parts(self),
+        }
+    }
+
+    /// Points sampled from the geometry: its vertices, and extra points along segments longer
+    /// than `max_segment_length`
+    fn samples(&self, max_segment_length: Option<T>) -> Vec<Coordinate<T>> {
+        let rings = self
+            .polygons
+            .iter()
+            .flat_map(|p| ::std::iter::once(&p.exterior).chain(p.interiors.iter()));
+        let mut samples: Vec<_> = self.points.iter().map(|p| p.0).collect();
+        for line in self
+            .lines
+            .iter()
+            .cloned()
+            .chain(rings.flat_map(|ring| ring.lines()))
+        {
+            samples.push(line.start);
+            samples.extend(densify_line(line, max_segment_length));
+            samples.push(line.end);
+        }
+        samples
+    }
+}
+
+/// A geometry prepared for finding the distances to many points
+struct Target<T>
+where
+    T: Float + SpadeFloat,
+{
+    linear: SpatialIndex<T, ()>,
+    areal: Option<PreparedGeometry<T>>,
+}
+
+impl<T> Target<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn new(parts: &Parts<T>) -> Target<T> {
+        let points = parts.points.iter().map(|&p| (Geometry::Point(p), ()));
+        let lines = parts.lines.iter().map(|&l| (Geometry::Line(l), ()));
+        Target {
+            linear: SpatialIndex::bulk_load(points.chain(lines)),
+            areal: if parts.polygons.is_empty() {
+                None
+            } else {
+                Some(PreparedGeometry::new(parts.polygons.iter().cloned()))
+            },
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.linear.is_empty() && self.areal.is_none()
+    }
+
+    fn distance(&self, point: &Point<T>) -> T {
+        let linear = match self.linear.nearest(point) {
+            Some(nearest) => nearest.distance(point),
+            None => T::max_value(),
+        };
+        match self.areal {
+            Some(ref areal) => linear.min(areal.euclidean_distance(point)),
+            None => linear,
+        }
+    }
+}
+
+/// Gathers the parts of a geometry for measuring Hausdorff distances
+trait HausdorffParts<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>);
+
+    fn parts<'a>(&'a self) -> Parts<'a, T> {
+        let mut parts = Parts {
+            points: vec![],
+            lines: vec![],
+            polygons: vec![],
+        };
+        self.add_parts(&mut parts);
+        parts
+    }
+}
+
+impl<T> HausdorffParts<T> for Point<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.points.push(*self);
+    }
+}
+
+impl<T> HausdorffParts<T> for Line<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.lines.push(*self);
+    }
+}
+
+impl<T> HausdorffParts<T> for LineString<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.add_linestring(self);
+    }
+}
+
+impl<T> HausdorffParts<T> for Polygon<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.polygons.push(self);
+    }
+}
+
+impl<T> HausdorffParts<T> for MultiPoint<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.points.extend(self.0.iter().cloned());
+    }
+}
+
+impl<T> HausdorffParts<T> for MultiLineString<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        for linestring in &self.0 {
+            parts.add_linestring(linestring);
+        }
+    }
+}
+
+impl<T> HausdorffParts<T> for MultiPolygon<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.polygons.extend(self.0.iter());
+    }
+}
+
+impl<T> HausdorffParts<T> for GeometryCollection<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        for geometry in &self.0 {
+            parts.add_geometry(geometry);
+        }
+    }
+}
+
+impl<T> HausdorffParts<T> for Geometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.add_geometry(self);
+    }
+}
+
+fn hausdorff_distance<T>(a: &Parts<T>, b: &Parts<T>, max_segment_length: Option<T>) -> T
+where
+    T: Float + SpadeFloat,
+{
+    let (target_a, target_b) = (Target::new(a), Target::new(b));
+    if target_a.is_empty() || target_b.is_empty() {
+        return T::zero();
+    }
+    let directed = |from: &Parts<T>, to: &Target<T>| {
+        from.samples(max_segment_length)
+            .into_iter()
+            .map(|c| to.distance(&Point(c)))
+            .fold(T::zero(), |accum, val| accum.max(val))
+    };
+    directed(a, &target_b).max(directed(b, &target_a))
+}
+
+macro_rules! hausdorff_distance_impl {
+    ($($from:ident),*) => {
+        hausdorff_distance_impl!(@each [$($from),*] [$($from),*]);
+    };
+    (@each [$($from:ident),*] $to:tt) => {
+        $(hausdorff_distance_impl!(@pairs $from $to);)*
+    };
+    (@pairs $from:ident [$($to:ident),*]) => {
+        $(
+            impl<T> HausdorffDistance<T, $to<T>> for $from<T>
+            where
+                T: Float + SpadeFloat,
+            {
+                fn hausdorff_distance(&self, rhs: &$to<T>) -> T {
+                    hausdorff_distance(&self.parts(), &rhs.parts(), None)
+                }
+
+                fn hausdorff_distance_densified(&self, rhs: &$to<T>, max_segment_length: T) -> T {
+                    hausdorff_distance(&self.parts(), &rhs.parts(), Some(max_segment_length))
+                }
+            }
+        )*
+    };
+}
+hausdorff_distance_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn points_test() {
+        let a = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+        let b = MultiPoint(vec![
+            Point::new(0., 0.),
+            Point::new(0., 0.5),
+            Point::new(5., 0.),
+        ]);
+        assert_eq!(a.hausdorff_distance(&b), 4.);
+        assert_eq!(b.hausdorff_distance(&a), 4.);
+        assert_eq!(
+            Point::new(1., 1.).hausdorff_distance(&Point::new(4., 5.)),
+            5.
+        );
+    }
+
+    #[test]
+    fn linestring_test() {
+        let a = LineString::from(vec![(0., 0.), (4., 0.)]);
+        let b = LineString::from(vec![(0., 1.), (2., 3.), (4., 1.)]);
+        assert_eq!(a.hausdorff_distance(&b), 3.);
+        let line = Line::from([(0., 0.), (4., 0.)]);
+        assert_eq!(line.hausdorff_distance(&b), 3.);
+        assert_eq!(a.hausdorff_distance(&line), 0.);
+    }
+
+    #[test]
+    fn polygon_test() {
+        let outer = square(0., 0., 10.);
+        let inner = square(2., 2., 2.);
+        // every vertex of `inner` is inside `outer`, and the far corner of `outer` is furthest
+        assert_relative_eq!(outer.hausdorff_distance(&inner), 6. * 2f64.sqrt());
+        assert_relative_eq!(inner.hausdorff_distance(&outer), 6. * 2f64.sqrt());
+        let point = Point::new(5., 5.);
+        assert_relative_eq!(point.hausdorff_distance(&outer), 50f64.sqrt());
+        assert_relative_eq!(
+            MultiPolygon(vec![inner.clone(), square(20., 0., 1.)]).hausdorff_distance(&outer),
+            11.
+        );
+    }
+
+    #[test]
+    fn geometry_test() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(0., 3.)),
+            Geometry::LineString(LineString::from(vec![(0., 0.), (4., 0.)])),
+        ]);
+        let line = Geometry::Line(Line::from([(0., 0.), (4., 0.)]));
+        assert_eq!(collection.hausdorff_distance(&line), 3.);
+        assert_eq!(line.hausdorff_distance(&collection), 3.);
+        assert_eq!(
+            collection.hausdorff_distance(&GeometryCollection(vec![])),
+            0.
+        );
+    }
+
+    #[test]
+    fn densified_test() {
+        let line = LineString::from(vec![(0., 0.), (10., 0.)]);
+        let ends = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+        assert_eq!(line.hausdorff_distance(&ends), 0.);
+        assert_eq!(line.hausdorff_distance_densified(&ends, 3.), 5.);
+        assert_relative_eq!(ends.hausdorff_distance_densified(&line, 4.), 10. / 3.);
+        // non-positive lengths leave the segments alone
+        assert_eq!(line.hausdorff_distance_densified(&ends, 0.), 0.);
+    }
+}
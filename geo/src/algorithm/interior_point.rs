@@ -0,0 +1,486 @@
+use algorithm::boundingbox::BoundingBox;
+use num_traits::Float;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use {
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+/// Returns a point which is guaranteed to lie on a geometry.
+pub trait InteriorPoint<T>
+where
+    T: Float,
+{
+    type Output;
+
+    /// Returns a point which lies on the geometry, like PostGIS's `ST_PointOnSurface`.
+    ///
+    /// Unlike the centroid, this always intersects the geometry. For polygons, it's the middle
+    /// of the widest span of the polygon along a horizontal line through the middle of its
+    /// bounding box. For line strings, it's the vertex nearest the centroid, preferring
+    /// vertices other than the ends, and for points, the one nearest their mean. A collection
+    /// uses the parts of the highest dimension it has, so polygons without any area count
+    /// as lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::interior_point::InteriorPoint;
+    ///
+    /// // a U shape
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![
+    ///         (0., 0.), (6., 0.), (6., 6.), (4., 6.), (4., 2.), (2., 2.), (2., 6.), (0., 6.), (0., 0.),
+    ///     ]),
+    ///     vec![],
+    /// );
+    ///
+    /// let point = polygon.interior_point().unwrap();
+    /// assert!(polygon.contains(&point));
+    /// ```
+    fn interior_point(&self) -> Self::Output;
+}
+
+impl<T> InteriorPoint<T> for Point<T>
+where
+    T: Float,
+{
+    type Output = Point<T>;
+
+    fn interior_point(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl<T> InteriorPoint<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = Point<T>;
+
+    // both ends are as near the middle
+    fn interior_point(&self) -> Self::Output {
+        Point(self.start)
+    }
+}
+
+impl<T> InteriorPoint<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+impl<T> InteriorPoint<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn interior_point(&self) -> Self::Output {
+        self.candidates().interior_point()
+    }
+}
+
+/// The parts of a geometry which the interior point may be chosen from
+struct Candidates<'a, T>
+where
+    T: Float + 'a,
+{
+    points: Vec<Point<T>>,
+    lines: Vec<Cow<'a, [Coordinate<T>]>>,
+    polygons: Vec<&'a Polygon<T>>,
+}
+
+impl<'a, T> Candidates<'a, T>
+where
+    T: Float,
+{
+    fn interior_point(&self) -> Option<Point<T>> {
+        let widest = self
+            .polygons
+            .iter()
+            .filter_map(|polygon| widest_span(polygon))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+        if let Some((point, _)) = widest {
+            return Some(point);
+        }
+        let lines: Vec<&[Coordinate<T>]> = self
+            .lines
+            .iter()
+            .map(|line| &line[..])
+            .chain(self.polygons.iter().map(|polygon| &polygon.exterior.0[..]))
+            .collect();
+        line_vertex(&lines).or_else(|| {
+            let mean = mean(self.points.iter().map(|point| point.0))?;
+            nearest(self.points.iter().cloned(), mean)
+        })
+    }
+}
+
+trait InteriorCandidates<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>);
+
+    fn candidates<'a>(&'a self) -> Candidates<'a, T> {
+        let mut candidates = Candidates {
+            points: vec![],
+            lines: vec![],
+            polygons: vec![],
+        };
+        self.add_candidates(&mut candidates);
+        candidates
+    }
+}
+
+impl<T> InteriorCandidates<T> for LineString<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        candidates.lines.push(Cow::Borrowed(&self.0));
+    }
+}
+
+impl<T> InteriorCandidates<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        candidates.polygons.push(self);
+    }
+}
+
+impl<T> InteriorCandidates<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        candidates.points.extend(self.0.iter().cloned());
+    }
+}
+
+impl<T> InteriorCandidates<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        for linestring in &self.0 {
+            linestring.add_candidates(candidates);
+        }
+    }
+}
+
+impl<T> InteriorCandidates<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        candidates.polygons.extend(self.0.iter());
+    }
+}
+
+impl<T> InteriorCandidates<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        for geometry in &self.0 {
+            geometry.add_candidates(candidates);
+        }
+    }
+}
+
+impl<T> InteriorCandidates<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn add_candidates<'a>(&'a self, candidates: &mut Candidates<'a, T>) {
+        match *self {
+            Geometry::Point(point) => candidates.points.push(point),
+            Geometry::Line(line) => candidates
+                .lines
+                .push(Cow::Owned(vec![line.start, line.end])),
+            Geometry::LineString(ref ls) => ls.add_candidates(candidates),
+            Geometry::Polygon(ref polygon) => polygon.add_candidates(candidates),
+            Geometry::MultiPoint(ref mp) => mp.add_candidates(candidates),
+            Geometry::MultiLineString(ref mls) => mls.add_candidates(candidates),
+            Geometry::MultiPolygon(ref mp) => mp.add_candidates(candidates),
+            Geometry::GeometryCollection(ref gc) => gc.add_candidates(candidates),
+        }
+    }
+}
+
+/// The middle of the widest span of a polygon along a horizontal line, and its width.
+///
+/// The line runs through the middle of the bounding box, but avoids the height of every
+/// vertex, so that it crosses edges cleanly.
+fn widest_span<T>(polygon: &Polygon<T>) -> Option<(Point<T>, T)>
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    let bbox = polygon.bbox()?;
+    let middle = (bbox.ymin + bbox.ymax) / two;
+    let rings = || ::std::iter::once(&polygon.exterior).chain(polygon.interiors.iter());
+    let (mut below, mut above) = (bbox.ymin, bbox.ymax);
+    for coord in rings().flat_map(|ring| ring.0.iter()) {
+        if coord.y <= middle && coord.y > below {
+            below = coord.y;
+        } else if coord.y > middle && coord.y < above {
+            above = coord.y;
+        }
+    }
+    if below == above {
+        return None;
+    }
+    let y = (below + above) / two;
+    let mut crossings: Vec<T> = rings()
+        .flat_map(|ring| ring.0.windows(2))
+        .filter(|edge| (edge[0].y > y) != (edge[1].y > y))
+        .map(|edge| {
+            let (a, b) = (edge[0], edge[1]);
+            a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y)
+        })
+        .collect();
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    crossings
+        .chunks(2)
+        .filter(|span| span.len() == 2 && span[1] > span[0])
+        .map(|span| (Point::new((span[0] + span[1]) / two, y), span[1] - span[0]))
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
+
+/// The vertex of some line strings nearest their centroid, other than their ends if possible
+fn line_vertex<T>(lines: &[&[Coordinate<T>]]) -> Option<Point<T>>
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    let (mut length, mut x, mut y) = (T::zero(), T::zero(), T::zero());
+    for segment in lines.iter().flat_map(|line| line.windows(2)) {
+        let (a, b) = (segment[0], segment[1]);
+        let segment_length = (b.x - a.x).hypot(b.y - a.y);
+        length = length + segment_length;
+        x = x + (a.x + b.x) / two * segment_length;
+        y = y + (a.y + b.y) / two * segment_length;
+    }
+    let centroid = if length > T::zero() {
+        Coordinate {
+            x: x / length,
+            y: y / length,
+        }
+    } else {
+        mean(lines.iter().flat_map(|line| line.iter().cloned()))?
+    };
+    let inner = lines
+        .iter()
+        .filter(|line| line.len() > 2)
+        .flat_map(|line| line[1..line.len() - 1].iter())
+        .map(|coord| Point(*coord));
+    nearest(inner, centroid).or_else(|| {
+        let ends = lines
+            .iter()
+            .filter(|line| !line.is_empty())
+            .flat_map(|line| vec![Point(line[0]), Point(line[line.len() - 1])]);
+        nearest(ends, centroid)
+    })
+}
+
+fn mean<T, I>(coords: I) -> Option<Coordinate<T>>
+where
+    T: Float,
+    I: Iterator<Item = Coordinate<T>>,
+{
+    let (count, x, y) = coords.fold((0, T::zero(), T::zero()), |(count, x, y), coord| {
+        (count + 1, x + coord.x, y + coord.y)
+    });
+    if count == 0 {
+        return None;
+    }
+    let count = T::from(count).unwrap();
+    Some(Coordinate {
+        x: x / count,
+        y: y / count,
+    })
+}
+
+fn nearest<T, I>(points: I, target: Coordinate<T>) -> Option<Point<T>>
+where
+    T: Float,
+    I: Iterator<Item = Point<T>>,
+{
+    let distance = |point: &Point<T>| (point.x() - target.x).hypot(point.y() - target.y);
+    points.min_by(|a, b| {
+        distance(a)
+            .partial_cmp(&distance(b))
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::contains::Contains;
+
+    fn polygon(coords: Vec<(f64, f64)>) -> Polygon<f64> {
+        Polygon::new(LineString::from(coords), vec![])
+    }
+
+    #[test]
+    fn polygon_test() {
+        // a C shape, with a vertex at the height of the middle of its bounding box
+        let c = polygon(vec![
+            (0., 0.),
+            (6., 0.),
+            (6., 2.),
+            (2., 2.),
+            (2., 3.),
+            (1., 3.),
+            (2., 3.),
+            (2., 4.),
+            (6., 4.),
+            (6., 6.),
+            (0., 6.),
+            (0., 0.),
+        ]);
+        let point = c.interior_point().unwrap();
+        assert!(c.contains(&point));
+        assert_eq!(point, Point::new(1., 3.5));
+    }
+
+    #[test]
+    fn hole_test() {
+        let exterior = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]);
+        let hole = LineString::from(vec![(1., 1.), (8., 1.), (8., 9.), (1., 9.), (1., 1.)]);
+        let square = Polygon::new(exterior, vec![hole]);
+        let point = square.interior_point().unwrap();
+        assert!(square.contains(&point));
+        assert_eq!(point, Point::new(9., 5.));
+    }
+
+    #[test]
+    fn multipolygon_test() {
+        let small = polygon(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+        let large = polygon(vec![(5., 5.), (9., 5.), (9., 9.), (5., 9.), (5., 5.)]);
+        let multi = MultiPolygon(vec![small, large]);
+        assert_eq!(multi.interior_point(), Some(Point::new(7., 7.)));
+    }
+
+    #[test]
+    fn linestring_test() {
+        let ls = LineString::from(vec![(0., 0.), (1., 5.), (4., 0.), (5., 0.)]);
+        assert_eq!(ls.interior_point(), Some(Point::new(1., 5.)));
+        let ls = LineString::from(vec![(0., 0.), (4., 0.)]);
+        assert_eq!(ls.interior_point(), Some(Point::new(0., 0.)));
+        let mls = MultiLineString(vec![
+            ls,
+            LineString::from(vec![(0., 1.), (2., 1.), (4., 1.)]),
+        ]);
+        assert_eq!(mls.interior_point(), Some(Point::new(2., 1.)));
+        assert_eq!(LineString::<f64>(vec![]).interior_point(), None);
+    }
+
+    #[test]
+    fn points_test() {
+        let mp = MultiPoint(vec![
+            Point::new(0., 0.),
+            Point::new(3., 3.),
+            Point::new(10., 0.),
+        ]);
+        assert_eq!(mp.interior_point(), Some(Point::new(3., 3.)));
+        assert_eq!(Point::new(1., 2.).interior_point(), Point::new(1., 2.));
+        assert_eq!(
+            Line::from([(1., 2.), (3., 4.)]).interior_point(),
+            Point::new(1., 2.)
+        );
+        assert_eq!(MultiPoint::<f64>(vec![]).interior_point(), None);
+    }
+
+    #[test]
+    fn collection_test() {
+        let square = polygon(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let flat = polygon(vec![(5., 5.), (9., 5.), (5., 5.)]);
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::LineString(LineString::from(vec![(0., 5.), (5., 10.)])),
+            Geometry::Polygon(square.clone()),
+        ]);
+        assert_eq!(gc.interior_point(), Some(Point::new(1., 1.)));
+        // a polygon without area counts as a line
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::Polygon(flat),
+        ]);
+        assert_eq!(gc.interior_point(), Some(Point::new(9., 5.)));
+        assert_eq!(
+            Geometry::Polygon(square).interior_point(),
+            Some(Point::new(1., 1.))
+        );
+        assert_eq!(GeometryCollection::<f64>(vec![]).interior_point(), None);
+    }
+}
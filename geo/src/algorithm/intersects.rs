@@ -1,12 +1,21 @@
-use algorithm::contains::Contains;
+use algorithm::contains::{get_position, Contains, PositionPoint};
+use algorithm::line_intersection::line_intersection;
+use algorithm::predicates::{orient2d, Orientation};
 use num_traits::Float;
-use {Bbox, Line, LineString, Point, Polygon};
+use {
+    Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
 
 /// Checks if the geometry A intersects the geometry B.
 
 pub trait Intersects<Rhs = Self> {
     /// Checks if the geometry A intersects the geometry B.
     ///
+    /// A `MultiPoint`, `MultiLineString`, `MultiPolygon` or `GeometryCollection` intersects
+    /// a geometry if any of its members does, and a `Geometry` intersects it if the geometry
+    /// it wraps does. `a.intersects(&b)` is always the same as `b.intersects(&a)`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -29,37 +38,23 @@ where
     T: Float,
 {
     fn intersects(&self, p: &Point<T>) -> bool {
-        let tx = if self.dx() == T::zero() {
-            None
-        } else {
-            Some((p.x() - self.start.x) / self.dx())
-        };
-        let ty = if self.dy() == T::zero() {
-            None
-        } else {
-            Some((p.y() - self.start.y) / self.dy())
-        };
-        match (tx, ty) {
-            (None, None) => {
-                // Degenerate line
-                p.0 == self.start
-            }
-            (Some(t), None) => {
-                // Horizontal line
-                p.y() == self.start.y && T::zero() <= t && t <= T::one()
-            }
-            (None, Some(t)) => {
-                // Vertical line
-                p.x() == self.start.x && T::zero() <= t && t <= T::one()
-            }
-            (Some(t_x), Some(t_y)) => {
-                // All other lines
-                (t_x - t_y).abs() <= T::epsilon() && T::zero() <= t_x && t_x <= T::one()
-            }
-        }
+        // the point must be collinear with the line, and lie within its bounding box
+        orient2d(self.start, self.end, p.0) == Orientation::Collinear
+            && point_in_segment_bbox(p.0, self)
     }
 }
 
+/// Checks whether a coordinate lies within the bounding box of a line segment
+fn point_in_segment_bbox<T>(c: Coordinate<T>, line: &Line<T>) -> bool
+where
+    T: Float,
+{
+    c.x >= line.start.x.min(line.end.x)
+        && c.x <= line.start.x.max(line.end.x)
+        && c.y >= line.start.y.min(line.end.y)
+        && c.y <= line.start.y.max(line.end.y)
+}
+
 impl<T> Intersects<Line<T>> for Point<T>
 where
     T: Float,
@@ -74,30 +69,7 @@ where
     T: Float,
 {
     fn intersects(&self, line: &Line<T>) -> bool {
-        // Using Cramer's Rule:
-        // https://en.wikipedia.org/wiki/Intersection_%28Euclidean_geometry%29#Two_line_segments
-        let a1 = self.dx();
-        let a2 = self.dy();
-        let b1 = -line.dx();
-        let b2 = -line.dy();
-        let c1 = line.start.x - self.start.x;
-        let c2 = line.start.y - self.start.y;
-
-        let d = a1 * b2 - a2 * b1;
-        if d == T::zero() {
-            let (self_start, self_end) = self.points();
-            let (other_start, other_end) = line.points();
-            // lines are parallel
-            // return true iff at least one endpoint intersects the other line
-            self_start.intersects(line)
-                || self_end.intersects(line)
-                || other_start.intersects(self)
-                || other_end.intersects(self)
-        } else {
-            let s = (c1 * b2 - c2 * b1) / d;
-            let t = (a1 * c2 - a2 * c1) / d;
-            (T::zero() <= s) && (s <= T::one()) && (T::zero() <= t) && (t <= T::one())
-        }
+        line_intersection(*self, *line).is_some()
     }
 }
 
@@ -144,31 +116,12 @@ impl<T> Intersects<LineString<T>> for LineString<T>
 where
     T: Float,
 {
-    // See: https://github.com/brandonxiang/geojson-python-utils/blob/33b4c00c6cf27921fb296052d0c0341bd6ca1af2/geojson_utils.py
     fn intersects(&self, linestring: &LineString<T>) -> bool {
         if self.0.is_empty() || linestring.0.is_empty() {
             return false;
         }
-        for a in self.lines() {
-            for b in linestring.lines() {
-                let u_b = b.dy() * a.dx() - b.dx() * a.dy();
-                if u_b == T::zero() {
-                    continue;
-                }
-                let ua_t = b.dx() * (a.start.y - b.start.y) - b.dy() * (a.start.x - b.start.x);
-                let ub_t = a.dx() * (a.start.y - b.start.y) - a.dy() * (a.start.x - b.start.x);
-                let u_a = ua_t / u_b;
-                let u_b = ub_t / u_b;
-                if (T::zero() <= u_a)
-                    && (u_a <= T::one())
-                    && (T::zero() <= u_b)
-                    && (u_b <= T::one())
-                {
-                    return true;
-                }
-            }
-        }
-        false
+        self.lines()
+            .any(|a| linestring.lines().any(|b| a.intersects(&b)))
     }
 }
 
@@ -206,15 +159,11 @@ where
     T: Float,
 {
     fn intersects(&self, bbox: &Bbox<T>) -> bool {
-        // line intersects inner or outer polygon edge
-        if bbox.contains(self) {
-            false
-        } else {
-            (self.xmin >= bbox.xmin && self.xmin <= bbox.xmax
-                || self.xmax >= bbox.xmin && self.xmax <= bbox.xmax)
-                && (self.ymin >= bbox.ymin && self.ymin <= bbox.ymax
-                    || self.ymax >= bbox.ymin && self.ymax <= bbox.ymax)
-        }
+        // the boxes overlap, or touch, along both axes
+        self.xmin <= bbox.xmax
+            && bbox.xmin <= self.xmax
+            && self.ymin <= bbox.ymax
+            && bbox.ymin <= self.ymax
     }
 }
 
@@ -232,17 +181,7 @@ where
     T: Float,
 {
     fn intersects(&self, bbox: &Bbox<T>) -> bool {
-        let p = Polygon::new(
-            LineString::from(vec![
-                (bbox.xmin, bbox.ymin),
-                (bbox.xmin, bbox.ymax),
-                (bbox.xmax, bbox.ymax),
-                (bbox.xmax, bbox.ymin),
-                (bbox.xmin, bbox.ymin),
-            ]),
-            vec![],
-        );
-        self.intersects(&p)
+        self.intersects(&bbox.to_polygon())
     }
 }
 
@@ -259,10 +198,217 @@ where
     }
 }
 
+impl<T> Intersects<Point<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self == p
+    }
+}
+
+impl<T> Intersects<Point<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self.0.contains(&p.0) || self.lines().any(|line| line.intersects(p))
+    }
+}
+
+impl<T> Intersects<LineString<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, linestring: &LineString<T>) -> bool {
+        linestring.intersects(self)
+    }
+}
+
+impl<T> Intersects<Point<T>> for Polygon<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        // unlike containment, a point on the boundary counts
+        match get_position(*p, &self.exterior) {
+            PositionPoint::Outside => false,
+            PositionPoint::OnBoundary => true,
+            PositionPoint::Inside => self
+                .interiors
+                .iter()
+                .all(|ring| get_position(*p, ring) != PositionPoint::Inside),
+        }
+    }
+}
+
+impl<T> Intersects<Polygon<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, polygon: &Polygon<T>) -> bool {
+        polygon.intersects(self)
+    }
+}
+
+impl<T> Intersects<Point<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self.contains(p)
+    }
+}
+
+impl<T> Intersects<Bbox<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bbox: &Bbox<T>) -> bool {
+        bbox.contains(self)
+    }
+}
+
+impl<T> Intersects<Bbox<T>> for Line<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bbox: &Bbox<T>) -> bool {
+        self.intersects(&bbox.to_polygon())
+    }
+}
+
+impl<T> Intersects<Line<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn intersects(&self, line: &Line<T>) -> bool {
+        line.intersects(self)
+    }
+}
+
+impl<T> Intersects<Bbox<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bbox: &Bbox<T>) -> bool {
+        self.intersects(&bbox.to_polygon())
+    }
+}
+
+impl<T> Intersects<LineString<T>> for Bbox<T>
+where
+    T: Float,
+{
+    fn intersects(&self, linestring: &LineString<T>) -> bool {
+        linestring.intersects(self)
+    }
+}
+
+// Collections intersect a geometry if any of their members do
+macro_rules! intersects_members {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> Intersects<$rhs<T>> for $lhs<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    self.0.iter().any(|member| member.intersects(rhs))
+                }
+            }
+        )*)*
+    };
+}
+
+intersects_members! {
+    MultiPoint => [Point, Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+    MultiLineString => [Point, Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+    MultiPolygon => [Point, Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+    GeometryCollection => [Point, Line, LineString, Polygon, Bbox, MultiPoint, MultiLineString,
+        MultiPolygon, GeometryCollection, Geometry];
+}
+
+// The other way round, for single geometries
+macro_rules! intersects_reversed {
+    ($($lhs:ident => [$($rhs:ident),*];)*) => {
+        $($(
+            impl<T> Intersects<$rhs<T>> for $lhs<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    rhs.intersects(self)
+                }
+            }
+        )*)*
+    };
+}
+
+intersects_reversed! {
+    Point => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Line => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    LineString => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Polygon => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+    Bbox => [MultiPoint, MultiLineString, MultiPolygon, GeometryCollection];
+}
+
+// A Geometry intersects whatever the geometry it wraps does
+macro_rules! intersects_geometry {
+    ($($other:ident),*) => {
+        $(
+            impl<T> Intersects<$other<T>> for Geometry<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$other<T>) -> bool {
+                    match *self {
+                        Geometry::Point(ref g) => g.intersects(rhs),
+                        Geometry::Line(ref g) => g.intersects(rhs),
+                        Geometry::LineString(ref g) => g.intersects(rhs),
+                        Geometry::Polygon(ref g) => g.intersects(rhs),
+                        Geometry::MultiPoint(ref g) => g.intersects(rhs),
+                        Geometry::MultiLineString(ref g) => g.intersects(rhs),
+                        Geometry::MultiPolygon(ref g) => g.intersects(rhs),
+                        Geometry::GeometryCollection(ref g) => g.intersects(rhs),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+intersects_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    Bbox,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry
+);
+
+intersects_reversed! {
+    Point => [Geometry];
+    Line => [Geometry];
+    LineString => [Geometry];
+    Polygon => [Geometry];
+    Bbox => [Geometry];
+}
+
 #[cfg(test)]
 mod test {
     use algorithm::intersects::Intersects;
-    use {Bbox, Coordinate, Line, LineString, Point, Polygon};
+    use {
+        Bbox, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiPoint, Point,
+        Polygon,
+    };
     /// Tests: intersection LineString and LineString
     #[test]
     fn empty_linestring1_test() {
@@ -557,8 +703,8 @@ mod test {
             ymin: 0.,
             ymax: 30.,
         };
-        assert_eq!(false, bbox_xl.intersects(&bbox_sm));
-        assert_eq!(false, bbox_sm.intersects(&bbox_xl));
+        assert_eq!(true, bbox_xl.intersects(&bbox_sm));
+        assert_eq!(true, bbox_sm.intersects(&bbox_xl));
         assert_eq!(true, bbox_sm.intersects(&bbox_s2));
         assert_eq!(true, bbox_s2.intersects(&bbox_sm));
     }
@@ -655,4 +801,44 @@ mod test {
         assert!(!line0.intersects(&poly2));
         assert!(!poly2.intersects(&line0));
     }
+    #[test]
+    fn point_intersects_polygon_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (6., 0.), (6., 6.), (0., 6.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (4., 2.),
+                (4., 4.),
+                (2., 4.),
+                (2., 2.),
+            ])],
+        );
+        // inside, on either boundary, in the hole, and outside
+        assert!(poly.intersects(&Point::new(1., 1.)));
+        assert!(poly.intersects(&Point::new(6., 3.)));
+        assert!(Point::new(2., 3.).intersects(&poly));
+        assert!(!poly.intersects(&Point::new(3., 3.)));
+        assert!(!Point::new(7., 3.).intersects(&poly));
+    }
+    #[test]
+    fn collections_intersect_test() {
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(5., 5.)]);
+        let line = Line::from([(4., 6.), (6., 4.)]);
+        let bbox = Bbox {
+            xmin: -1.,
+            xmax: 1.,
+            ymin: -1.,
+            ymax: 1.,
+        };
+        assert!(points.intersects(&line));
+        assert!(line.intersects(&points));
+        assert!(bbox.intersects(&points));
+        let collection = GeometryCollection(vec![
+            Geometry::LineString(LineString::from(vec![(10., 0.), (10., 10.)])),
+            Geometry::MultiPoint(points),
+        ]);
+        assert!(collection.intersects(&Geometry::Line(line)));
+        assert!(!collection.intersects(&Point::new(3., 3.)));
+        assert!(!GeometryCollection::<f64>(vec![]).intersects(&bbox));
+    }
 }
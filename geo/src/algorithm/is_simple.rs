@@ -0,0 +1,405 @@
+use std::collections::BTreeMap;
+
+use algorithm::line_intersection::LineIntersection;
+use algorithm::segment_intersections::{SegmentIntersection, SelfIntersections};
+use num_traits::Float;
+use {LineString, MultiLineString, Point, Polygon};
+
+/// A reason for a line string or ring not being simple
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Simplicity<T>
+where
+    T: Float,
+{
+    /// The vertex at `vertex` is identical to the one before it
+    RepeatedVertex {
+        /// The index of the line string or ring, as described in [`IsSimple`](trait.IsSimple.html)
+        component: usize,
+        /// The index of the repeated vertex
+        vertex: usize,
+        point: Point<T>,
+    },
+    /// Two segments meet other than at the vertex they share
+    SelfIntersection {
+        /// The index of the line string or ring, as described in [`IsSimple`](trait.IsSimple.html)
+        component: usize,
+        /// The segments are indexed as in `LineString::lines()`
+        intersection: SegmentIntersection<T>,
+    },
+    /// Two line strings or rings cross, overlap, or touch at more than one point
+    Intersection {
+        /// The indices of the two line strings or rings, the lower first
+        components: (usize, usize),
+        /// `first` indexes a segment of the first line string or ring, and `second` one of the
+        /// second, as in `LineString::lines()`
+        intersection: SegmentIntersection<T>,
+    },
+}
+
+/// Checks whether line strings and polygon rings are simple
+///
+/// A line string is simple if no vertex is repeated consecutively, and no two of its segments
+/// meet other than consecutive segments at their shared vertex. The first and last segments of
+/// a closed line string may also meet at its start point.
+pub trait IsSimple<T>
+where
+    T: Float,
+{
+    /// Returns the repeated vertices and self-intersections which keep the geometry from being
+    /// simple.
+    ///
+    /// The line strings of a `MultiLineString` are checked against each other as well as on
+    /// their own, with `component` giving their index, and so are the rings of a `Polygon`,
+    /// with the exterior as component `0` and the interiors numbered from `1`. Two components
+    /// may touch at a single point, such as a hole touching its shell, but not cross, overlap
+    /// or touch anywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::is_simple::{IsSimple, Simplicity};
+    /// use geo::algorithm::line_intersection::LineIntersection;
+    ///
+    /// // a bow tie, with a repeated vertex
+    /// let linestring =
+    ///     LineString::from(vec![(0., 0.), (2., 2.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+    /// let problems = linestring.simplicity();
+    /// assert_eq!(problems.len(), 2);
+    /// assert_eq!(
+    ///     problems[0],
+    ///     Simplicity::RepeatedVertex {
+    ///         component: 0,
+    ///         vertex: 2,
+    ///         point: Point::new(2., 2.),
+    ///     }
+    /// );
+    /// match problems[1] {
+    ///     Simplicity::SelfIntersection { intersection, .. } => {
+    ///         assert_eq!((intersection.first, intersection.second), (0, 3));
+    ///         assert_eq!(
+    ///             intersection.intersection,
+    ///             LineIntersection::SinglePoint {
+    ///                 intersection: Point::new(1., 1.),
+    ///                 is_proper: true,
+    ///             }
+    ///         );
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    fn simplicity(&self) -> Vec<Simplicity<T>>;
+
+    /// Checks whether the geometry is simple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::is_simple::IsSimple;
+    ///
+    /// let square = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+    /// assert!(square.is_simple());
+    ///
+    /// let bow_tie = LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 1.), (0., 0.)]);
+    /// assert!(!bow_tie.is_simple());
+    /// ```
+    fn is_simple(&self) -> bool {
+        self.simplicity().is_empty()
+    }
+}
+
+impl<T> IsSimple<T> for LineString<T>
+where
+    T: Float,
+{
+    fn simplicity(&self) -> Vec<Simplicity<T>> {
+        simplicity(&[self], self.self_intersections())
+    }
+}
+
+impl<T> IsSimple<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn simplicity(&self) -> Vec<Simplicity<T>> {
+        simplicity(
+            &self.0.iter().collect::<Vec<_>>(),
+            self.self_intersections(),
+        )
+    }
+}
+
+impl<T> IsSimple<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn simplicity(&self) -> Vec<Simplicity<T>> {
+        let rings: Vec<_> = ::std::iter::once(&self.exterior)
+            .chain(self.interiors.iter())
+            .collect();
+        simplicity(&rings, self.self_intersections())
+    }
+}
+
+/// Sorts the intersections of the concatenated segments of `components` into those within a
+/// component and those between two, adding the repeated vertices of each component
+fn simplicity<T>(
+    components: &[&LineString<T>],
+    intersections: Vec<SegmentIntersection<T>>,
+) -> Vec<Simplicity<T>>
+where
+    T: Float,
+{
+    // the component each segment belongs to, and its index there
+    let mut locations = vec![];
+    for (component, linestring) in components.iter().enumerate() {
+        locations.extend((0..linestring.lines().count()).map(|segment| (component, segment)));
+    }
+    let mut within = vec![vec![]; components.len()];
+    let mut between = BTreeMap::new();
+    for i in intersections {
+        let ((a, first), (b, second)) = (locations[i.first], locations[i.second]);
+        let intersection = SegmentIntersection { first, second, ..i };
+        if a == b {
+            within[a].push(Simplicity::SelfIntersection {
+                component: a,
+                intersection,
+            });
+        } else {
+            between
+                .entry((a, b))
+                .or_insert_with(Vec::new)
+                .push(intersection);
+        }
+    }
+    let mut problems = vec![];
+    for (component, (linestring, crossings)) in components.iter().zip(within).enumerate() {
+        problems.extend(repeated_vertices(component, linestring));
+        problems.extend(crossings);
+    }
+    for (components, intersections) in between {
+        // touching at a single point is fine
+        let touch = touching_point(&intersections[0]);
+        if touch.is_some() && intersections.iter().all(|i| touching_point(i) == touch) {
+            continue;
+        }
+        problems.extend(
+            intersections
+                .into_iter()
+                .map(|intersection| Simplicity::Intersection {
+                    components,
+                    intersection,
+                }),
+        );
+    }
+    problems
+}
+
+/// The point at which two segments touch, if they meet at a single point without crossing
+fn touching_point<T>(intersection: &SegmentIntersection<T>) -> Option<Point<T>>
+where
+    T: Float,
+{
+    match intersection.intersection {
+        LineIntersection::SinglePoint {
+            intersection,
+            is_proper: false,
+        } => Some(intersection),
+        _ => None,
+    }
+}
+
+fn repeated_vertices<T>(component: usize, linestring: &LineString<T>) -> Vec<Simplicity<T>>
+where
+    T: Float,
+{
+    linestring
+        .0
+        .windows(2)
+        .enumerate()
+        .filter(|&(_, pair)| pair[0] == pair[1])
+        .map(|(i, pair)| Simplicity::RepeatedVertex {
+            component,
+            vertex: i + 1,
+            point: Point(pair[1]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Line;
+
+    #[test]
+    fn simple_linestring_test() {
+        let open = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+        assert!(open.is_simple());
+        let closed = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+        assert!(closed.is_simple());
+        let empty: LineString<f64> = LineString(vec![]);
+        assert!(empty.is_simple());
+    }
+
+    #[test]
+    fn touching_linestring_test() {
+        // returns to its second vertex without crossing itself
+        let linestring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (1., 1.), (2., 0.)]);
+        assert_eq!(
+            linestring.simplicity(),
+            vec![
+                Simplicity::SelfIntersection {
+                    component: 0,
+                    intersection: SegmentIntersection {
+                        first: 0,
+                        second: 3,
+                        intersection: LineIntersection::SinglePoint {
+                            intersection: Point::new(2., 0.),
+                            is_proper: false,
+                        },
+                    },
+                },
+                Simplicity::SelfIntersection {
+                    component: 0,
+                    intersection: SegmentIntersection {
+                        first: 1,
+                        second: 3,
+                        intersection: LineIntersection::SinglePoint {
+                            intersection: Point::new(2., 0.),
+                            is_proper: false,
+                        },
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn backtracking_linestring_test() {
+        let linestring = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.)]);
+        assert_eq!(
+            linestring.simplicity(),
+            vec![Simplicity::SelfIntersection {
+                component: 0,
+                intersection: SegmentIntersection {
+                    first: 0,
+                    second: 1,
+                    intersection: LineIntersection::Collinear {
+                        intersection: Line::from([(1., 0.), (2., 0.)]),
+                    },
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn multilinestring_test() {
+        // the members cross each other, and the second repeats a vertex
+        let mls = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (2., 2.)]),
+            LineString::from(vec![(0., 2.), (2., 0.), (2., 0.)]),
+        ]);
+        assert!(!mls.is_simple());
+        assert_eq!(
+            mls.simplicity(),
+            vec![
+                Simplicity::RepeatedVertex {
+                    component: 1,
+                    vertex: 2,
+                    point: Point::new(2., 0.),
+                },
+                Simplicity::Intersection {
+                    components: (0, 1),
+                    intersection: SegmentIntersection {
+                        first: 0,
+                        second: 0,
+                        intersection: LineIntersection::SinglePoint {
+                            intersection: Point::new(1., 1.),
+                            is_proper: true,
+                        },
+                    },
+                },
+            ]
+        );
+
+        // members may meet at a single point
+        let mls = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (2., 2.)]),
+            LineString::from(vec![(2., 2.), (4., 0.)]),
+        ]);
+        assert!(mls.is_simple());
+    }
+
+    #[test]
+    fn polygon_test() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let polygon = Polygon::new(exterior.clone(), vec![]);
+        assert!(polygon.is_simple());
+
+        let bow_tie = LineString::from(vec![(1., 1.), (2., 2.), (2., 1.), (1., 2.), (1., 1.)]);
+        let polygon = Polygon::new(exterior, vec![bow_tie]);
+        let problems = polygon.simplicity();
+        assert_eq!(problems.len(), 1);
+        match problems[0] {
+            Simplicity::SelfIntersection {
+                component,
+                intersection,
+            } => {
+                assert_eq!(component, 1);
+                assert_eq!((intersection.first, intersection.second), (0, 2));
+            }
+            _ => panic!("expected a self-intersection"),
+        }
+    }
+
+    #[test]
+    fn polygon_rings_test() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let ring = |coords: Vec<(f64, f64)>| Polygon::new(exterior.clone(), vec![coords.into()]);
+
+        // a hole touching its shell at a vertex
+        let polygon = ring(vec![(0., 0.), (2., 1.), (1., 2.), (0., 0.)]);
+        assert!(polygon.is_simple());
+
+        // a hole touching its shell at two points, splitting the polygon in two
+        let polygon = ring(vec![(0., 0.), (4., 4.), (1., 2.), (0., 0.)]);
+        // at each point, two segments of the hole meet two of the shell
+        let problems = polygon.simplicity();
+        assert_eq!(problems.len(), 8);
+        assert!(problems.iter().all(|problem| match *problem {
+            Simplicity::Intersection { components, .. } => components == (0, 1),
+            _ => false,
+        }));
+
+        // a hole partly outside its shell
+        let polygon = ring(vec![(3., 1.), (5., 2.), (3., 3.), (3., 1.)]);
+        assert_eq!(
+            polygon.simplicity(),
+            vec![
+                Simplicity::Intersection {
+                    components: (0, 1),
+                    intersection: SegmentIntersection {
+                        first: 1,
+                        second: 0,
+                        intersection: LineIntersection::SinglePoint {
+                            intersection: Point::new(4., 1.5),
+                            is_proper: true,
+                        },
+                    },
+                },
+                Simplicity::Intersection {
+                    components: (0, 1),
+                    intersection: SegmentIntersection {
+                        first: 1,
+                        second: 1,
+                        intersection: LineIntersection::SinglePoint {
+                            intersection: Point::new(4., 2.5),
+                            is_proper: true,
+                        },
+                    },
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use algorithm::predicates::{orient2d, Orientation};
+use num_traits::Float;
+use {Coordinate, Line, Point};
+
+/// The result of intersecting two `Line`s
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum LineIntersection<T>
+where
+    T: Float,
+{
+    /// The lines meet in exactly one point.
+    ///
+    /// The intersection is *proper* if the point lies in the interior of both lines, i.e. it is
+    /// not an endpoint of either of them.
+    SinglePoint {
+        intersection: Point<T>,
+        is_proper: bool,
+    },
+    /// The lines are collinear and overlap along a segment of non-zero length.
+    ///
+    /// The overlap has the same direction as the first line.
+    Collinear { intersection: Line<T> },
+}
+
+impl<T> LineIntersection<T>
+where
+    T: Float,
+{
+    /// Returns true if the intersection is a single point interior to both lines
+    pub fn is_proper(&self) -> bool {
+        match *self {
+            LineIntersection::SinglePoint { is_proper, .. } => is_proper,
+            LineIntersection::Collinear { .. } => false,
+        }
+    }
+}
+
+/// Computes the intersection of two `Line`s.
+///
+/// Returns `None` if the lines do not meet. Whether they meet, and whether they meet properly or
+/// are collinear, is decided using exact orientation tests. Endpoints are returned unchanged
+/// wherever they are part of the result, so only the point of a proper intersection is subject
+/// to rounding; it is guaranteed to lie within the bounding boxes of both lines.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Line, Point};
+/// use geo::algorithm::line_intersection::{line_intersection, LineIntersection};
+///
+/// let line_1 = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 5. });
+/// let line_2 = Line::new(Coordinate { x: 0., y: 5. }, Coordinate { x: 5., y: 0. });
+/// assert_eq!(
+///     line_intersection(line_1, line_2),
+///     Some(LineIntersection::SinglePoint {
+///         intersection: Point::new(2.5, 2.5),
+///         is_proper: true,
+///     })
+/// );
+///
+/// let line_3 = Line::new(Coordinate { x: 3., y: 3. }, Coordinate { x: 8., y: 8. });
+/// assert_eq!(
+///     line_intersection(line_1, line_3),
+///     Some(LineIntersection::Collinear {
+///         intersection: Line::new(Coordinate { x: 3., y: 3. }, Coordinate { x: 5., y: 5. }),
+///     })
+/// );
+///
+/// let line_4 = Line::new(Coordinate { x: 6., y: 0. }, Coordinate { x: 9., y: 3. });
+/// assert_eq!(line_intersection(line_1, line_4), None);
+/// ```
+pub fn line_intersection<T>(p: Line<T>, q: Line<T>) -> Option<LineIntersection<T>>
+where
+    T: Float,
+{
+    if !bboxes_overlap(&p, &q) {
+        return None;
+    }
+
+    let p_q1 = orient2d(p.start, p.end, q.start);
+    let p_q2 = orient2d(p.start, p.end, q.end);
+    if p_q1 == p_q2 && p_q1 != Orientation::Collinear {
+        // q lies strictly on one side of p
+        return None;
+    }
+    let q_p1 = orient2d(q.start, q.end, p.start);
+    let q_p2 = orient2d(q.start, q.end, p.end);
+    if q_p1 == q_p2 && q_p1 != Orientation::Collinear {
+        // p lies strictly on one side of q
+        return None;
+    }
+
+    if p_q1 == Orientation::Collinear
+        && p_q2 == Orientation::Collinear
+        && q_p1 == Orientation::Collinear
+        && q_p2 == Orientation::Collinear
+    {
+        return collinear_intersection(p, q);
+    }
+
+    // The lines cross. If an endpoint of one lies on the other, that endpoint is the intersection.
+    let endpoint = if p_q1 == Orientation::Collinear {
+        Some(q.start)
+    } else if p_q2 == Orientation::Collinear {
+        Some(q.end)
+    } else if q_p1 == Orientation::Collinear {
+        Some(p.start)
+    } else if q_p2 == Orientation::Collinear {
+        Some(p.end)
+    } else {
+        None
+    };
+    Some(match endpoint {
+        Some(c) => LineIntersection::SinglePoint {
+            intersection: Point(c),
+            is_proper: false,
+        },
+        None => LineIntersection::SinglePoint {
+            intersection: Point(proper_intersection(p, q)),
+            is_proper: true,
+        },
+    })
+}
+
+fn bboxes_overlap<T>(p: &Line<T>, q: &Line<T>) -> bool
+where
+    T: Float,
+{
+    p.start.x.min(p.end.x) <= q.start.x.max(q.end.x)
+        && q.start.x.min(q.end.x) <= p.start.x.max(p.end.x)
+        && p.start.y.min(p.end.y) <= q.start.y.max(q.end.y)
+        && q.start.y.min(q.end.y) <= p.start.y.max(p.end.y)
+}
+
+/// Intersection of two lines known to lie on a common line (either may be degenerate)
+fn collinear_intersection<T>(p: Line<T>, q: Line<T>) -> Option<LineIntersection<T>>
+where
+    T: Float,
+{
+    // Order coordinates along whichever axis the common line spans most
+    let (dx, dy) = if p.start != p.end {
+        (p.dx(), p.dy())
+    } else {
+        (q.dx(), q.dy())
+    };
+    let key = |c: Coordinate<T>| {
+        if dx.abs() >= dy.abs() {
+            c.x
+        } else {
+            c.y
+        }
+    };
+    let ordered = |a: Coordinate<T>, b: Coordinate<T>| {
+        if key(a) <= key(b) {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    let (p_min, p_max) = ordered(p.start, p.end);
+    let (q_min, q_max) = ordered(q.start, q.end);
+    let low = if key(p_min) >= key(q_min) {
+        p_min
+    } else {
+        q_min
+    };
+    let high = if key(p_max) <= key(q_max) {
+        p_max
+    } else {
+        q_max
+    };
+
+    if key(low) > key(high) {
+        None
+    } else if key(low) == key(high) {
+        Some(LineIntersection::SinglePoint {
+            intersection: Point(low),
+            is_proper: false,
+        })
+    } else if key(p.start) <= key(p.end) {
+        Some(LineIntersection::Collinear {
+            intersection: Line::new(low, high),
+        })
+    } else {
+        Some(LineIntersection::Collinear {
+            intersection: Line::new(high, low),
+        })
+    }
+}
+
+/// Intersection point of two lines which are known to cross at a single interior point
+fn proper_intersection<T>(p: Line<T>, q: Line<T>) -> Coordinate<T>
+where
+    T: Float,
+{
+    // Work relative to the midpoint of the overlapping bounding boxes, to keep magnitudes small
+    let min_x = p.start.x.min(p.end.x).max(q.start.x.min(q.end.x));
+    let max_x = p.start.x.max(p.end.x).min(q.start.x.max(q.end.x));
+    let min_y = p.start.y.min(p.end.y).max(q.start.y.min(q.end.y));
+    let max_y = p.start.y.max(p.end.y).min(q.start.y.max(q.end.y));
+    let two = T::one() + T::one();
+    let origin = Coordinate {
+        x: (min_x + max_x) / two,
+        y: (min_y + max_y) / two,
+    };
+
+    let p_start = Coordinate {
+        x: p.start.x - origin.x,
+        y: p.start.y - origin.y,
+    };
+    let q_start = Coordinate {
+        x: q.start.x - origin.x,
+        y: q.start.y - origin.y,
+    };
+    let (p_dx, p_dy) = (p.dx(), p.dy());
+    let (q_dx, q_dy) = (q.dx(), q.dy());
+    let denom = p_dx * q_dy - p_dy * q_dx;
+    let t = ((q_start.x - p_start.x) * q_dy - (q_start.y - p_start.y) * q_dx) / denom;
+
+    // Rounding may push the point marginally outside the lines; clamp it back
+    Coordinate {
+        x: (p_start.x + t * p_dx + origin.x).max(min_x).min(max_x),
+        y: (p_start.y + t * p_dy + origin.y).max(min_y).min(max_y),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Line<f64> {
+        Line::new(Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 })
+    }
+
+    fn single_point(x: f64, y: f64, is_proper: bool) -> Option<LineIntersection<f64>> {
+        Some(LineIntersection::SinglePoint {
+            intersection: Point::new(x, y),
+            is_proper,
+        })
+    }
+
+    #[test]
+    fn disjoint_test() {
+        // parallel
+        assert_eq!(
+            line_intersection(line(0., 0., 5., 0.), line(0., 1., 5., 1.)),
+            None
+        );
+        // collinear but separate
+        assert_eq!(
+            line_intersection(line(0., 0., 1., 1.), line(2., 2., 3., 3.)),
+            None
+        );
+        // the supporting lines cross, but beyond the end of one segment
+        assert_eq!(
+            line_intersection(line(0., 0., 1., 1.), line(0., 4., 4., 0.)),
+            None
+        );
+        // overlapping bounding boxes
+        assert_eq!(
+            line_intersection(line(0., 0., 4., 4.), line(1., 0., 4., 3.)),
+            None
+        );
+    }
+
+    #[test]
+    fn proper_intersection_test() {
+        assert_eq!(
+            line_intersection(line(0., 0., 4., 4.), line(0., 4., 4., 0.)),
+            single_point(2., 2., true)
+        );
+        let result = line_intersection(line(0., 0., 10., 1.), line(3., -2., 4., 5.));
+        match result {
+            Some(LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            }) => {
+                assert_relative_eq!(intersection.x(), 3.3333333333333335);
+                assert_relative_eq!(intersection.y(), 0.33333333333333337);
+            }
+            _ => panic!("expected a proper intersection, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn improper_intersection_test() {
+        // T-junction
+        assert_eq!(
+            line_intersection(line(0., 0., 4., 0.), line(2., 0., 2., 3.)),
+            single_point(2., 0., false)
+        );
+        assert_eq!(
+            line_intersection(line(2., 3., 2., 0.), line(0., 0., 4., 0.)),
+            single_point(2., 0., false)
+        );
+        // shared endpoint
+        assert_eq!(
+            line_intersection(line(0., 0., 1., 1.), line(1., 1., 2., 0.)),
+            single_point(1., 1., false)
+        );
+        // collinear, touching end to end
+        assert_eq!(
+            line_intersection(line(0., 0., 1., 1.), line(1., 1., 2., 2.)),
+            single_point(1., 1., false)
+        );
+    }
+
+    #[test]
+    fn collinear_intersection_test() {
+        assert_eq!(
+            line_intersection(line(0., 0., 4., 0.), line(6., 0., 2., 0.)),
+            Some(LineIntersection::Collinear {
+                intersection: line(2., 0., 4., 0.)
+            })
+        );
+        // the overlap follows the direction of the first line
+        assert_eq!(
+            line_intersection(line(4., 0., 0., 0.), line(2., 0., 6., 0.)),
+            Some(LineIntersection::Collinear {
+                intersection: line(4., 0., 2., 0.)
+            })
+        );
+        // one line inside the other, on a steep line
+        assert_eq!(
+            line_intersection(line(0., 0., 1., 10.), line(0.25, 2.5, 0.5, 5.)),
+            Some(LineIntersection::Collinear {
+                intersection: line(0.25, 2.5, 0.5, 5.)
+            })
+        );
+        // identical lines
+        assert_eq!(
+            line_intersection(line(1., 1., 3., 2.), line(3., 2., 1., 1.)),
+            Some(LineIntersection::Collinear {
+                intersection: line(1., 1., 3., 2.)
+            })
+        );
+    }
+
+    #[test]
+    fn degenerate_line_test() {
+        // a zero-length line on another line
+        assert_eq!(
+            line_intersection(line(1., 1., 1., 1.), line(0., 0., 2., 2.)),
+            single_point(1., 1., false)
+        );
+        assert_eq!(
+            line_intersection(line(0., 0., 2., 2.), line(1., 1., 1., 1.)),
+            single_point(1., 1., false)
+        );
+        assert_eq!(
+            line_intersection(line(1., 0., 1., 0.), line(0., 0., 2., 2.)),
+            None
+        );
+        // two zero-length lines
+        assert_eq!(
+            line_intersection(line(1., 1., 1., 1.), line(1., 1., 1., 1.)),
+            single_point(1., 1., false)
+        );
+        assert_eq!(
+            line_intersection(line(1., 1., 1., 1.), line(1., 2., 1., 2.)),
+            None
+        );
+    }
+
+    #[test]
+    fn symmetry_test() {
+        let lines = [
+            line(0., 0., 4., 4.),
+            line(0., 4., 4., 0.),
+            line(2., 0., 2., 3.),
+            line(1., 1., 6., 6.),
+            line(4., 4., 5., 0.),
+            line(-1., 2., 0.1, 0.3),
+        ];
+        for a in &lines {
+            for b in &lines {
+                let ab = line_intersection(*a, *b);
+                let ba = line_intersection(*b, *a);
+                assert_eq!(ab.is_some(), ba.is_some());
+                match (ab, ba) {
+                    (
+                        Some(LineIntersection::SinglePoint {
+                            intersection: i1,
+                            is_proper: p1,
+                        }),
+                        Some(LineIntersection::SinglePoint {
+                            intersection: i2,
+                            is_proper: p2,
+                        }),
+                    ) => {
+                        assert_eq!(p1, p2);
+                        assert_relative_eq!(i1.x(), i2.x());
+                        assert_relative_eq!(i1.y(), i2.y());
+                    }
+                    (
+                        Some(LineIntersection::Collinear { .. }),
+                        Some(LineIntersection::Collinear { .. }),
+                    )
+                    | (None, None) => {}
+                    _ => panic!("asymmetric result for {:?} and {:?}", a, b),
+                }
+            }
+        }
+    }
+}
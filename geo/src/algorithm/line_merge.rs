@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use algorithm::coordinate_key::key;
+use num_traits::Float;
+use {Coordinate, LineString, MultiLineString};
+
+/// Merges lines which meet end to end into the longest lines possible.
+///
+/// Lines are joined where exactly two of them meet. Where three or more lines meet, or
+/// where a line ends without meeting another, the merged lines end too. Lines are joined
+/// whichever way round they run, so some may be reversed to fit; the lines which start a
+/// merged line keep their direction. Lines which form a closed loop, without meeting any
+/// others, are merged into a closed line.
+pub trait LineMerge<T>
+where
+    T: Float,
+{
+    /// Returns the merged lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, MultiLineString};
+    /// use geo::algorithm::line_merge::LineMerge;
+    ///
+    /// // a road, in pieces, with a junction at (4., 0.)
+    /// let roads = MultiLineString(vec![
+    ///     LineString::from(vec![(0., 0.), (2., 0.)]),
+    ///     LineString::from(vec![(4., 0.), (2., 0.)]),
+    ///     LineString::from(vec![(4., 0.), (6., 1.)]),
+    ///     LineString::from(vec![(4., 0.), (4., -3.)]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     roads.line_merge(),
+    ///     MultiLineString(vec![
+    ///         LineString::from(vec![(0., 0.), (2., 0.), (4., 0.)]),
+    ///         LineString::from(vec![(4., 0.), (6., 1.)]),
+    ///         LineString::from(vec![(4., 0.), (4., -3.)]),
+    ///     ])
+    /// );
+    /// ```
+    fn line_merge(&self) -> MultiLineString<T>;
+}
+
+/// The line ends meeting at a point: `(line, true)` is the start of `line`, and
+/// `(line, false)` its end
+type Meeting = Vec<(usize, bool)>;
+
+/// The line ends meeting at the start and at the end of each line
+struct Ends {
+    at: Vec<(Meeting, Meeting)>,
+}
+
+impl Ends {
+    fn new<T>(lines: &[Vec<Coordinate<T>>]) -> Ends
+    where
+        T: Float,
+    {
+        let mut nodes = BTreeMap::new();
+        for (i, line) in lines.iter().enumerate() {
+            let (start, end) = (&line[0], &line[line.len() - 1]);
+            nodes
+                .entry(key(start))
+                .or_insert_with(Vec::new)
+                .push((i, true));
+            nodes
+                .entry(key(end))
+                .or_insert_with(Vec::new)
+                .push((i, false));
+        }
+        Ends {
+            at: lines
+                .iter()
+                .map(|line| {
+                    (
+                        nodes[&key(&line[0])].clone(),
+                        nodes[&key(&line[line.len() - 1])].clone(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn meeting(&self, line: usize, start: bool) -> &[(usize, bool)] {
+        if start {
+            &self.at[line].0
+        } else {
+            &self.at[line].1
+        }
+    }
+}
+
+/// Follows lines from the start of `first` if `forward`, or from its end otherwise, joining
+/// them for as long as exactly two lines meet
+fn follow<T>(
+    lines: &[Vec<Coordinate<T>>],
+    ends: &Ends,
+    merged: &mut [bool],
+    first: usize,
+    forward: bool,
+) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let mut coords: Vec<Coordinate<T>> = vec![];
+    let (mut line, mut forward) = (first, forward);
+    loop {
+        merged[line] = true;
+        let skip = if coords.is_empty() { 0 } else { 1 };
+        if forward {
+            coords.extend(lines[line].iter().skip(skip));
+        } else {
+            coords.extend(lines[line].iter().rev().skip(skip));
+        }
+        // the end of `line` we've arrived at
+        let meeting = ends.meeting(line, !forward);
+        if meeting.len() != 2 {
+            return coords;
+        }
+        match meeting.iter().find(|&&(other, _)| !merged[other]) {
+            Some(&(other, start)) => {
+                line = other;
+                forward = start;
+            }
+            None => return coords,
+        }
+    }
+}
+
+fn merge_lines<T>(lines: Vec<Vec<Coordinate<T>>>) -> MultiLineString<T>
+where
+    T: Float,
+{
+    let lines: Vec<_> = lines
+        .into_iter()
+        .map(|mut line| {
+            line.dedup();
+            line
+        })
+        .filter(|line| line.len() > 1)
+        .collect();
+    let ends = Ends::new(&lines);
+    let mut merged = vec![false; lines.len()];
+    let mut result = vec![];
+    // start from the lines which end where something other than two lines meet
+    for i in 0..lines.len() {
+        if merged[i] {
+            continue;
+        }
+        if ends.meeting(i, true).len() != 2 {
+            result.push(LineString(follow(&lines, &ends, &mut merged, i, true)));
+        } else if ends.meeting(i, false).len() != 2 {
+            let mut coords = follow(&lines, &ends, &mut merged, i, false);
+            coords.reverse();
+            result.push(LineString(coords));
+        }
+    }
+    // anything left is part of a loop
+    for i in 0..lines.len() {
+        if !merged[i] {
+            result.push(LineString(follow(&lines, &ends, &mut merged, i, true)));
+        }
+    }
+    MultiLineString(result)
+}
+
+impl<T> LineMerge<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn line_merge(&self) -> MultiLineString<T> {
+        merge_lines(self.0.iter().map(|l| l.0.clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chain_test() {
+        // pieces in no particular order or direction
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(2., 0.), (3., 0.)]),
+            LineString::from(vec![(1., 0.), (0., 0.)]),
+            LineString::from(vec![(2., 0.), (1., 0.)]),
+            LineString::from(vec![(3., 0.), (3., 1.), (4., 1.)]),
+        ]);
+        let merged = lines.line_merge();
+        assert_eq!(
+            merged,
+            MultiLineString(vec![LineString::from(vec![
+                (4., 1.),
+                (3., 1.),
+                (3., 0.),
+                (2., 0.),
+                (1., 0.),
+                (0., 0.),
+            ])])
+        );
+    }
+
+    #[test]
+    fn loop_test() {
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.)]),
+            LineString::from(vec![(0., 0.), (0., 1.), (1., 1.)]),
+            LineString::from(vec![(5., 5.), (6., 5.), (5., 5.)]),
+            LineString::from(vec![(9., 9.), (9., 9.)]),
+        ]);
+        let merged = lines.line_merge();
+        assert_eq!(
+            merged,
+            MultiLineString(vec![
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+                LineString::from(vec![(5., 5.), (6., 5.), (5., 5.)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn junction_test() {
+        // a loop hanging off a line
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (1., 0.)]),
+            LineString::from(vec![(1., 0.), (2., 1.), (2., -1.), (1., 0.)]),
+            LineString::from(vec![(-1., 0.), (0., 0.)]),
+        ]);
+        let merged = lines.line_merge();
+        assert_eq!(
+            merged,
+            MultiLineString(vec![
+                LineString::from(vec![(-1., 0.), (0., 0.), (1., 0.)]),
+                LineString::from(vec![(1., 0.), (2., 1.), (2., -1.), (1., 0.)]),
+            ])
+        );
+    }
+}
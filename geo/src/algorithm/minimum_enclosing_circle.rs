@@ -0,0 +1,242 @@
+use algorithm::convexhull::{hull_vertices, ConvexHull};
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::predicates::{incircle, orient2d, CirclePosition, Orientation};
+use num_traits::Float;
+use {Circle, Point};
+
+/// Returns the smallest circle enclosing a geometry.
+pub trait MinimumEnclosingCircle<T>
+where
+    T: Float,
+{
+    /// Returns the smallest circle which encloses the geometry.
+    ///
+    /// This uses [Welzl's algorithm](https://doi.org/10.1007/BFb0038202), which runs in
+    /// expected linear time, on the vertices of the geometry's convex hull. Returns `None`
+    /// if the geometry has no coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::minimum_enclosing_circle::MinimumEnclosingCircle;
+    ///
+    /// let ls = LineString::from(vec![(0., 0.), (4., 0.), (2., 1.), (2., -1.)]);
+    ///
+    /// let circle = ls.minimum_enclosing_circle().unwrap();
+    /// assert_eq!(circle.center, Point::new(2., 0.));
+    /// assert_eq!(circle.radius, 2.);
+    /// ```
+    fn minimum_enclosing_circle(&self) -> Option<Circle<T>>;
+}
+
+impl<T, G> MinimumEnclosingCircle<T> for G
+where
+    T: Float,
+    G: ConvexHull<T>,
+{
+    fn minimum_enclosing_circle(&self) -> Option<Circle<T>> {
+        let mut points = hull_vertices(&self.convex_hull());
+        shuffle(&mut points);
+        welzl(&points)
+    }
+}
+
+// Welzl's algorithm relies on a random order for its expected running time. A fixed
+// pseudo-random permutation keeps the result reproducible.
+fn shuffle<T>(points: &mut [T]) {
+    let mut state: u32 = 0x9e37_79b9;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        points.swap(i, state as usize % (i + 1));
+    }
+}
+
+/// The points a circle was built to pass through
+#[derive(Clone, Copy)]
+enum Support<T>
+where
+    T: Float,
+{
+    /// The ends of a diameter, which are the same point for a circle of radius zero
+    Diameter(Point<T>, Point<T>),
+    /// Three points which aren't collinear
+    Triangle(Point<T>, Point<T>, Point<T>),
+}
+
+/// A circle, along with the points it was built to pass through
+#[derive(Clone, Copy)]
+struct Enclosing<T>
+where
+    T: Float,
+{
+    circle: Circle<T>,
+    support: Support<T>,
+}
+
+impl<T> Enclosing<T>
+where
+    T: Float,
+{
+    fn encloses(&self, point: Point<T>) -> bool {
+        match self.support {
+            Support::Triangle(a, b, c) => {
+                incircle(a.0, b.0, c.0, point.0) != CirclePosition::Outside
+            }
+            Support::Diameter(..) => {
+                // allow for the rounding error in computing the circle
+                let tolerance = T::epsilon() * T::from(64).unwrap();
+                point.euclidean_distance(&self.circle.center)
+                    <= self.circle.radius * (T::one() + tolerance)
+            }
+        }
+    }
+}
+
+fn diametral<T>(a: Point<T>, b: Point<T>) -> Enclosing<T>
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    Enclosing {
+        circle: Circle {
+            center: Point::new((a.x() + b.x()) / two, (a.y() + b.y()) / two),
+            radius: a.euclidean_distance(&b) / two,
+        },
+        support: Support::Diameter(a, b),
+    }
+}
+
+fn circumcircle<T>(a: Point<T>, b: Point<T>, c: Point<T>) -> Enclosing<T>
+where
+    T: Float,
+{
+    if orient2d(a.0, b.0, c.0) == Orientation::Collinear {
+        // the two furthest apart lie on a diameter
+        return [diametral(a, c), diametral(b, c)].iter().fold(
+            diametral(a, b),
+            |widest, &enclosing| {
+                if enclosing.circle.radius > widest.circle.radius {
+                    enclosing
+                } else {
+                    widest
+                }
+            },
+        );
+    }
+    let (ab, ac) = (b - a, c - a);
+    let d = a.cross_prod(b, c) * (T::one() + T::one());
+    let (ab2, ac2) = (ab.dot(ab), ac.dot(ac));
+    let center = Point::new(
+        (ac.y() * ab2 - ab.y() * ac2) / d,
+        (ab.x() * ac2 - ac.x() * ab2) / d,
+    );
+    Enclosing {
+        circle: Circle {
+            center: center + a,
+            radius: center.x().hypot(center.y()),
+        },
+        support: Support::Triangle(a, b, c),
+    }
+}
+
+// The iterative form of Welzl's algorithm: each point outside the circle so far must lie on
+// the boundary of the circle enclosing it and the points before it.
+fn welzl<T>(points: &[Point<T>]) -> Option<Circle<T>>
+where
+    T: Float,
+{
+    let first = *points.first()?;
+    let mut enclosing = diametral(first, first);
+    for i in 1..points.len() {
+        if enclosing.encloses(points[i]) {
+            continue;
+        }
+        enclosing = diametral(points[i], points[i]);
+        for j in 0..i {
+            if enclosing.encloses(points[j]) {
+                continue;
+            }
+            enclosing = diametral(points[i], points[j]);
+            for k in 0..j {
+                if !enclosing.encloses(points[k]) {
+                    enclosing = circumcircle(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    Some(enclosing.circle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {LineString, MultiPoint, Polygon};
+
+    #[test]
+    fn triangle_test() {
+        // an acute triangle is enclosed by its circumcircle
+        let triangle = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (2., 3.), (0., 0.)]),
+            vec![],
+        );
+        let circle = triangle.minimum_enclosing_circle().unwrap();
+        assert_relative_eq!(circle.center.x(), 2.);
+        assert_relative_eq!(circle.center.y(), 5. / 6.);
+        assert_relative_eq!(circle.radius, 13. / 6.);
+        // an obtuse one by the circle on its longest side
+        let triangle = LineString::from(vec![(0., 0.), (4., 0.), (2., 1.)]);
+        let circle = triangle.minimum_enclosing_circle().unwrap();
+        assert_eq!(
+            circle,
+            diametral(Point::new(0., 0.), Point::new(4., 0.)).circle
+        );
+    }
+
+    #[test]
+    fn enclosing_test() {
+        let points: MultiPoint<f64> = (0..200)
+            .map(|i| {
+                let t = i as f64;
+                Point::new((t * 1.3).sin() * 7. + t.cos(), (t * 0.7).cos() * 4.)
+            })
+            .collect();
+        let circle = points.minimum_enclosing_circle().unwrap();
+        let on_boundary = points
+            .0
+            .iter()
+            .filter(|p| {
+                let d = p.euclidean_distance(&circle.center);
+                assert!(d <= circle.radius + 1e-9);
+                d >= circle.radius - 1e-9
+            })
+            .count();
+        // the circle is pinned by at least two points
+        assert!(on_boundary >= 2);
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(empty.minimum_enclosing_circle(), None);
+        let single = MultiPoint(vec![Point::new(1., 2.)]);
+        assert_eq!(
+            single.minimum_enclosing_circle(),
+            Some(Circle {
+                center: Point::new(1., 2.),
+                radius: 0.,
+            })
+        );
+        let collinear = LineString::from(vec![(0., 0.), (1., 1.), (2., 2.), (-1., -1.)]);
+        let circle = collinear.minimum_enclosing_circle().unwrap();
+        assert_eq!(circle.center, Point::new(0.5, 0.5));
+        assert_relative_eq!(circle.radius, 4.5f64.sqrt());
+        // cocircular points, whichever three the circle is built through
+        let square: MultiPoint<f64> = vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (1., 1.)].into();
+        let circle = square.minimum_enclosing_circle().unwrap();
+        assert_eq!(circle.center, Point::new(1., 1.));
+        assert_relative_eq!(circle.radius, 2f64.sqrt());
+    }
+}
@@ -0,0 +1,213 @@
+use algorithm::convexhull::{hull_vertices, ConvexHull};
+use num_traits::Float;
+use {LineString, Point, Polygon};
+
+/// Returns the minimum-area rectangle enclosing a geometry, which need not be axis-aligned.
+pub trait MinimumRotatedRect<T>
+where
+    T: Float,
+{
+    /// Returns the smallest rectangle, by area, which encloses the geometry.
+    ///
+    /// One side of the rectangle always lies along an edge of the geometry's convex hull,
+    /// so rotating calipers find it in linear time from the hull. The rectangle is oriented
+    /// counter-clockwise, and degenerates to a line or a point for collinear or single-point
+    /// input. Returns `None` if the geometry has no coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+    ///
+    /// // a square, rotated by 45°
+    /// let diamond: Polygon<f64> = Polygon::new(
+    ///     LineString::from(vec![(1., 0.), (2., 1.), (1., 2.), (0., 1.), (1., 0.)]),
+    ///     vec![],
+    /// );
+    ///
+    /// let rect = diamond.minimum_rotated_rect().unwrap();
+    /// assert!((rect.area() - 2.).abs() < 1e-9);
+    /// ```
+    fn minimum_rotated_rect(&self) -> Option<Polygon<T>>;
+}
+
+impl<T, G> MinimumRotatedRect<T> for G
+where
+    T: Float,
+    G: ConvexHull<T>,
+{
+    fn minimum_rotated_rect(&self) -> Option<Polygon<T>> {
+        let hull = hull_vertices(&self.convex_hull());
+        let corners = match hull.len() {
+            0 => return None,
+            1 => [hull[0]; 4],
+            _ => rotating_calipers(&hull),
+        };
+        let mut ring = corners.to_vec();
+        ring.push(corners[0]);
+        Some(Polygon::new(LineString::from(ring), vec![]))
+    }
+}
+
+fn offset<T>(origin: Point<T>, u: Point<T>, along: T, v: Point<T>, across: T) -> Point<T>
+where
+    T: Float,
+{
+    Point::new(
+        origin.x() + u.x() * along + v.x() * across,
+        origin.y() + u.y() * along + v.y() * across,
+    )
+}
+
+// Moves `index` forwards around the hull for as long as the projection doesn't decrease.
+// The projections of a convex polygon's vertices are unimodal, so this finds the maximum
+// when starting from the maximum for the previous edge, which lies behind it.
+fn advance<T, F>(hull: &[Point<T>], mut index: usize, project: F) -> usize
+where
+    T: Float,
+    F: Fn(Point<T>) -> T,
+{
+    for _ in 0..hull.len() {
+        let next = (index + 1) % hull.len();
+        if project(hull[next]) < project(hull[index]) {
+            break;
+        }
+        index = next;
+    }
+    index
+}
+
+// The unit vector along an edge of the hull, and the one perpendicular to it, pointing inwards
+fn axes<T>(hull: &[Point<T>], i: usize) -> (Point<T>, Point<T>)
+where
+    T: Float,
+{
+    let edge = hull[(i + 1) % hull.len()] - hull[i];
+    let length = edge.x().hypot(edge.y());
+    let u = Point::new(edge.x() / length, edge.y() / length);
+    (u, Point::new(-u.y(), u.x()))
+}
+
+// The corners of the smallest rectangle with a side along an edge of the hull, which must be
+// counter-clockwise with at least two vertices
+fn rotating_calipers<T>(hull: &[Point<T>]) -> [Point<T>; 4]
+where
+    T: Float,
+{
+    // the vertices furthest along the edge, away from it, and back along it
+    let (mut right, mut top, mut left) = (1, 1, 1);
+    let mut best: Option<(T, [Point<T>; 4])> = None;
+    for (i, &origin) in hull.iter().enumerate() {
+        let (u, v) = axes(hull, i);
+        // the extremes are met in this order going round from the edge, and all move
+        // forwards as the edges turn
+        right = advance(hull, right, |p| u.dot(p - origin));
+        if i == 0 {
+            top = right;
+        }
+        top = advance(hull, top, |p| v.dot(p - origin));
+        if i == 0 {
+            left = top;
+        }
+        left = advance(hull, left, |p| -u.dot(p - origin));
+        let max_u = u.dot(hull[right] - origin);
+        let max_v = v.dot(hull[top] - origin);
+        let min_u = u.dot(hull[left] - origin);
+        let area = (max_u - min_u) * max_v;
+        let smaller = match best {
+            Some((smallest, _)) => area < smallest,
+            None => true,
+        };
+        if smaller {
+            let corners = [
+                offset(origin, u, min_u, v, T::zero()),
+                offset(origin, u, max_u, v, T::zero()),
+                offset(origin, u, max_u, v, max_v),
+                offset(origin, u, min_u, v, max_v),
+            ];
+            best = Some((area, corners));
+        }
+    }
+    best.unwrap().1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use algorithm::contains::Contains;
+    use algorithm::euclidean_distance::EuclideanDistance;
+    use MultiPoint;
+
+    // the smallest rectangle with a side along each hull edge, in quadratic time
+    fn brute_force(points: &MultiPoint<f64>) -> f64 {
+        let hull = hull_vertices(&points.convex_hull());
+        (0..hull.len())
+            .map(|i| {
+                let (u, v) = axes(&hull, i);
+                let along: Vec<f64> = hull.iter().map(|p| u.dot(*p - hull[i])).collect();
+                let across: Vec<f64> = hull.iter().map(|p| v.dot(*p - hull[i])).collect();
+                let max = |values: &[f64]| values.iter().cloned().fold(f64::MIN, f64::max);
+                let min = |values: &[f64]| values.iter().cloned().fold(f64::MAX, f64::min);
+                (max(&along) - min(&along)) * (max(&across) - min(&across))
+            })
+            .fold(f64::MAX, f64::min)
+    }
+
+    #[test]
+    fn rotated_rectangle_test() {
+        // a 4 × 1 rectangle at 30°, with some points inside it
+        let (cos, sin) = (30f64.to_radians().cos(), 30f64.to_radians().sin());
+        let points: MultiPoint<f64> =
+            vec![(0., 0.), (4., 0.), (4., 1.), (0., 1.), (1., 0.5), (3., 0.2)]
+                .into_iter()
+                .map(|(x, y)| Point::new(x * cos - y * sin + 10., x * sin + y * cos - 5.))
+                .collect();
+        let rect = points.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.area(), 4., epsilon = 1e-9);
+        assert_eq!(rect.exterior.0.len(), 5);
+        for corner in &rect.exterior.0 {
+            let nearest = points
+                .0
+                .iter()
+                .map(|p| (p.x() - corner.x).hypot(p.y() - corner.y))
+                .fold(f64::MAX, f64::min);
+            assert_relative_eq!(nearest, 0., epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn brute_force_test() {
+        for seed in 1..20 {
+            let points: MultiPoint<f64> = (0..30)
+                .map(|i| {
+                    let t = (i * seed) as f64;
+                    Point::new((t * 1.7).sin() * 5. + t.cos(), (t * 0.3).cos() * 3.)
+                })
+                .collect();
+            let rect = points.minimum_rotated_rect().unwrap();
+            assert_relative_eq!(rect.area(), brute_force(&points), epsilon = 1e-9);
+            for point in &points.0 {
+                assert!(rect.contains(point) || rect.exterior.euclidean_distance(point) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(empty.minimum_rotated_rect(), None);
+        let single = MultiPoint(vec![Point::new(1., 2.)]);
+        assert_eq!(
+            single.minimum_rotated_rect().unwrap().exterior,
+            LineString::from(vec![(1., 2.); 5])
+        );
+        let line = LineString::from(vec![(0., 0.), (1., 1.), (3., 3.)]);
+        let rect = line.minimum_rotated_rect().unwrap();
+        assert_eq!(rect.area(), 0.);
+        assert_relative_eq!(rect.exterior.0[1].x, 3., epsilon = 1e-9);
+        assert_relative_eq!(rect.exterior.0[1].y, 3., epsilon = 1e-9);
+    }
+}
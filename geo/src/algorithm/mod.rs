@@ -4,23 +4,41 @@ pub mod area;
 pub mod bearing;
 /// Returns the Bbox of a geometry.
 pub mod boundingbox;
+/// Interpolates a smooth curve through the vertices of a geometry.
+pub mod catmull_rom;
 /// Calculation of the centroid of a geometry.
 pub mod centroid;
+/// Smooths a geometry by cutting its corners.
+pub mod chaikin_smoothing;
+/// Clips a geometry to a rectangle.
+pub mod clip_to_rect;
 /// Determine the minimum distance between two objects.
 pub mod closest_point;
 /// Checks if the geometry A is completely inside the B geometry.
 pub mod contains;
 /// Calculates the convex hull of a geometry.
 pub mod convexhull;
+/// Helpers for matching coordinates exactly.
+pub(crate) mod coordinate_key;
+/// Simplifies a coverage of polygons, keeping their shared boundaries consistent.
+pub mod coverage_simplify;
+/// Adds vertices to a geometry, so that none of its segments is longer than a given length.
+pub mod densify;
 /// Returns the Euclidean distance between two geometries.
 pub mod euclidean_distance;
 /// Returns the length of a line.
 pub mod euclidean_length;
 /// Returns the extreme indices of a `Polygon`, `MultiPolygon`, or `MultiPoint`.
 pub mod extremes;
+/// Returns the discrete Fréchet distance between two `LineString`s.
+pub mod frechet_distance;
 /// Produces geometry from PostGIS.
 #[cfg(feature = "postgis-integration")]
 pub mod from_postgis;
+/// Encodes and decodes geohashes, and finds the geohashes covering a geometry.
+pub mod geohash;
+/// Returns the Hausdorff distance between two geometries.
+pub mod hausdorff_distance;
 /// Returns a new Point using distance and bearing.
 pub mod haversine_destination;
 /// Returns the Haversine distance between two geometries.
@@ -29,28 +47,64 @@ pub mod haversine_distance;
 pub mod haversine_intermediate;
 /// Returns the Haversine length of a line.
 pub mod haversine_length;
+/// Returns a point guaranteed to lie on a geometry.
+pub mod interior_point;
 /// Checks if the geometry A intersects the geometry B.
 pub mod intersects;
+/// Checks whether a geometry is simple, reporting repeated vertices and self-intersections.
+pub mod is_simple;
+/// Computes the intersection of two lines: a point, an overlapping line, or nothing.
+pub mod line_intersection;
+/// Merges lines which meet end to end into longer lines.
+pub mod line_merge;
 /// Apply a function to all coordinates.
 pub mod map_coords;
+/// Returns the smallest circle enclosing a geometry.
+pub mod minimum_enclosing_circle;
+/// Returns the minimum-area rectangle enclosing a geometry.
+pub mod minimum_rotated_rect;
+/// Encodes geometries as Mapbox Vector Tiles, and decodes them.
+pub mod mvt;
 /// Orients a Polygon's exterior and interior rings.
 pub mod orient;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon distance method.
 pub(crate) mod polygon_distance_fast_path;
+/// Builds polygons from noded lines.
+pub mod polygonize;
+/// Returns the pole of inaccessibility of a polygon, for placing labels.
+pub mod polylabel;
+/// Encodes and decodes `LineString`s as Google encoded polylines.
+pub mod polyline;
+/// Polygons prepared for fast repeated `Contains`, `Intersects` and distance queries.
+pub mod prepared_geometry;
+/// Robust geometric predicates: orientation and in-circle tests.
+pub mod predicates;
 /// Coordinate projections and transformations using [PROJ](http://proj4.org) v5.0.x.
 #[cfg(feature = "use-proj")]
 pub mod proj;
 /// Rotate a geometry around either its centroid or a point by an angle given in degrees.
 pub mod rotate;
-/// Simplifies geometries using the Ramer-Douglas-Peucker algorithm.
+/// Finds all intersections between line segments, using a sweep-line algorithm.
+pub mod segment_intersections;
+/// Returns the shortest line between two geometries.
+pub mod shortest_line;
+/// An R* tree spatial index of geometries and their payloads.
+pub mod spatial_index;
+/// Joins two collections of geometries on a spatial predicate, using an R* tree.
+pub mod spatial_join;
+/// Simplifies geometries using the Ramer-Douglas-Peucker algorithm. Includes a topology-preserving variant.
 pub mod simplify;
-/// Simplifies geometries using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
+/// Simplifies geometries using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant, and one simplifying to a number of points.
 pub mod simplifyvw;
+/// Slippy map tiles: their bounds and quadkeys, and the tiles a geometry covers.
+pub mod tile;
 /// Converts geometries into PostGIS types.
 #[cfg(feature = "postgis-integration")]
 pub mod to_postgis;
 /// Translates a geometry along the given offsets.
 pub mod translate;
+/// Merges many polygons into one `MultiPolygon`, using a cascaded union.
+pub mod unary_union;
 /// Calculate the Vincenty distance between Points.
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a LineString.
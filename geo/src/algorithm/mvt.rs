@@ -0,0 +1,991 @@
+use std::collections::BTreeMap;
+use std::{error, fmt, str};
+
+use num_traits::Float;
+use {
+    Bbox, Coordinate, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+
+/// The type of a feature's geometry, as stored in a vector tile
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GeomType {
+    Unknown,
+    Point,
+    LineString,
+    Polygon,
+}
+
+impl GeomType {
+    fn from_u64(value: u64) -> GeomType {
+        match value {
+            1 => GeomType::Point,
+            2 => GeomType::LineString,
+            3 => GeomType::Polygon,
+            _ => GeomType::Unknown,
+        }
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            GeomType::Unknown => 0,
+            GeomType::Point => 1,
+            GeomType::LineString => 2,
+            GeomType::Polygon => 3,
+        }
+    }
+}
+
+/// The value of one of a feature's attributes
+#[derive(PartialEq, Clone, Debug)]
+pub enum Value {
+    String(String),
+    Float(f32),
+    Double(f64),
+    Int(i64),
+    UInt(u64),
+    SInt(i64),
+    Bool(bool),
+}
+
+/// A feature in a vector tile layer: its encoded geometry, and its attributes
+#[derive(PartialEq, Clone, Debug)]
+pub struct Feature {
+    pub id: Option<u64>,
+    pub properties: Vec<(String, Value)>,
+    pub geom_type: GeomType,
+    /// The geometry, as a stream of command integers and zigzag-encoded coordinate offsets
+    pub geometry: Vec<u32>,
+}
+
+/// A named layer of features in a vector tile
+#[derive(PartialEq, Clone, Debug)]
+pub struct Layer {
+    pub name: String,
+    /// The width and height of the tile, in the units of its features' coordinates
+    pub extent: u32,
+    pub features: Vec<Feature>,
+}
+
+/// A Mapbox Vector Tile, made up of layers of features.
+///
+/// Tiles are encoded to and decoded from the protobuf message of version 2.1 of the
+/// [specification](https://github.com/mapbox/vector-tile-spec).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Bbox, Geometry, LineString, Polygon};
+/// use geo::algorithm::mvt::{Feature, Layer, Tile, Value};
+///
+/// let bbox = Bbox { xmin: 0., xmax: 1024., ymin: 0., ymax: 1024. };
+/// // wound clockwise, as vector tiles need
+/// let field: Geometry<f64> = Polygon::new(
+///     LineString::from(vec![(10., 10.), (10., 30.), (40., 30.), (40., 10.), (10., 10.)]),
+///     vec![],
+/// ).into();
+///
+/// let mut feature = Feature::new(&field, &bbox, 4096).unwrap();
+/// feature.properties.push(("crop".to_string(), Value::String("wheat".to_string())));
+/// let tile = Tile {
+///     layers: vec![Layer { name: "fields".to_string(), extent: 4096, features: vec![feature] }],
+/// };
+///
+/// let decoded = Tile::decode(&tile.encode()).unwrap();
+/// assert_eq!(decoded, tile);
+/// let feature = &decoded.layers[0].features[0];
+/// assert_eq!(feature.to_geometry(&bbox, 4096).unwrap(), field);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct Tile {
+    pub layers: Vec<Layer>,
+}
+
+/// The reasons a tile, or a feature's geometry, can fail to decode
+#[derive(PartialEq, Clone, Debug)]
+pub enum DecodeError {
+    /// The data ended in the middle of a message
+    UnexpectedEnd,
+    /// A field had a protobuf wire type which isn't known
+    UnknownWireType(u64),
+    /// A field had the wrong wire type for its contents
+    WrongWireType,
+    /// A string wasn't valid UTF-8
+    InvalidString,
+    /// A feature's attributes referred to a key or value which its layer doesn't have
+    InvalidTags,
+    /// A feature's geometry didn't make sense for its type
+    InvalidGeometry,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::UnexpectedEnd => write!(f, "vector tile data ended unexpectedly"),
+            DecodeError::UnknownWireType(wire_type) => {
+                write!(f, "unknown protobuf wire type {}", wire_type)
+            }
+            DecodeError::WrongWireType => write!(f, "protobuf field has the wrong wire type"),
+            DecodeError::InvalidString => write!(f, "vector tile string is not valid UTF-8"),
+            DecodeError::InvalidTags => write!(f, "vector tile feature has invalid tags"),
+            DecodeError::InvalidGeometry => write!(f, "vector tile feature has invalid geometry"),
+        }
+    }
+}
+
+impl error::Error for DecodeError {
+    fn description(&self) -> &str {
+        "failed to decode vector tile"
+    }
+}
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+fn zigzag(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+fn unzigzag(n: u32) -> i32 {
+    ((n >> 1) as i32) ^ -((n & 1) as i32)
+}
+
+/// Converts between coordinates and the integer grid of a tile, whose y axis points down
+struct Grid<T>
+where
+    T: Float,
+{
+    bbox: Bbox<T>,
+    extent: T,
+}
+
+impl<T> Grid<T>
+where
+    T: Float,
+{
+    fn new(bbox: &Bbox<T>, extent: u32) -> Grid<T> {
+        Grid {
+            bbox: *bbox,
+            extent: T::from(extent).unwrap(),
+        }
+    }
+
+    fn snap(&self, c: &Coordinate<T>) -> Option<(i32, i32)> {
+        let x = (c.x - self.bbox.xmin) / (self.bbox.xmax - self.bbox.xmin) * self.extent;
+        let y = (self.bbox.ymax - c.y) / (self.bbox.ymax - self.bbox.ymin) * self.extent;
+        Some((x.round().to_i32()?, y.round().to_i32()?))
+    }
+
+    fn unsnap(&self, (x, y): (i32, i32)) -> Coordinate<T> {
+        let (x, y) = (T::from(x).unwrap(), T::from(y).unwrap());
+        Coordinate {
+            x: self.bbox.xmin + x / self.extent * (self.bbox.xmax - self.bbox.xmin),
+            y: self.bbox.ymax - y / self.extent * (self.bbox.ymax - self.bbox.ymin),
+        }
+    }
+
+    /// The vertices of a line on the grid, without repeats, or `None` if any of them is off
+    /// the grid
+    fn line(&self, line_string: &LineString<T>) -> Option<Vec<(i32, i32)>> {
+        let mut points = line_string
+            .0
+            .iter()
+            .map(|c| self.snap(c))
+            .collect::<Option<Vec<_>>>()?;
+        points.dedup();
+        Some(points)
+    }
+
+    /// The vertices of a ring on the grid, without repeating the first, wound clockwise on
+    /// screen if `exterior`, and anti-clockwise if not. The ring is empty if it collapses on
+    /// the grid, and `None` if any of its vertices is off the grid.
+    fn ring(&self, ring: &LineString<T>, exterior: bool) -> Option<Vec<(i32, i32)>> {
+        let mut points = self.line(ring)?;
+        if points.len() > 1 && points[0] == points[points.len() - 1] {
+            points.pop();
+        }
+        let area = twice_area(&points);
+        if points.len() < 3 || area == 0 {
+            return Some(vec![]);
+        }
+        if (area > 0) != exterior {
+            points.reverse();
+        }
+        Some(points)
+    }
+}
+
+/// Twice the signed area of a ring on the grid, which is positive for rings wound clockwise
+/// on screen
+fn twice_area(ring: &[(i32, i32)]) -> i64 {
+    (0..ring.len())
+        .map(|i| {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            i64::from(a.0) * i64::from(b.1) - i64::from(b.0) * i64::from(a.1)
+        })
+        .sum()
+}
+
+/// Writes the command stream of a feature's geometry
+struct Encoder {
+    commands: Vec<u32>,
+    cursor: (i32, i32),
+}
+
+impl Encoder {
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push(id | ((count as u32) << 3));
+    }
+
+    fn to(&mut self, point: (i32, i32)) {
+        self.commands
+            .push(zigzag(point.0.wrapping_sub(self.cursor.0)));
+        self.commands
+            .push(zigzag(point.1.wrapping_sub(self.cursor.1)));
+        self.cursor = point;
+    }
+
+    fn points(&mut self, points: &[(i32, i32)]) {
+        self.command(MOVE_TO, points.len());
+        for &point in points {
+            self.to(point);
+        }
+    }
+
+    fn line(&mut self, line: &[(i32, i32)]) {
+        self.command(MOVE_TO, 1);
+        self.to(line[0]);
+        self.command(LINE_TO, line.len() - 1);
+        for &point in &line[1..] {
+            self.to(point);
+        }
+    }
+
+    fn ring(&mut self, ring: &[(i32, i32)]) {
+        self.line(ring);
+        self.command(CLOSE_PATH, 1);
+    }
+}
+
+impl Feature {
+    /// Encodes a geometry as a feature of a tile covering `bbox`, whose layer has the given
+    /// extent.
+    ///
+    /// Coordinates are rounded to the tile's integer grid, with x increasing to the right
+    /// and y increasing downwards, and repeated points are dropped. Exterior rings are wound
+    /// clockwise, as seen on the map, and interior rings anti-clockwise, reversing them if
+    /// need be. Geometries should be clipped
+    /// to the tile first, perhaps with a buffer, using
+    /// [`ClipToRect`](../clip_to_rect/trait.ClipToRect.html).
+    ///
+    /// Returns `None` if nothing is left of the geometry on the grid, if any of its
+    /// coordinates lies so far outside the tile that it can't be put on the grid, or if it's
+    /// a `GeometryCollection`, which a single feature can't hold.
+    pub fn new<T>(geometry: &Geometry<T>, bbox: &Bbox<T>, extent: u32) -> Option<Feature>
+    where
+        T: Float,
+    {
+        let grid = Grid::new(bbox, extent);
+        let mut encoder = Encoder {
+            commands: vec![],
+            cursor: (0, 0),
+        };
+        let geom_type = match *geometry {
+            Geometry::Point(ref p) => {
+                encoder.points(&[grid.snap(&p.0)?]);
+                GeomType::Point
+            }
+            Geometry::MultiPoint(ref mp) => {
+                let points =
+                    mp.0.iter()
+                        .map(|p| grid.snap(&p.0))
+                        .collect::<Option<Vec<_>>>()?;
+                if points.is_empty() {
+                    return None;
+                }
+                encoder.points(&points);
+                GeomType::Point
+            }
+            Geometry::Line(ref l) => {
+                let line = grid.line(&LineString(vec![l.start, l.end]))?;
+                if line.len() > 1 {
+                    encoder.line(&line);
+                }
+                GeomType::LineString
+            }
+            Geometry::LineString(ref ls) => {
+                let line = grid.line(ls)?;
+                if line.len() > 1 {
+                    encoder.line(&line);
+                }
+                GeomType::LineString
+            }
+            Geometry::MultiLineString(ref mls) => {
+                for ls in &mls.0 {
+                    let line = grid.line(ls)?;
+                    if line.len() > 1 {
+                        encoder.line(&line);
+                    }
+                }
+                GeomType::LineString
+            }
+            Geometry::Polygon(ref p) => {
+                encode_polygon(&grid, &mut encoder, p)?;
+                GeomType::Polygon
+            }
+            Geometry::MultiPolygon(ref mp) => {
+                for p in &mp.0 {
+                    encode_polygon(&grid, &mut encoder, p)?;
+                }
+                GeomType::Polygon
+            }
+            Geometry::GeometryCollection(_) => return None,
+        };
+        if encoder.commands.is_empty() {
+            return None;
+        }
+        Some(Feature {
+            id: None,
+            properties: vec![],
+            geom_type,
+            geometry: encoder.commands,
+        })
+    }
+
+    /// Decodes the feature's geometry, for a tile covering `bbox` whose layer has the given
+    /// extent.
+    ///
+    /// Features with one point, line or polygon give a `Point`, `LineString` or `Polygon`,
+    /// and those with more give a multi-geometry. Polygon rings wound clockwise start a new
+    /// polygon, and those wound anti-clockwise are interiors of the polygon before them. The
+    /// rings keep their winding.
+    pub fn to_geometry<T>(&self, bbox: &Bbox<T>, extent: u32) -> Result<Geometry<T>, DecodeError>
+    where
+        T: Float,
+    {
+        let grid = Grid::new(bbox, extent);
+        let paths = decode_paths(&self.geometry)?;
+        let line = |path: &[(i32, i32)]| LineString(path.iter().map(|&p| grid.unsnap(p)).collect());
+        match self.geom_type {
+            GeomType::Point => {
+                let mut points: Vec<Point<T>> = paths
+                    .iter()
+                    .flat_map(|path| path.points.iter().map(|&p| Point(grid.unsnap(p))))
+                    .collect();
+                match points.len() {
+                    0 => Err(DecodeError::InvalidGeometry),
+                    1 => Ok(Geometry::Point(points.remove(0))),
+                    _ => Ok(Geometry::MultiPoint(MultiPoint(points))),
+                }
+            }
+            GeomType::LineString => {
+                if paths
+                    .iter()
+                    .any(|path| path.points.len() < 2 || path.closed)
+                {
+                    return Err(DecodeError::InvalidGeometry);
+                }
+                let mut lines: Vec<LineString<T>> =
+                    paths.iter().map(|path| line(&path.points)).collect();
+                match lines.len() {
+                    0 => Err(DecodeError::InvalidGeometry),
+                    1 => Ok(Geometry::LineString(lines.remove(0))),
+                    _ => Ok(Geometry::MultiLineString(MultiLineString(lines))),
+                }
+            }
+            GeomType::Polygon => {
+                let mut polygons: Vec<Polygon<T>> = vec![];
+                for path in &paths {
+                    let area = twice_area(&path.points);
+                    if !path.closed || path.points.len() < 3 || area == 0 {
+                        return Err(DecodeError::InvalidGeometry);
+                    }
+                    let mut ring = line(&path.points);
+                    ring.0.push(ring.0[0]);
+                    if area > 0 {
+                        polygons.push(Polygon::new(ring, vec![]));
+                    } else {
+                        match polygons.last_mut() {
+                            Some(polygon) => polygon.interiors.push(ring),
+                            None => return Err(DecodeError::InvalidGeometry),
+                        }
+                    }
+                }
+                match polygons.len() {
+                    0 => Err(DecodeError::InvalidGeometry),
+                    1 => Ok(Geometry::Polygon(polygons.remove(0))),
+                    _ => Ok(Geometry::MultiPolygon(MultiPolygon(polygons))),
+                }
+            }
+            GeomType::Unknown => Err(DecodeError::InvalidGeometry),
+        }
+    }
+}
+
+fn encode_polygon<T>(grid: &Grid<T>, encoder: &mut Encoder, polygon: &Polygon<T>) -> Option<()>
+where
+    T: Float,
+{
+    let exterior = grid.ring(&polygon.exterior, true)?;
+    let interiors = polygon
+        .interiors
+        .iter()
+        .map(|ring| grid.ring(ring, false))
+        .collect::<Option<Vec<_>>>()?;
+    if !exterior.is_empty() {
+        encoder.ring(&exterior);
+        for interior in interiors.iter().filter(|ring| !ring.is_empty()) {
+            encoder.ring(interior);
+        }
+    }
+    Some(())
+}
+
+/// The points on the grid which a command stream moves through, from one `MoveTo` to the next
+struct Path {
+    points: Vec<(i32, i32)>,
+    closed: bool,
+}
+
+/// Splits a command stream into paths
+fn decode_paths(commands: &[u32]) -> Result<Vec<Path>, DecodeError> {
+    let mut paths: Vec<Path> = vec![];
+    let mut cursor = (0i32, 0i32);
+    let mut commands = commands.iter();
+    while let Some(&command) = commands.next() {
+        let (id, count) = (command & 0x7, command >> 3);
+        match id {
+            MOVE_TO | LINE_TO => {
+                for _ in 0..count {
+                    let (dx, dy) = match (commands.next(), commands.next()) {
+                        (Some(&dx), Some(&dy)) => (unzigzag(dx), unzigzag(dy)),
+                        _ => return Err(DecodeError::InvalidGeometry),
+                    };
+                    cursor = (cursor.0.wrapping_add(dx), cursor.1.wrapping_add(dy));
+                    match (id, paths.last_mut()) {
+                        (MOVE_TO, _) => paths.push(Path {
+                            points: vec![cursor],
+                            closed: false,
+                        }),
+                        (_, Some(ref mut path)) if !path.closed => path.points.push(cursor),
+                        _ => return Err(DecodeError::InvalidGeometry),
+                    }
+                }
+            }
+            CLOSE_PATH => match paths.last_mut() {
+                Some(ref mut path) if count == 1 && !path.closed => path.closed = true,
+                _ => return Err(DecodeError::InvalidGeometry),
+            },
+            _ => return Err(DecodeError::InvalidGeometry),
+        }
+    }
+    Ok(paths)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_key(buf: &mut Vec<u8>, field: u32, wire_type: u32) {
+    write_varint(buf, u64::from((field << 3) | wire_type));
+}
+
+fn write_varint_field(buf: &mut Vec<u8>, field: u32, value: u64) {
+    write_key(buf, field, 0);
+    write_varint(buf, value);
+}
+
+fn write_bytes_field(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_key(buf, field, 2);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn write_packed_field(buf: &mut Vec<u8>, field: u32, values: &[u32]) {
+    let mut packed = vec![];
+    for &value in values {
+        write_varint(&mut packed, u64::from(value));
+    }
+    write_bytes_field(buf, field, &packed);
+}
+
+fn write_fixed_field(buf: &mut Vec<u8>, field: u32, value: u64, bytes: usize) {
+    write_key(buf, field, if bytes == 4 { 5 } else { 1 });
+    for i in 0..bytes {
+        buf.push((value >> (8 * i)) as u8);
+    }
+}
+
+fn encode_value(value: &Value) -> Vec<u8> {
+    let mut buf = vec![];
+    match *value {
+        Value::String(ref s) => write_bytes_field(&mut buf, 1, s.as_bytes()),
+        Value::Float(f) => write_fixed_field(&mut buf, 2, u64::from(f.to_bits()), 4),
+        Value::Double(d) => write_fixed_field(&mut buf, 3, d.to_bits(), 8),
+        Value::Int(i) => write_varint_field(&mut buf, 4, i as u64),
+        Value::UInt(u) => write_varint_field(&mut buf, 5, u),
+        Value::SInt(i) => write_varint_field(&mut buf, 6, ((i << 1) ^ (i >> 63)) as u64),
+        Value::Bool(b) => write_varint_field(&mut buf, 7, b as u64),
+    }
+    buf
+}
+
+fn encode_layer(layer: &Layer) -> Vec<u8> {
+    let mut buf = vec![];
+    write_varint_field(&mut buf, 15, 2);
+    write_bytes_field(&mut buf, 1, layer.name.as_bytes());
+    // keys and values are shared between features, by their index in the layer
+    let mut keys: BTreeMap<&str, u32> = BTreeMap::new();
+    let mut values: BTreeMap<Vec<u8>, u32> = BTreeMap::new();
+    let (mut key_list, mut value_list) = (vec![], vec![]);
+    for feature in &layer.features {
+        let mut message = vec![];
+        if let Some(id) = feature.id {
+            write_varint_field(&mut message, 1, id);
+        }
+        let mut tags = vec![];
+        for (key, value) in &feature.properties {
+            let next = key_list.len() as u32;
+            let key = *keys.entry(key.as_str()).or_insert_with(|| {
+                key_list.push(key.as_str());
+                next
+            });
+            let encoded = encode_value(value);
+            let next = value_list.len() as u32;
+            let value = *values.entry(encoded.clone()).or_insert_with(|| {
+                value_list.push(encoded);
+                next
+            });
+            tags.push(key);
+            tags.push(value);
+        }
+        if !tags.is_empty() {
+            write_packed_field(&mut message, 2, &tags);
+        }
+        write_varint_field(&mut message, 3, feature.geom_type.to_u64());
+        write_packed_field(&mut message, 4, &feature.geometry);
+        write_bytes_field(&mut buf, 2, &message);
+    }
+    for key in key_list {
+        write_bytes_field(&mut buf, 3, key.as_bytes());
+    }
+    for value in value_list {
+        write_bytes_field(&mut buf, 4, &value);
+    }
+    write_varint_field(&mut buf, 5, u64::from(layer.extent));
+    buf
+}
+
+/// A field of a protobuf message
+enum Field<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> Field<'a> {
+    fn varint(&self) -> Result<u64, DecodeError> {
+        match *self {
+            Field::Varint(value) => Ok(value),
+            _ => Err(DecodeError::WrongWireType),
+        }
+    }
+
+    fn string(&self) -> Result<String, DecodeError> {
+        match *self {
+            Field::Bytes(bytes) => str::from_utf8(bytes)
+                .map(|s| s.to_string())
+                .map_err(|_| DecodeError::InvalidString),
+            _ => Err(DecodeError::WrongWireType),
+        }
+    }
+
+    /// Appends the values of a repeated `uint32` field, packed or not
+    fn packed(&self, values: &mut Vec<u32>) -> Result<(), DecodeError> {
+        match *self {
+            Field::Varint(value) => values.push(value as u32),
+            Field::Bytes(bytes) => {
+                let mut reader = Reader { data: bytes };
+                while !reader.data.is_empty() {
+                    values.push(reader.varint()? as u32);
+                }
+            }
+            _ => return Err(DecodeError::WrongWireType),
+        }
+        Ok(())
+    }
+}
+
+/// Reads the fields of a protobuf message in turn
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if len > self.data.len() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn fixed(&mut self, len: usize) -> Result<u64, DecodeError> {
+        let bytes = self.take(len)?;
+        Ok(bytes
+            .iter()
+            .enumerate()
+            .fold(0, |value, (i, &b)| value | u64::from(b) << (8 * i)))
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0;
+        for shift in 0..10 {
+            let b = self.take(1)?[0];
+            value |= u64::from(b & 0x7f) << (7 * shift);
+            if b < 0x80 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::UnexpectedEnd)
+    }
+
+    fn field(&mut self) -> Result<Option<(u64, Field<'a>)>, DecodeError> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let field = match key & 0x7 {
+            0 => Field::Varint(self.varint()?),
+            1 => Field::Fixed64(self.fixed(8)?),
+            2 => {
+                let len = self.varint()? as usize;
+                Field::Bytes(self.take(len)?)
+            }
+            5 => Field::Fixed32(self.fixed(4)? as u32),
+            wire_type => return Err(DecodeError::UnknownWireType(wire_type)),
+        };
+        Ok(Some((key >> 3, field)))
+    }
+}
+
+fn message<'a>(field: &Field<'a>) -> Result<Reader<'a>, DecodeError> {
+    match *field {
+        Field::Bytes(data) => Ok(Reader { data }),
+        _ => Err(DecodeError::WrongWireType),
+    }
+}
+
+fn decode_value(mut reader: Reader) -> Result<Value, DecodeError> {
+    let mut value = None;
+    while let Some((number, field)) = reader.field()? {
+        value = match (number, field) {
+            (1, ref field) => Some(Value::String(field.string()?)),
+            (2, Field::Fixed32(bits)) => Some(Value::Float(f32::from_bits(bits))),
+            (3, Field::Fixed64(bits)) => Some(Value::Double(f64::from_bits(bits))),
+            (4, Field::Varint(v)) => Some(Value::Int(v as i64)),
+            (5, Field::Varint(v)) => Some(Value::UInt(v)),
+            (6, Field::Varint(v)) => Some(Value::SInt((v >> 1) as i64 ^ -((v & 1) as i64))),
+            (7, Field::Varint(v)) => Some(Value::Bool(v != 0)),
+            _ => value,
+        };
+    }
+    value.ok_or(DecodeError::InvalidTags)
+}
+
+fn decode_layer(mut reader: Reader) -> Result<Layer, DecodeError> {
+    let mut layer = Layer {
+        name: String::new(),
+        extent: 4096,
+        features: vec![],
+    };
+    let (mut keys, mut values, mut tags) = (vec![], vec![], vec![]);
+    while let Some((number, field)) = reader.field()? {
+        match number {
+            1 => layer.name = field.string()?,
+            2 => {
+                let mut feature = Feature {
+                    id: None,
+                    properties: vec![],
+                    geom_type: GeomType::Unknown,
+                    geometry: vec![],
+                };
+                let mut feature_tags = vec![];
+                let mut reader = message(&field)?;
+                while let Some((number, field)) = reader.field()? {
+                    match number {
+                        1 => feature.id = Some(field.varint()?),
+                        2 => field.packed(&mut feature_tags)?,
+                        3 => feature.geom_type = GeomType::from_u64(field.varint()?),
+                        4 => field.packed(&mut feature.geometry)?,
+                        _ => {}
+                    }
+                }
+                layer.features.push(feature);
+                tags.push(feature_tags);
+            }
+            3 => keys.push(field.string()?),
+            4 => values.push(decode_value(message(&field)?)?),
+            5 => layer.extent = field.varint()? as u32,
+            _ => {}
+        }
+    }
+    // features may come before the keys and values they refer to
+    for (feature, tags) in layer.features.iter_mut().zip(tags) {
+        if tags.len() % 2 == 1 {
+            return Err(DecodeError::InvalidTags);
+        }
+        for pair in tags.chunks(2) {
+            match (keys.get(pair[0] as usize), values.get(pair[1] as usize)) {
+                (Some(key), Some(value)) => feature.properties.push((key.clone(), value.clone())),
+                _ => return Err(DecodeError::InvalidTags),
+            }
+        }
+    }
+    Ok(layer)
+}
+
+impl Tile {
+    /// Encodes the tile as a protobuf message.
+    ///
+    /// Each layer's attribute keys and values are stored once, and shared between its
+    /// features.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        for layer in &self.layers {
+            write_bytes_field(&mut buf, 3, &encode_layer(layer));
+        }
+        buf
+    }
+
+    /// Decodes a tile from a protobuf message
+    pub fn decode(data: &[u8]) -> Result<Tile, DecodeError> {
+        let mut reader = Reader { data };
+        let mut layers = vec![];
+        while let Some((number, field)) = reader.field()? {
+            if number == 3 {
+                layers.push(decode_layer(message(&field)?)?);
+            }
+        }
+        Ok(Tile { layers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Line;
+
+    fn bbox() -> Bbox<f64> {
+        Bbox {
+            xmin: 0.,
+            xmax: 4096.,
+            ymin: 0.,
+            ymax: 4096.,
+        }
+    }
+
+    #[test]
+    fn spec_examples_test() {
+        // the examples from section 4.3.5 of the specification, with y flipped to point up
+        let flip = |x: f64, y: f64| (x, 4096. - y);
+        let point: Geometry<f64> = Point::from(flip(25., 17.)).into();
+        let feature = Feature::new(&point, &bbox(), 4096).unwrap();
+        assert_eq!(feature.geom_type, GeomType::Point);
+        assert_eq!(feature.geometry, vec![9, 50, 34]);
+
+        let points: Geometry<f64> =
+            MultiPoint(vec![Point::from(flip(5., 7.)), Point::from(flip(3., 2.))]).into();
+        let feature = Feature::new(&points, &bbox(), 4096).unwrap();
+        assert_eq!(feature.geometry, vec![17, 10, 14, 3, 9]);
+
+        let line: Geometry<f64> =
+            LineString::from(vec![flip(2., 2.), flip(2., 10.), flip(10., 10.)]).into();
+        let feature = Feature::new(&line, &bbox(), 4096).unwrap();
+        assert_eq!(feature.geom_type, GeomType::LineString);
+        assert_eq!(feature.geometry, vec![9, 4, 4, 18, 0, 16, 16, 0]);
+
+        let polygon: Geometry<f64> = Polygon::new(
+            LineString::from(vec![
+                flip(3., 6.),
+                flip(8., 12.),
+                flip(20., 34.),
+                flip(3., 6.),
+            ]),
+            vec![],
+        )
+        .into();
+        let feature = Feature::new(&polygon, &bbox(), 4096).unwrap();
+        assert_eq!(feature.geom_type, GeomType::Polygon);
+        assert_eq!(feature.geometry, vec![9, 6, 12, 18, 10, 12, 24, 44, 15]);
+    }
+
+    #[test]
+    fn winding_test() {
+        // an exterior wound anti-clockwise, and an interior clockwise, so both are reversed
+        let polygon: Geometry<f64> = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (2., 4.),
+                (4., 4.),
+                (4., 2.),
+                (2., 2.),
+            ])],
+        )
+        .into();
+        let bbox = Bbox {
+            xmin: 0.,
+            xmax: 10.,
+            ymin: 0.,
+            ymax: 10.,
+        };
+        let feature = Feature::new(&polygon, &bbox, 10).unwrap();
+        let paths = decode_paths(&feature.geometry).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert!(twice_area(&paths[0].points) > 0);
+        assert!(twice_area(&paths[1].points) < 0);
+        assert_eq!(
+            feature.to_geometry(&bbox, 10).unwrap(),
+            Polygon::new(
+                LineString::from(vec![(0., 10.), (10., 10.), (10., 0.), (0., 0.), (0., 10.)]),
+                vec![LineString::from(vec![
+                    (4., 2.),
+                    (4., 4.),
+                    (2., 4.),
+                    (2., 2.),
+                    (4., 2.),
+                ])],
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let bbox = Bbox {
+            xmin: -10.,
+            xmax: 10.,
+            ymin: 40.,
+            ymax: 60.,
+        };
+        let geometries: Vec<Geometry<f64>> = vec![
+            Point::new(-5., 45.).into(),
+            MultiLineString(vec![
+                LineString::from(vec![(-10., 40.), (0., 50.), (10., 40.)]),
+                LineString::from(vec![(-10., 60.), (10., 60.)]),
+            ])
+            .into(),
+            MultiPolygon(vec![
+                Polygon::new(
+                    LineString::from(vec![(0., 40.), (5., 45.), (5., 40.), (0., 40.)]),
+                    vec![],
+                ),
+                Polygon::new(
+                    LineString::from(vec![(-5., 50.), (0., 55.), (0., 50.), (-5., 50.)]),
+                    vec![],
+                ),
+            ])
+            .into(),
+        ];
+        let mut features: Vec<Feature> = geometries
+            .iter()
+            .map(|g| Feature::new(g, &bbox, 256).unwrap())
+            .collect();
+        features[0].id = Some(7);
+        features[0].properties = vec![
+            ("name".to_string(), Value::String("spring".to_string())),
+            ("depth".to_string(), Value::Double(2.5)),
+        ];
+        features[1].properties = vec![
+            ("name".to_string(), Value::String("road".to_string())),
+            ("lanes".to_string(), Value::UInt(2)),
+            ("level".to_string(), Value::SInt(-1)),
+        ];
+        features[2].properties = vec![
+            ("lit".to_string(), Value::Bool(true)),
+            ("width".to_string(), Value::Float(0.5)),
+            ("offset".to_string(), Value::Int(-3)),
+        ];
+        let tile = Tile {
+            layers: vec![
+                Layer {
+                    name: "things".to_string(),
+                    extent: 256,
+                    features,
+                },
+                Layer {
+                    name: "empty".to_string(),
+                    extent: 4096,
+                    features: vec![],
+                },
+            ],
+        };
+        let decoded = Tile::decode(&tile.encode()).unwrap();
+        assert_eq!(decoded, tile);
+        for (feature, geometry) in decoded.layers[0].features.iter().zip(&geometries) {
+            assert_eq!(&feature.to_geometry(&bbox, 256).unwrap(), geometry);
+        }
+    }
+
+    #[test]
+    fn vanishing_test() {
+        // too small to survive being snapped to the grid
+        let line: Geometry<f64> = Line::from([(1., 1.), (1.1, 1.1)]).into();
+        assert_eq!(Feature::new(&line, &bbox(), 4096), None);
+        let polygon: Geometry<f64> = Polygon::new(
+            LineString::from(vec![(1., 1.), (1.2, 1.), (1.2, 1.2), (1., 1.)]),
+            vec![],
+        )
+        .into();
+        assert_eq!(Feature::new(&polygon, &bbox(), 4096), None);
+    }
+
+    #[test]
+    fn off_grid_test() {
+        // the last vertex is too far from the tile to fit on the grid, and dropping it would
+        // change the line's shape
+        let line: Geometry<f64> = LineString::from(vec![(1., 1.), (5., 5.), (1e12, 1.)]).into();
+        assert_eq!(Feature::new(&line, &bbox(), 4096), None);
+        let polygon: Geometry<f64> = Polygon::new(
+            LineString::from(vec![(1., 1.), (5., 1.), (5., 5.), (1., 5.), (1., 1.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (2., 3.),
+                (1e12, 3.),
+                (2., 2.),
+            ])],
+        )
+        .into();
+        assert_eq!(Feature::new(&polygon, &bbox(), 4096), None);
+        let points: Geometry<f64> =
+            MultiPoint(vec![Point::new(1., 1.), Point::new(1e12, 1.)]).into();
+        assert_eq!(Feature::new(&points, &bbox(), 4096), None);
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert_eq!(
+            Tile::decode(&[0x1a, 0x05, 0x0a]),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        let feature = Feature {
+            id: None,
+            properties: vec![],
+            geom_type: GeomType::LineString,
+            // LineTo before any MoveTo
+            geometry: vec![10, 2, 2],
+        };
+        assert_eq!(
+            feature.to_geometry(&bbox(), 4096),
+            Err(DecodeError::InvalidGeometry)
+        );
+    }
+}
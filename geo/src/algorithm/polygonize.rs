@@ -0,0 +1,410 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::{get_position, PositionPoint};
+use algorithm::coordinate_key::{key, Key};
+use algorithm::winding_order::twice_signed_ring_area;
+use num_traits::Float;
+use {Bbox, Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+
+/// The result of polygonizing a set of lines
+#[derive(PartialEq, Clone, Debug)]
+pub struct Polygonized<T>
+where
+    T: Float,
+{
+    /// The polygons formed by the lines, with their exteriors wound counter-clockwise, and
+    /// their interiors clockwise
+    pub polygons: MultiPolygon<T>,
+    /// Lines which lead nowhere: one of their ends meets no other line, except for more dangles
+    pub dangles: MultiLineString<T>,
+    /// Lines which don't bound a polygon: they have the same polygon, or none, on both sides
+    pub cut_edges: MultiLineString<T>,
+}
+
+/// Builds polygons from a set of lines.
+///
+/// The lines must be noded: they may only meet at their ends. Each face which the lines
+/// divide the plane into becomes a polygon, with any faces inside it, or the lines around
+/// them, as interiors. A line which is closed, or lines which meet end to end around a face,
+/// become rings. Repeated lines are only used once.
+///
+/// Lines which can't bound a polygon are reported rather than used: dangles, which only
+/// touch the rest of the lines at one end, and cut edges, which join two parts that are
+/// otherwise separate, or cut into a polygon without dividing it.
+pub trait Polygonize<T>
+where
+    T: Float,
+{
+    /// Returns the polygons formed by the lines, along with their dangles and cut edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, MultiLineString, Polygon};
+    /// use geo::algorithm::polygonize::Polygonize;
+    ///
+    /// // two plots, sharing a boundary, and a path leading up to one of them
+    /// let lines = MultiLineString(vec![
+    ///     LineString::from(vec![(5., 0.), (5., 5.)]),
+    ///     LineString::from(vec![(5., 5.), (0., 5.), (0., 0.), (5., 0.)]),
+    ///     LineString::from(vec![(5., 0.), (9., 0.), (9., 5.)]),
+    ///     LineString::from(vec![(9., 5.), (5., 5.)]),
+    ///     LineString::from(vec![(9., 5.), (12., 8.)]),
+    /// ]);
+    ///
+    /// let polygonized = lines.polygonize();
+    /// assert_eq!(
+    ///     polygonized.polygons.0,
+    ///     vec![
+    ///         Polygon::new(
+    ///             LineString::from(vec![(5., 0.), (9., 0.), (9., 5.), (5., 5.), (5., 0.)]),
+    ///             vec![],
+    ///         ),
+    ///         Polygon::new(
+    ///             LineString::from(vec![(5., 0.), (5., 5.), (0., 5.), (0., 0.), (5., 0.)]),
+    ///             vec![],
+    ///         ),
+    ///     ]
+    /// );
+    /// assert_eq!(polygonized.dangles.0, vec![lines.0[4].clone()]);
+    /// assert!(polygonized.cut_edges.0.is_empty());
+    /// ```
+    fn polygonize(&self) -> Polygonized<T>;
+}
+
+/// A planar graph of lines, which meet at nodes at their ends. Each edge `e` is made up of
+/// two half edges: `2 * e` runs along the line, and `2 * e + 1` runs back.
+struct Graph<T>
+where
+    T: Float,
+{
+    edges: Vec<Vec<Coordinate<T>>>,
+    ends: Vec<(usize, usize)>,
+    /// The half edges leaving each node, counter-clockwise
+    outgoing: Vec<Vec<usize>>,
+}
+
+impl<T> Graph<T>
+where
+    T: Float,
+{
+    fn new<I>(lines: I) -> Graph<T>
+    where
+        I: IntoIterator<Item = Vec<Coordinate<T>>>,
+    {
+        let mut graph = Graph {
+            edges: vec![],
+            ends: vec![],
+            outgoing: vec![],
+        };
+        let mut nodes = BTreeMap::new();
+        let mut seen = BTreeSet::new();
+        for mut line in lines {
+            line.dedup();
+            if line.len() < 2 {
+                continue;
+            }
+            let forward: Vec<Key> = line.iter().map(key).collect();
+            let backward: Vec<Key> = forward.iter().rev().cloned().collect();
+            if !seen.insert(forward.min(backward)) {
+                continue;
+            }
+            let e = graph.edges.len();
+            let start = graph.node(&mut nodes, &line[0]);
+            let end = graph.node(&mut nodes, &line[line.len() - 1]);
+            graph.outgoing[start].push(2 * e);
+            graph.outgoing[end].push(2 * e + 1);
+            graph.ends.push((start, end));
+            graph.edges.push(line);
+        }
+        for outgoing in &mut graph.outgoing {
+            let edges = &graph.edges;
+            outgoing.sort_by(|&a, &b| angle(edges, a).partial_cmp(&angle(edges, b)).unwrap());
+        }
+        graph
+    }
+
+    fn node(&mut self, nodes: &mut BTreeMap<Key, usize>, c: &Coordinate<T>) -> usize {
+        let outgoing = &mut self.outgoing;
+        *nodes.entry(key(c)).or_insert_with(|| {
+            outgoing.push(vec![]);
+            outgoing.len() - 1
+        })
+    }
+
+    fn from(&self, half: usize) -> usize {
+        let (start, end) = self.ends[half / 2];
+        if half % 2 == 1 {
+            end
+        } else {
+            start
+        }
+    }
+
+    fn remove(&mut self, edge: usize) {
+        let (start, end) = self.ends[edge];
+        self.outgoing[start].retain(|&h| h / 2 != edge);
+        self.outgoing[end].retain(|&h| h / 2 != edge);
+    }
+
+    /// Removes dangles, returning them
+    fn remove_dangles(&mut self) -> Vec<usize> {
+        let mut dangles = vec![];
+        let mut stack: Vec<usize> = (0..self.outgoing.len()).collect();
+        while let Some(node) = stack.pop() {
+            if self.outgoing[node].len() != 1 {
+                continue;
+            }
+            let half = self.outgoing[node][0];
+            let other = self.from(half ^ 1);
+            self.remove(half / 2);
+            dangles.push(half / 2);
+            stack.push(other);
+        }
+        dangles.sort();
+        dangles
+    }
+
+    /// The half edge after `half` around the face on its left
+    fn next(&self, half: usize) -> usize {
+        let twin = half ^ 1;
+        let outgoing = &self.outgoing[self.from(twin)];
+        let i = outgoing.iter().position(|&h| h == twin).unwrap();
+        // the next half edge clockwise from the twin
+        outgoing[(i + outgoing.len() - 1) % outgoing.len()]
+    }
+
+    /// The cycles of half edges around each face, and the cycle each half edge belongs to
+    fn cycles(&self) -> (Vec<Vec<usize>>, Vec<Option<usize>>) {
+        let mut cycles = vec![];
+        let mut cycle_of = vec![None; 2 * self.edges.len()];
+        for outgoing in &self.outgoing {
+            for &start in outgoing {
+                if cycle_of[start].is_some() {
+                    continue;
+                }
+                let mut cycle = vec![];
+                let mut half = start;
+                loop {
+                    cycle_of[half] = Some(cycles.len());
+                    cycle.push(half);
+                    half = self.next(half);
+                    if half == start {
+                        break;
+                    }
+                }
+                cycles.push(cycle);
+            }
+        }
+        (cycles, cycle_of)
+    }
+
+    /// Removes cut edges, returning them
+    fn remove_cut_edges(&mut self) -> Vec<usize> {
+        let (_, cycle_of) = self.cycles();
+        let cut_edges: Vec<_> = (0..self.edges.len())
+            .filter(|&e| cycle_of[2 * e].is_some() && cycle_of[2 * e] == cycle_of[2 * e + 1])
+            .collect();
+        for &e in &cut_edges {
+            self.remove(e);
+        }
+        cut_edges
+    }
+
+    fn ring(&self, cycle: &[usize]) -> LineString<T> {
+        let mut ring: Vec<Coordinate<T>> = vec![];
+        for &half in cycle {
+            let mut coords = self.edges[half / 2].clone();
+            if half % 2 == 1 {
+                coords.reverse();
+            }
+            let skip = if ring.is_empty() { 0 } else { 1 };
+            ring.extend(coords.into_iter().skip(skip));
+        }
+        LineString(ring)
+    }
+}
+
+/// The direction in which a half edge leaves its node
+fn angle<T>(edges: &[Vec<Coordinate<T>>], half: usize) -> T
+where
+    T: Float,
+{
+    let edge = &edges[half / 2];
+    let (from, to) = if half % 2 == 1 {
+        (edge[edge.len() - 1], edge[edge.len() - 2])
+    } else {
+        (edge[0], edge[1])
+    };
+    (to.y - from.y).atan2(to.x - from.x)
+}
+
+/// Is `hole` inside `shell`? `hole` may touch `shell`, but not lie entirely along it
+fn encloses<T>(shell: &(LineString<T>, Bbox<T>), hole: &(LineString<T>, Bbox<T>)) -> bool
+where
+    T: Float,
+{
+    let (a, b) = (shell.1, hole.1);
+    if a.xmin > b.xmin || a.ymin > b.ymin || a.xmax < b.xmax || a.ymax < b.ymax {
+        return false;
+    }
+    hole.0
+         .0
+        .iter()
+        .map(|c| get_position(Point(*c), &shell.0))
+        .find(|position| *position != PositionPoint::OnBoundary)
+        == Some(PositionPoint::Inside)
+}
+
+fn polygonize_lines<I, T>(lines: I) -> Polygonized<T>
+where
+    I: IntoIterator<Item = Vec<Coordinate<T>>>,
+    T: Float,
+{
+    let mut graph = Graph::new(lines);
+    let dangles = graph.remove_dangles();
+    let cut_edges = graph.remove_cut_edges();
+    let (cycles, _) = graph.cycles();
+
+    // faces are traversed with their insides on the left, so the boundaries of faces come out
+    // counter-clockwise, and the outer boundaries of connected sets of lines clockwise
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for cycle in &cycles {
+        let ring = graph.ring(cycle);
+        let area = twice_signed_ring_area(&ring);
+        let bbox = ring.bbox().unwrap();
+        if area > T::zero() {
+            shells.push((area, (ring, bbox)));
+        } else {
+            holes.push((ring, bbox));
+        }
+    }
+    // each hole belongs to the smallest shell around it
+    let mut by_area: Vec<usize> = (0..shells.len()).collect();
+    by_area.sort_by(|&a, &b| shells[a].0.partial_cmp(&shells[b].0).unwrap());
+    let mut interiors = vec![vec![]; shells.len()];
+    for hole in holes {
+        if let Some(&shell) = by_area.iter().find(|&&s| encloses(&(shells[s].1), &hole)) {
+            interiors[shell].push(hole.0);
+        }
+    }
+    let lines = |edges: Vec<usize>| {
+        MultiLineString(
+            edges
+                .into_iter()
+                .map(|e| LineString(graph.edges[e].clone()))
+                .collect(),
+        )
+    };
+    Polygonized {
+        polygons: MultiPolygon(
+            shells
+                .into_iter()
+                .zip(interiors)
+                .map(|((_, (exterior, _)), interiors)| Polygon::new(exterior, interiors))
+                .collect(),
+        ),
+        dangles: lines(dangles),
+        cut_edges: lines(cut_edges),
+    }
+}
+
+impl<T> Polygonize<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn polygonize(&self) -> Polygonized<T> {
+        polygonize_lines(self.0.iter().map(|l| l.0.clone()))
+    }
+}
+
+impl<T> Polygonize<T> for [Line<T>]
+where
+    T: Float,
+{
+    fn polygonize(&self) -> Polygonized<T> {
+        polygonize_lines(self.iter().map(|l| vec![l.start, l.end]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+
+    #[test]
+    fn hole_test() {
+        // a square, with a triangle inside it, and a diamond inside that
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            LineString::from(vec![(2., 2.), (8., 2.), (5., 8.), (2., 2.)]),
+            LineString::from(vec![(5., 3.), (6., 4.), (5., 5.), (4., 4.), (5., 3.)]),
+        ]);
+        let polygonized = lines.polygonize();
+        assert!(polygonized.dangles.0.is_empty());
+        assert!(polygonized.cut_edges.0.is_empty());
+        let polygons = polygonized.polygons.0;
+        assert_eq!(polygons.len(), 3);
+        let areas: Vec<f64> = polygons
+            .iter()
+            .map(|p| Polygon::new(p.exterior.clone(), vec![]).area())
+            .collect();
+        assert_eq!(areas, vec![100., 18., 2.]);
+        assert_eq!(polygons[0].interiors.len(), 1);
+        assert_eq!(polygons[1].interiors.len(), 1);
+        assert!(polygons[2].interiors.is_empty());
+    }
+
+    #[test]
+    fn cut_edges_and_dangles_test() {
+        // two squares joined by a bridge, with a dangling tail
+        let mut lines = vec![
+            Line::from([(0., 0.), (2., 0.)]),
+            Line::from([(2., 0.), (2., 2.)]),
+            Line::from([(2., 2.), (0., 2.)]),
+            Line::from([(0., 2.), (0., 0.)]),
+            Line::from([(5., 0.), (7., 0.)]),
+            Line::from([(7., 0.), (7., 2.)]),
+            Line::from([(7., 2.), (5., 2.)]),
+            Line::from([(5., 2.), (5., 0.)]),
+        ];
+        let bridge = Line::from([(2., 1.), (5., 1.)]);
+        let tail = Line::from([(7., 2.), (8., 3.)]);
+        // the bridge meets the squares' sides, which have to be noded there
+        lines[1] = Line::from([(2., 0.), (2., 1.)]);
+        lines.push(Line::from([(2., 1.), (2., 2.)]));
+        lines[7] = Line::from([(5., 2.), (5., 1.)]);
+        lines.push(Line::from([(5., 1.), (5., 0.)]));
+        lines.push(bridge);
+        lines.push(tail);
+        // a repeated line changes nothing
+        lines.push(Line::from([(0., 2.), (2., 2.)]));
+        let polygonized = lines.polygonize();
+        assert_eq!(polygonized.polygons.0.len(), 2);
+        assert!(polygonized.polygons.0.iter().all(|p| p.area() == 4.));
+        assert_eq!(
+            polygonized.cut_edges,
+            MultiLineString(vec![LineString::from(vec![(2., 1.), (5., 1.)])])
+        );
+        assert_eq!(
+            polygonized.dangles,
+            MultiLineString(vec![LineString::from(vec![(7., 2.), (8., 3.)])])
+        );
+    }
+
+    #[test]
+    fn open_test() {
+        // lines which don't close make no polygons
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.)]),
+            LineString::from(vec![(1., 1.), (0., 1.)]),
+            LineString::from(vec![(3., 3.)]),
+            LineString(vec![]),
+        ]);
+        let polygonized = lines.polygonize();
+        assert!(polygonized.polygons.0.is_empty());
+        assert_eq!(polygonized.dangles.0.len(), 2);
+    }
+}
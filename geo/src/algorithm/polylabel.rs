@@ -0,0 +1,279 @@
+use algorithm::boundingbox::BoundingBox;
+use algorithm::centroid::Centroid;
+use algorithm::contains::Contains;
+use algorithm::euclidean_distance::EuclideanDistance;
+use num_traits::{Float, FromPrimitive};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::Sum;
+use {MultiPolygon, Point, Polygon};
+
+/// Returns the pole of inaccessibility of a polygon: a point inside it, as far as possible
+/// from its boundary.
+pub trait Polylabel<T>
+where
+    T: Float,
+{
+    /// Returns a point inside the polygon, within `tolerance` of the greatest possible
+    /// distance from its boundary.
+    ///
+    /// This is the quadtree search used by [Mapbox's polylabel](https://github.com/mapbox/polylabel),
+    /// which makes a good position for a label, unlike the centroid of a concave polygon.
+    /// For a `MultiPolygon`, it's the best such point of any member. The tolerance is raised
+    /// to a tiny fraction of the polygon's size if it's smaller. Returns `None` if the
+    /// polygon is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point, Polygon};
+    /// use geo::algorithm::centroid::Centroid;
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::polylabel::Polylabel;
+    ///
+    /// // a U shape
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![
+    ///         (0., 0.), (6., 0.), (6., 6.), (4., 6.), (4., 2.), (2., 2.), (2., 6.), (0., 6.), (0., 0.),
+    ///     ]),
+    ///     vec![],
+    /// );
+    /// assert!(!polygon.contains(&polygon.centroid().unwrap()));
+    ///
+    /// let label = polygon.polylabel(0.01).unwrap();
+    /// assert!(polygon.contains(&label));
+    /// ```
+    fn polylabel(&self, tolerance: T) -> Option<Point<T>>;
+}
+
+impl<T> Polylabel<T> for Polygon<T>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    fn polylabel(&self, tolerance: T) -> Option<Point<T>> {
+        pole(self, tolerance).map(|cell| cell.center)
+    }
+}
+
+impl<T> Polylabel<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    fn polylabel(&self, tolerance: T) -> Option<Point<T>> {
+        self.0
+            .iter()
+            .filter_map(|polygon| pole(polygon, tolerance))
+            .max()
+            .map(|cell| cell.center)
+    }
+}
+
+/// The distance from a point to the boundary of a polygon, negative outside it
+fn signed_distance<T>(polygon: &Polygon<T>, point: Point<T>) -> T
+where
+    T: Float,
+{
+    let distance = ::std::iter::once(&polygon.exterior)
+        .chain(polygon.interiors.iter())
+        .map(|ring| point.euclidean_distance(ring))
+        .fold(T::infinity(), |a, b| a.min(b));
+    if polygon.contains(&point) {
+        distance
+    } else {
+        -distance
+    }
+}
+
+/// A square cell of the quadtree, ordered by the greatest distance a point in it could have
+/// from the polygon's boundary
+struct Cell<T>
+where
+    T: Float,
+{
+    center: Point<T>,
+    half_size: T,
+    distance: T,
+    potential: T,
+}
+
+impl<T> Cell<T>
+where
+    T: Float,
+{
+    fn new(polygon: &Polygon<T>, center: Point<T>, half_size: T) -> Cell<T> {
+        let distance = signed_distance(polygon, center);
+        Cell {
+            center,
+            half_size,
+            distance,
+            potential: distance + half_size * T::from(2).unwrap().sqrt(),
+        }
+    }
+}
+
+impl<T> PartialEq for Cell<T>
+where
+    T: Float,
+{
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Cell<T> where T: Float {}
+
+impl<T> PartialOrd for Cell<T>
+where
+    T: Float,
+{
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Cell<T>
+where
+    T: Float,
+{
+    fn cmp(&self, other: &Cell<T>) -> Ordering {
+        self.potential
+            .partial_cmp(&other.potential)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The best cell found for a polygon, which is ordered by potential too, but has no more
+/// potential than distance
+fn pole<T>(polygon: &Polygon<T>, tolerance: T) -> Option<Cell<T>>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    let bbox = polygon.bbox()?;
+    let two = T::one() + T::one();
+    let (width, height) = (bbox.xmax - bbox.xmin, bbox.ymax - bbox.ymin);
+    let size = width.min(height);
+    if size == T::zero() {
+        let corner = Point::new(bbox.xmin, bbox.ymin);
+        return Some(Cell::new(polygon, corner, T::zero()));
+    }
+    // stop splitting cells before they vanish into rounding error
+    let tolerance = tolerance.max(width.max(height) * T::epsilon() * T::from(16).unwrap());
+
+    // cover the bounding box with square cells
+    let half_size = size / two;
+    let mut cells = BinaryHeap::new();
+    let mut x = bbox.xmin;
+    while x < bbox.xmax {
+        let mut y = bbox.ymin;
+        while y < bbox.ymax {
+            cells.push(Cell::new(
+                polygon,
+                Point::new(x + half_size, y + half_size),
+                half_size,
+            ));
+            y = y + size;
+        }
+        x = x + size;
+    }
+
+    let center = Point::new(bbox.xmin + width / two, bbox.ymin + height / two);
+    let mut best = Cell::new(polygon, center, T::zero());
+    if let Some(centroid) = polygon.centroid() {
+        let centroid = Cell::new(polygon, centroid, T::zero());
+        if centroid.distance > best.distance {
+            best = centroid;
+        }
+    }
+
+    while let Some(cell) = cells.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(polygon, cell.center, T::zero());
+        }
+        // the remaining cells can't hold a point far enough beyond the best
+        if cell.potential - best.distance <= tolerance {
+            break;
+        }
+        let half_size = cell.half_size / two;
+        let (x, y) = (cell.center.x(), cell.center.y());
+        for &(x, y) in &[
+            (x - half_size, y - half_size),
+            (x + half_size, y - half_size),
+            (x - half_size, y + half_size),
+            (x + half_size, y + half_size),
+        ] {
+            cells.push(Cell::new(polygon, Point::new(x, y), half_size));
+        }
+    }
+    Some(best)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use LineString;
+
+    fn polygon(coords: Vec<(f64, f64)>) -> Polygon<f64> {
+        Polygon::new(LineString::from(coords), vec![])
+    }
+
+    #[test]
+    fn square_test() {
+        let square = polygon(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        assert_eq!(square.polylabel(0.1), Some(Point::new(2., 2.)));
+    }
+
+    #[test]
+    fn u_shape_test() {
+        // the best points are in the corners of the base, pushed out by the inner corners
+        let u = polygon(vec![
+            (0., 0.),
+            (6., 0.),
+            (6., 6.),
+            (4., 6.),
+            (4., 2.),
+            (2., 2.),
+            (2., 6.),
+            (0., 6.),
+            (0., 0.),
+        ]);
+        let label = u.polylabel(0.001).unwrap();
+        assert!(u.contains(&label));
+        assert_relative_eq!(
+            signed_distance(&u, label),
+            4. - 8f64.sqrt(),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn hole_test() {
+        // a square with a square hole, like the U in each corner
+        let exterior = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]);
+        let hole = LineString::from(vec![(2., 2.), (8., 2.), (8., 8.), (2., 8.), (2., 2.)]);
+        let square = Polygon::new(exterior, vec![hole]);
+        let label = square.polylabel(0.001).unwrap();
+        assert!(square.contains(&label));
+        assert_relative_eq!(
+            signed_distance(&square, label),
+            4. - 8f64.sqrt(),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn multipolygon_test() {
+        let small = polygon(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+        let large = polygon(vec![(5., 5.), (9., 5.), (9., 9.), (5., 9.), (5., 5.)]);
+        let multi = MultiPolygon(vec![small, large]);
+        assert_eq!(multi.polylabel(0.1), Some(Point::new(7., 7.)));
+        assert_eq!(MultiPolygon::<f64>(vec![]).polylabel(0.1), None);
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.polylabel(0.1), None);
+        let flat = polygon(vec![(0., 0.), (4., 0.), (0., 0.)]);
+        assert_eq!(flat.polylabel(0.1), Some(Point::new(0., 0.)));
+    }
+}
@@ -0,0 +1,205 @@
+use std::{error, fmt};
+
+use num_traits::Float;
+use {Coordinate, LineString};
+
+/// The reasons a polyline can fail to be encoded or decoded
+#[derive(PartialEq, Clone, Debug)]
+pub enum PolylineError {
+    /// The longitude wasn't between -180 and 180, or the latitude between -90 and 90
+    InvalidCoordinate,
+    /// The polyline contained a character outside the range `?` to `~`
+    InvalidCharacter(char),
+    /// The polyline ended part way through a value, or had a latitude without a longitude
+    UnexpectedEnd,
+    /// A value was too large to encode in, or decode from, the polyline
+    Overflow,
+}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PolylineError::InvalidCoordinate => {
+                write!(f, "coordinate is not a valid longitude and latitude")
+            }
+            PolylineError::InvalidCharacter(c) => write!(f, "invalid polyline character {:?}", c),
+            PolylineError::UnexpectedEnd => write!(f, "polyline ended unexpectedly"),
+            PolylineError::Overflow => write!(f, "polyline value is too large"),
+        }
+    }
+}
+
+impl error::Error for PolylineError {
+    fn description(&self) -> &str {
+        "invalid polyline"
+    }
+}
+
+/// Encodes a `LineString`, whose coordinates are longitudes and latitudes in degrees, using
+/// Google's encoded polyline algorithm.
+///
+/// Coordinates are rounded to `precision` decimal places: 5 in Google's own APIs, and 6 in
+/// some others, such as OSRM's `polyline6`. The polyline holds each point's latitude before
+/// its longitude.
+///
+/// # Examples
+///
+/// ```
+/// use geo::LineString;
+/// use geo::algorithm::polyline::{decode, encode};
+///
+/// let line = LineString::from(vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)]);
+/// let polyline = encode(&line, 5).unwrap();
+/// assert_eq!(polyline, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+/// assert_eq!(decode::<f64>(&polyline, 5).unwrap(), line);
+/// ```
+pub fn encode<T>(line: &LineString<T>, precision: u32) -> Result<String, PolylineError>
+where
+    T: Float,
+{
+    let factor = 10f64.powi(precision as i32);
+    let mut polyline = String::new();
+    let mut previous = (0, 0);
+    for coord in &line.0 {
+        let (lon, lat) = match (coord.x.to_f64(), coord.y.to_f64()) {
+            (Some(lon), Some(lat))
+                if (-180. ..=180.).contains(&lon) && (-90. ..=90.).contains(&lat) =>
+            {
+                (lon, lat)
+            }
+            _ => return Err(PolylineError::InvalidCoordinate),
+        };
+        let scaled = |value: f64| (value * factor).round() as i64;
+        let (lat, lon) = (scaled(lat), scaled(lon));
+        // only a precision far beyond that of any coordinate can make these overflow
+        let delta =
+            |value: i64, previous: i64| value.checked_sub(previous).ok_or(PolylineError::Overflow);
+        push_value(&mut polyline, delta(lat, previous.0)?);
+        push_value(&mut polyline, delta(lon, previous.1)?);
+        previous = (lat, lon);
+    }
+    Ok(polyline)
+}
+
+/// Appends a value, as the difference from the one before it, in chunks of five bits
+fn push_value(polyline: &mut String, delta: i64) {
+    // the sign goes in the lowest bit, so that small negative values stay short
+    let mut value = if delta < 0 { !(delta << 1) } else { delta << 1 } as u64;
+    while value >= 0x20 {
+        polyline.push((((value & 0x1f) | 0x20) as u8 + 63) as char);
+        value >>= 5;
+    }
+    polyline.push((value as u8 + 63) as char);
+}
+
+/// Decodes a polyline encoded with Google's algorithm, with `precision` decimal places, to a
+/// `LineString` of longitudes and latitudes in degrees.
+pub fn decode<T>(polyline: &str, precision: u32) -> Result<LineString<T>, PolylineError>
+where
+    T: Float,
+{
+    let factor = 10f64.powi(precision as i32);
+    let mut chars = polyline.chars().peekable();
+    let mut coords = vec![];
+    let (mut lat, mut lon) = (0i64, 0i64);
+    while chars.peek().is_some() {
+        lat = lat
+            .checked_add(next_value(&mut chars)?)
+            .ok_or(PolylineError::Overflow)?;
+        lon = lon
+            .checked_add(next_value(&mut chars)?)
+            .ok_or(PolylineError::Overflow)?;
+        coords.push(Coordinate {
+            x: T::from(lon as f64 / factor).ok_or(PolylineError::Overflow)?,
+            y: T::from(lat as f64 / factor).ok_or(PolylineError::Overflow)?,
+        });
+    }
+    Ok(LineString(coords))
+}
+
+/// Reads the next difference from a polyline
+fn next_value<I>(chars: &mut I) -> Result<i64, PolylineError>
+where
+    I: Iterator<Item = char>,
+{
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let c = chars.next().ok_or(PolylineError::UnexpectedEnd)?;
+        if !('?'..='~').contains(&c) {
+            return Err(PolylineError::InvalidCharacter(c));
+        }
+        let chunk = c as u64 - 63;
+        let bits = chunk & 0x1f;
+        // the value must fit in 64 bits, without losing any from the top
+        if shift >= 64 || (bits << shift) >> shift != bits {
+            return Err(PolylineError::Overflow);
+        }
+        value |= bits << shift;
+        shift += 5;
+        if chunk & 0x20 == 0 {
+            break;
+        }
+    }
+    let delta = (value >> 1) as i64;
+    Ok(if value & 1 == 0 { delta } else { !delta })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn precision_test() {
+        let line = LineString::from(vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)]);
+        let polyline = encode(&line, 6).unwrap();
+        assert_eq!(polyline, "_izlhA~rlgdF_{geC~ywl@_kwzCn`{nI");
+        assert_eq!(decode::<f64>(&polyline, 6).unwrap(), line);
+        // the same polyline read at the wrong precision is ten times smaller
+        let small: LineString<f64> = decode(&polyline, 7).unwrap();
+        assert_relative_eq!(small.0[0].x, -12.02, epsilon = 1e-9);
+        assert_relative_eq!(small.0[0].y, 3.85, epsilon = 1e-9);
+
+        // coordinates are rounded, and repeated points are kept
+        let line = LineString::from(vec![(0.000_004, -0.000_006), (0.000_004, -0.000_006)]);
+        assert_eq!(encode(&line, 5).unwrap(), "@???");
+        assert_eq!(
+            decode::<f64>("@?", 5).unwrap(),
+            LineString::from(vec![(0., -0.00001)])
+        );
+        assert_eq!(encode(&LineString::<f64>(vec![]), 5).unwrap(), "");
+        assert_eq!(decode::<f64>("", 5).unwrap(), LineString(vec![]));
+    }
+
+    #[test]
+    fn error_test() {
+        let line = LineString::from(vec![(0., 0.), (0., 91.)]);
+        assert_eq!(encode(&line, 5), Err(PolylineError::InvalidCoordinate));
+        let line = LineString::from(vec![(Float::nan(), 0.)]);
+        assert_eq!(encode(&line, 5), Err(PolylineError::InvalidCoordinate));
+        // the latitudes scale to the largest and smallest values, whose difference overflows
+        let line = LineString::from(vec![(0., 90.), (0., -90.)]);
+        assert_eq!(encode(&line, 20), Err(PolylineError::Overflow));
+
+        assert_eq!(decode::<f64>("_p~iF", 5), Err(PolylineError::UnexpectedEnd));
+        assert_eq!(decode::<f64>("_p~", 5), Err(PolylineError::UnexpectedEnd));
+        assert_eq!(
+            decode::<f64>("_p~iF ps|U", 5),
+            Err(PolylineError::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            decode::<f64>("~~~~~~~~~~~~~~?", 5),
+            Err(PolylineError::Overflow)
+        );
+        assert_eq!(
+            decode::<f64>(&"~~~~~~~~~~~~^".repeat(4), 5),
+            Err(PolylineError::Overflow)
+        );
+        // each difference fits, but their sum doesn't
+        let mut polyline = String::new();
+        for _ in 0..4 {
+            push_value(&mut polyline, (!0u64 >> 1) as i64);
+        }
+        assert_eq!(decode::<f64>(&polyline, 5), Err(PolylineError::Overflow));
+    }
+}
@@ -0,0 +1,665 @@
+use std::cmp::Ordering;
+
+use {Coordinate, CoordinateType};
+
+// Adaptive-precision arithmetic after Jonathan Richard Shewchuk,
+// "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates" (1997)
+// https://www.cs.cmu.edu/~quake/robust.html
+//
+// Each predicate first evaluates its determinant in ordinary floating-point arithmetic and
+// compares the result against a forward error bound. Only if the sign cannot be trusted is the
+// determinant recomputed exactly, using floating-point expansions (sums of non-overlapping
+// doubles ordered by increasing magnitude).
+//
+// Coordinates are first converted to `f64`, so the predicates are exact for the converted
+// values. Coordinate types which can't all be represented as `f64`, such as integers beyond
+// 2^53 in magnitude, are rounded by the conversion, and the result may then be wrong for the
+// original coordinates.
+
+// 2^-53, half an ulp of 1.0
+const EPSILON: f64 = 1.1102230246251565e-16;
+// 2^27 + 1, used to split a double into two non-overlapping halves
+const SPLITTER: f64 = 134_217_729.0;
+const CCW_ERRBOUND_A: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND_A: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// The orientation of an ordered triple of points
+#[derive(PartialEq, Clone, Copy, Debug, Eq)]
+pub enum Orientation {
+    /// The third point lies to the left of the directed line through the first two
+    CounterClockwise,
+    /// The third point lies to the right of the directed line through the first two
+    Clockwise,
+    /// The three points lie on a single line
+    Collinear,
+}
+
+/// The position of a point relative to the circle passing through three other points
+#[derive(PartialEq, Clone, Copy, Debug, Eq)]
+pub enum CirclePosition {
+    Inside,
+    OnCircle,
+    Outside,
+}
+
+/// Returns the orientation of the triangle `a` → `b` → `c`.
+///
+/// The result is exact: it is the sign of the determinant
+/// `(a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x)`, evaluated with enough precision
+/// that nearly collinear inputs are classified consistently. The coordinates are converted
+/// to `f64` first, which rounds integers beyond 2<sup>53</sup> in magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Coordinate;
+/// use geo::algorithm::predicates::{orient2d, Orientation};
+///
+/// let a = Coordinate { x: 0., y: 0. };
+/// let b = Coordinate { x: 1., y: 0. };
+///
+/// assert_eq!(orient2d(a, b, Coordinate { x: 0.5, y: 1. }), Orientation::CounterClockwise);
+/// assert_eq!(orient2d(a, b, Coordinate { x: 0.5, y: -1. }), Orientation::Clockwise);
+/// assert_eq!(orient2d(a, b, Coordinate { x: 2., y: 0. }), Orientation::Collinear);
+/// ```
+pub fn orient2d<T>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Orientation
+where
+    T: CoordinateType,
+{
+    let (a, b, c) = (to_f64(a), to_f64(b), to_f64(c));
+    let det = orient2d_f64(a, b, c);
+    if det > 0. {
+        Orientation::CounterClockwise
+    } else if det < 0. {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// Returns the position of `d` relative to the circle passing through `a`, `b` and `c`.
+///
+/// The winding order of `a`, `b` and `c` doesn't matter. If they are collinear the circle
+/// degenerates into a line, and `d` is reported to be either `OnCircle` or `Outside`. As with
+/// `orient2d`, the result is exact for the coordinates once converted to `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Coordinate;
+/// use geo::algorithm::predicates::{incircle, CirclePosition};
+///
+/// let a = Coordinate { x: 0., y: 0. };
+/// let b = Coordinate { x: 2., y: 0. };
+/// let c = Coordinate { x: 2., y: 2. };
+///
+/// assert_eq!(incircle(a, b, c, Coordinate { x: 1., y: 1. }), CirclePosition::Inside);
+/// assert_eq!(incircle(a, b, c, Coordinate { x: 0., y: 2. }), CirclePosition::OnCircle);
+/// assert_eq!(incircle(a, b, c, Coordinate { x: 3., y: 3. }), CirclePosition::Outside);
+/// ```
+pub fn incircle<T>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+    d: Coordinate<T>,
+) -> CirclePosition
+where
+    T: CoordinateType,
+{
+    let (a, b, c, d) = (to_f64(a), to_f64(b), to_f64(c), to_f64(d));
+    let det = incircle_f64(a, b, c, d);
+    let det = match orient2d_f64(a, b, c) {
+        o if o > 0. => det,
+        o if o < 0. => -det,
+        _ => {
+            return if det == 0. {
+                CirclePosition::OnCircle
+            } else {
+                CirclePosition::Outside
+            }
+        }
+    };
+    if det > 0. {
+        CirclePosition::Inside
+    } else if det < 0. {
+        CirclePosition::Outside
+    } else {
+        CirclePosition::OnCircle
+    }
+}
+
+/// A point with exact rational coordinates `(x / w, y / w)`, where `x`, `y` and `w` are
+/// expansions and `w` is positive.
+///
+/// This represents intersection points of segments exactly, so that they can be compared with
+/// one another and tested against lines without any rounding.
+#[derive(Clone, Debug)]
+pub(crate) struct ExactPoint {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    w: Vec<f64>,
+}
+
+impl ExactPoint {
+    pub(crate) fn new<T>(c: Coordinate<T>) -> ExactPoint
+    where
+        T: CoordinateType,
+    {
+        let (x, y) = to_f64(c);
+        ExactPoint {
+            x: [x].iter().cloned().filter(|v| *v != 0.).collect(),
+            y: [y].iter().cloned().filter(|v| *v != 0.).collect(),
+            w: vec![1.],
+        }
+    }
+
+    /// The intersection of the lines through `a`, `b` and through `c`, `d`, or `None` if they
+    /// are parallel
+    pub(crate) fn intersection<T>(
+        a: Coordinate<T>,
+        b: Coordinate<T>,
+        c: Coordinate<T>,
+        d: Coordinate<T>,
+    ) -> Option<ExactPoint>
+    where
+        T: CoordinateType,
+    {
+        let (a, b, c, d) = (to_f64(a), to_f64(b), to_f64(c), to_f64(d));
+        let (abx, aby) = (two_diff(b.0, a.0), two_diff(b.1, a.1));
+        let (cdx, cdy) = (two_diff(d.0, c.0), two_diff(d.1, c.1));
+        let (acx, acy) = (two_diff(c.0, a.0), two_diff(c.1, a.1));
+        // a + (b - a) * t / w, where w = (b - a) × (d - c) and t = (c - a) × (d - c)
+        let w = expansion_diff(
+            &expansion_product(&abx, &cdy),
+            &expansion_product(&aby, &cdx),
+        );
+        let t = expansion_diff(
+            &expansion_product(&acx, &cdy),
+            &expansion_product(&acy, &cdx),
+        );
+        let x = expansion_sum(&scale_expansion(&w, a.0), &expansion_product(&abx, &t));
+        let y = expansion_sum(&scale_expansion(&w, a.1), &expansion_product(&aby, &t));
+        let sign = expansion_sign(&w);
+        if sign == 0. {
+            return None;
+        }
+        let negate = |e: Vec<f64>| -> Vec<f64> {
+            if sign < 0. {
+                e.iter().map(|v| -v).collect()
+            } else {
+                e
+            }
+        };
+        Some(ExactPoint {
+            x: compress(&negate(x)),
+            y: compress(&negate(y)),
+            w: compress(&negate(w)),
+        })
+    }
+
+    fn approx(&self) -> (f64, f64) {
+        let w = estimate(&self.w);
+        (estimate(&self.x) / w, estimate(&self.y) / w)
+    }
+
+    fn is_integral(&self) -> bool {
+        self.w.len() == 1 && self.w[0] == 1.
+    }
+
+    /// Returns the orientation of the triangle `a` → `b` → `self`
+    pub(crate) fn orientation<T>(&self, a: Coordinate<T>, b: Coordinate<T>) -> Orientation
+    where
+        T: CoordinateType,
+    {
+        let (a, b) = (to_f64(a), to_f64(b));
+        let det = if self.is_integral() {
+            orient2d_f64(a, b, (estimate(&self.x), estimate(&self.y)))
+        } else {
+            // Try an approximation of the point first. The error bound allows for the relative
+            // error of the approximate coordinates, and for evaluating the determinant.
+            let (x, y) = self.approx();
+            let (abx, aby) = (b.0 - a.0, b.1 - a.1);
+            let det = abx * (y - a.1) - aby * (x - a.0);
+            let relative =
+                (self.x.len().max(self.y.len()) + self.w.len() + 2) as f64 * 2. * EPSILON;
+            let bound = relative * (abx.abs() * y.abs() + aby.abs() * x.abs())
+                + 8. * EPSILON * (abx.abs() * (y - a.1).abs() + aby.abs() * (x - a.0).abs());
+            if det.abs() > bound {
+                det
+            } else {
+                // (b - a) × (self - a), scaled by w
+                let (abx, aby) = (two_diff(b.0, a.0), two_diff(b.1, a.1));
+                let px = expansion_diff(&self.x, &scale_expansion(&self.w, a.0));
+                let py = expansion_diff(&self.y, &scale_expansion(&self.w, a.1));
+                expansion_sign(&expansion_diff(
+                    &expansion_product(&abx, &py),
+                    &expansion_product(&aby, &px),
+                ))
+            }
+        };
+        if det > 0. {
+            Orientation::CounterClockwise
+        } else if det < 0. {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+}
+
+/// Compares `n1 / w1` with `n2 / w2`, where `w1` and `w2` are positive
+fn cmp_ratios(n1: &[f64], w1: &[f64], n2: &[f64], w2: &[f64]) -> Ordering {
+    if n1 == n2 && w1 == w2 {
+        return Ordering::Equal;
+    }
+    let (r1, r2) = (estimate(n1) / estimate(w1), estimate(n2) / estimate(w2));
+    // Relative errors of the estimates, which are generous bounds for summing each expansion
+    // and then dividing
+    let e1 = (n1.len() + w1.len() + 2) as f64 * 2. * EPSILON;
+    let e2 = (n2.len() + w2.len() + 2) as f64 * 2. * EPSILON;
+    if (r1 - r2).abs() > e1 * r1.abs() + e2 * r2.abs() {
+        return r1.partial_cmp(&r2).unwrap();
+    }
+    let det = expansion_sign(&expansion_diff(
+        &expansion_product(n1, w2),
+        &expansion_product(n2, w1),
+    ));
+    match det.partial_cmp(&0.) {
+        Some(ordering) => ordering,
+        // the expansions overflowed, which coordinates near the largest finite values can
+        // cause, so settle for a consistent order of the estimates
+        None => total_cmp(r1, r2),
+    }
+}
+
+/// Orders floats by sign and then magnitude, like IEEE 754's `totalOrder`, with NaNs at the
+/// ends
+fn total_cmp(a: f64, b: f64) -> Ordering {
+    let key = |v: f64| {
+        let bits = v.to_bits() as i64;
+        bits ^ (((bits >> 63) as u64) >> 1) as i64
+    };
+    key(a).cmp(&key(b))
+}
+
+impl Ord for ExactPoint {
+    /// Orders points lexicographically, by x and then by y
+    fn cmp(&self, other: &ExactPoint) -> Ordering {
+        cmp_ratios(&self.x, &self.w, &other.x, &other.w)
+            .then_with(|| cmp_ratios(&self.y, &self.w, &other.y, &other.w))
+    }
+}
+
+impl PartialOrd for ExactPoint {
+    fn partial_cmp(&self, other: &ExactPoint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ExactPoint {
+    fn eq(&self, other: &ExactPoint) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ExactPoint {}
+
+fn to_f64<T: CoordinateType>(c: Coordinate<T>) -> (f64, f64) {
+    (c.x.to_f64().unwrap(), c.y.to_f64().unwrap())
+}
+
+// Returns a value whose sign is that of the orientation determinant
+fn orient2d_f64(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    let detleft = (a.0 - c.0) * (b.1 - c.1);
+    let detright = (a.1 - c.1) * (b.0 - c.0);
+    let det = detleft - detright;
+
+    let detsum = if detleft > 0. {
+        if detright <= 0. {
+            return det;
+        }
+        detleft + detright
+    } else if detleft < 0. {
+        if detright >= 0. {
+            return det;
+        }
+        -detleft - detright
+    } else {
+        return det;
+    };
+
+    let errbound = CCW_ERRBOUND_A * detsum;
+    if det >= errbound || -det >= errbound {
+        return det;
+    }
+    orient2d_exact(a, b, c)
+}
+
+fn orient2d_exact(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    let acx = two_diff(a.0, c.0);
+    let acy = two_diff(a.1, c.1);
+    let bcx = two_diff(b.0, c.0);
+    let bcy = two_diff(b.1, c.1);
+    let left = expansion_product(&acx, &bcy);
+    let right = expansion_product(&acy, &bcx);
+    expansion_sign(&expansion_diff(&left, &right))
+}
+
+// Returns a value whose sign is positive if `d` lies inside the circle through the
+// counter-clockwise triangle `a`, `b`, `c`
+fn incircle_f64(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> f64 {
+    let adx = a.0 - d.0;
+    let bdx = b.0 - d.0;
+    let cdx = c.0 - d.0;
+    let ady = a.1 - d.1;
+    let bdy = b.1 - d.1;
+    let cdy = c.1 - d.1;
+
+    let bdxcdy = bdx * cdy;
+    let cdxbdy = cdx * bdy;
+    let alift = adx * adx + ady * ady;
+
+    let cdxady = cdx * ady;
+    let adxcdy = adx * cdy;
+    let blift = bdx * bdx + bdy * bdy;
+
+    let adxbdy = adx * bdy;
+    let bdxady = bdx * ady;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    let errbound = ICC_ERRBOUND_A * permanent;
+    if det > errbound || -det > errbound {
+        return det;
+    }
+    incircle_exact(a, b, c, d)
+}
+
+fn incircle_exact(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> f64 {
+    let adx = two_diff(a.0, d.0);
+    let bdx = two_diff(b.0, d.0);
+    let cdx = two_diff(c.0, d.0);
+    let ady = two_diff(a.1, d.1);
+    let bdy = two_diff(b.1, d.1);
+    let cdy = two_diff(c.1, d.1);
+
+    let lift = |dx: &[f64], dy: &[f64]| {
+        expansion_sum(&expansion_product(dx, dx), &expansion_product(dy, dy))
+    };
+    let cross = |px: &[f64], py: &[f64], qx: &[f64], qy: &[f64]| {
+        expansion_diff(&expansion_product(px, qy), &expansion_product(qx, py))
+    };
+
+    let adet = expansion_product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+    let bdet = expansion_product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+    let cdet = expansion_product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+    expansion_sign(&expansion_sum(&expansion_sum(&adet, &bdet), &cdet))
+}
+
+// a + b = x + y exactly, where |a| >= |b|
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bvirt = x - a;
+    (x, b - bvirt)
+}
+
+// a + b = x + y exactly
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bvirt = x - a;
+    let avirt = x - bvirt;
+    let bround = b - bvirt;
+    let around = a - avirt;
+    (x, around + bround)
+}
+
+// a - b as a zero-eliminated expansion
+fn two_diff(a: f64, b: f64) -> Vec<f64> {
+    let x = a - b;
+    let bvirt = a - x;
+    let avirt = x + bvirt;
+    let bround = bvirt - b;
+    let around = a - avirt;
+    let y = around + bround;
+    [y, x].iter().cloned().filter(|v| *v != 0.).collect()
+}
+
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let abig = c - a;
+    let ahi = c - abig;
+    (ahi, a - ahi)
+}
+
+// a * b = x + y exactly
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let err1 = x - ahi * bhi;
+    let err2 = err1 - alo * bhi;
+    let err3 = err2 - ahi * blo;
+    (x, alo * blo - err3)
+}
+
+// Add a single double to an expansion
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &enow in e {
+        let (sum, hh) = two_sum(q, enow);
+        q = sum;
+        if hh != 0. {
+            h.push(hh);
+        }
+    }
+    if q != 0. {
+        h.push(q);
+    }
+    h
+}
+
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |h, &b| grow_expansion(&h, b))
+}
+
+fn expansion_diff(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let negated: Vec<f64> = f.iter().map(|v| -v).collect();
+    expansion_sum(e, &negated)
+}
+
+// Multiply an expansion by a single double
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(2 * e.len());
+    let mut iter = e.iter();
+    let mut q = match iter.next() {
+        Some(&first) => {
+            let (q, hh) = two_product(first, b);
+            if hh != 0. {
+                h.push(hh);
+            }
+            q
+        }
+        None => return h,
+    };
+    for &enow in iter {
+        let (product1, product0) = two_product(enow, b);
+        let (sum, hh) = two_sum(q, product0);
+        if hh != 0. {
+            h.push(hh);
+        }
+        let (sum, hh) = fast_two_sum(product1, sum);
+        q = sum;
+        if hh != 0. {
+            h.push(hh);
+        }
+    }
+    if q != 0. {
+        h.push(q);
+    }
+    h
+}
+
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter()
+        .fold(vec![], |h, &b| expansion_sum(&h, &scale_expansion(e, b)))
+}
+
+// The largest component of a non-overlapping expansion determines its sign
+fn expansion_sign(e: &[f64]) -> f64 {
+    e.last().cloned().unwrap_or(0.)
+}
+
+// Shortens an expansion to an equal one with as few components as possible
+fn compress(e: &[f64]) -> Vec<f64> {
+    if e.is_empty() {
+        return vec![];
+    }
+    let mut g = e.to_vec();
+    let mut bottom = e.len() - 1;
+    let mut q = e[bottom];
+    for &enow in e[..e.len() - 1].iter().rev() {
+        let (sum, err) = fast_two_sum(q, enow);
+        if err != 0. {
+            g[bottom] = sum;
+            bottom -= 1;
+            q = err;
+        } else {
+            q = sum;
+        }
+    }
+    g[bottom] = q;
+    let mut h = vec![];
+    for &gnow in &g[bottom + 1..] {
+        let (sum, err) = fast_two_sum(gnow, q);
+        q = sum;
+        if err != 0. {
+            h.push(err);
+        }
+    }
+    if q != 0. {
+        h.push(q);
+    }
+    h
+}
+
+// An approximation of the value of an expansion
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().fold(0., |sum, v| sum + v)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn orient2d_test() {
+        let c = |x, y| Coordinate { x: x, y: y };
+        assert_eq!(
+            orient2d(c(0., 0.), c(1., 1.), c(0., 1.)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orient2d(c(0., 0.), c(0., 1.), c(1., 1.)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orient2d(c(0., 0.), c(1., 1.), c(2., 2.)),
+            Orientation::Collinear
+        );
+        // degenerate triangles are collinear
+        assert_eq!(
+            orient2d(c(1., 1.), c(1., 1.), c(2., 3.)),
+            Orientation::Collinear
+        );
+    }
+    #[test]
+    fn orient2d_integer_test() {
+        let c = |x, y| Coordinate { x: x, y: y };
+        assert_eq!(
+            orient2d(c(0i64, 0), c(10, 0), c(5, 1)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(orient2d(c(0, 0), c(10, 10), c(5, 5)), Orientation::Collinear);
+    }
+    #[test]
+    fn orient2d_nearly_collinear_test() {
+        // The point (x, y) lies on the line through (12, 12) and (24, 24) iff x == y, and to its
+        // left iff y > x. Perturbing (0.5, 0.5) by a few ulps defeats naive evaluation.
+        let ulp = 2f64.powi(-53);
+        let b = Coordinate { x: 12., y: 12. };
+        let c = Coordinate { x: 24., y: 24. };
+        for i in 0..32 {
+            for j in 0..32 {
+                let a = Coordinate {
+                    x: 0.5 + i as f64 * ulp,
+                    y: 0.5 + j as f64 * ulp,
+                };
+                let expected = if j > i {
+                    Orientation::CounterClockwise
+                } else if j < i {
+                    Orientation::Clockwise
+                } else {
+                    Orientation::Collinear
+                };
+                assert_eq!(orient2d(a, b, c), expected);
+            }
+        }
+    }
+    #[test]
+    fn orient2d_consistent_under_permutation_test() {
+        let a = Coordinate { x: 0.1, y: 0.1 };
+        let b = Coordinate { x: 0.3, y: 0.3 };
+        let c = Coordinate {
+            x: 0.30000000000000004,
+            y: 0.3,
+        };
+        let o = orient2d(a, b, c);
+        assert_eq!(orient2d(b, c, a), o);
+        assert_eq!(orient2d(c, a, b), o);
+        assert_ne!(orient2d(a, c, b), o);
+    }
+    #[test]
+    fn exact_point_overflow_test() {
+        // the products of these coordinates overflow, but they can still be ordered
+        let c = |x: f64, y: f64| Coordinate { x: x, y: y };
+        let big = 1e300;
+        let p = ExactPoint::intersection(c(-big, -big), c(big, big), c(-big, big), c(big, -big))
+            .unwrap();
+        let q = ExactPoint::new(c(0., 0.));
+        assert_eq!(p.cmp(&p), Ordering::Equal);
+        assert_eq!(p.cmp(&q), q.cmp(&p).reverse());
+    }
+
+    #[test]
+    fn incircle_test() {
+        let c = |x, y| Coordinate { x: x, y: y };
+        let (a, b, d) = (c(0., 0.), c(1., 0.), c(1., 1.));
+        assert_eq!(incircle(a, b, d, c(0.5, 0.5)), CirclePosition::Inside);
+        assert_eq!(incircle(a, b, d, c(0., 1.)), CirclePosition::OnCircle);
+        assert_eq!(incircle(a, b, d, c(2., 2.)), CirclePosition::Outside);
+        // winding order of the first three points doesn't matter
+        assert_eq!(incircle(a, d, b, c(0.5, 0.5)), CirclePosition::Inside);
+        assert_eq!(incircle(a, d, b, c(2., 2.)), CirclePosition::Outside);
+    }
+    #[test]
+    fn incircle_nearly_cocircular_test() {
+        // The corners of this square lie on a common circle in real arithmetic, but their binary
+        // approximations don't. Whichever way rounding moved them, rotating the quadrilateral by
+        // two vertices must give the same answer, and rotating by one the opposite answer.
+        let c = |x, y| Coordinate { x: x, y: y };
+        let (a, b, d, e) = (c(0.1, 0.1), c(0.3, 0.1), c(0.3, 0.3), c(0.1, 0.3));
+        let pos = incircle(a, b, d, e);
+        let opposite = match pos {
+            CirclePosition::Inside => CirclePosition::Outside,
+            CirclePosition::Outside => CirclePosition::Inside,
+            CirclePosition::OnCircle => CirclePosition::OnCircle,
+        };
+        assert_eq!(incircle(d, e, a, b), pos);
+        assert_eq!(incircle(b, d, e, a), opposite);
+        assert_eq!(incircle(e, a, b, d), opposite);
+    }
+}
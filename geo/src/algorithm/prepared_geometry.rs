@@ -0,0 +1,460 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::intersects::Intersects;
+use algorithm::predicates::{orient2d, Orientation};
+use num_traits::Float;
+use spade::rtree::RTree;
+use spade::{BoundingRect, SpadeFloat, SpatialObject};
+use {Bbox, Line, LineString, MultiPolygon, Point, Polygon};
+
+/// Identifies a ring: ring `0` of each polygon is its exterior, and the interiors follow
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+struct Ring {
+    polygon: usize,
+    ring: usize,
+}
+
+/// A segment of one of the rings of a prepared geometry
+#[derive(Clone)]
+struct RingSegment<T>
+where
+    T: Float,
+{
+    line: Line<T>,
+    ring: Ring,
+}
+
+impl<T> SpatialObject for RingSegment<T>
+where
+    T: Float + SpadeFloat,
+{
+    type Point = Point<T>;
+
+    fn mbr(&self) -> BoundingRect<Point<T>> {
+        BoundingRect::from_corners(&self.line.start_point(), &self.line.end_point())
+    }
+
+    fn distance2(&self, point: &Point<T>) -> T {
+        let d = self.line.euclidean_distance(point);
+        d * d
+    }
+}
+
+/// Where a point lies with respect to one ring, as computed by `contains::get_position`
+#[derive(Default)]
+struct RingPosition {
+    on_boundary: bool,
+    crossings: usize,
+}
+
+/// A `Polygon` or `MultiPolygon` prepared for repeated `Contains`, `Intersects` and
+/// `EuclideanDistance` queries.
+///
+/// The segments of every ring are stored in an
+/// [R* tree](../../../spade/rtree/struct.RTree.html), so that a query only looks at the
+/// segments near its argument, or those crossing a ray cast from a point, instead of scanning
+/// every ring. Results are the same as those of the corresponding traits implemented for
+/// `Polygon`, with a prepared `MultiPolygon` behaving as the union of its polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{LineString, Point, Polygon};
+/// use geo::algorithm::contains::Contains;
+/// use geo::algorithm::euclidean_distance::EuclideanDistance;
+/// use geo::algorithm::prepared_geometry::PreparedGeometry;
+///
+/// let polygon = Polygon::new(
+///     LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+///     vec![LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)])],
+/// );
+/// let prepared = PreparedGeometry::from(&polygon);
+///
+/// assert!(prepared.contains(&Point::new(3., 3.)));
+/// assert!(!prepared.contains(&Point::new(1.5, 1.5)));
+/// assert_eq!(prepared.euclidean_distance(&Point::new(6., 3.)), 2.);
+/// ```
+pub struct PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    segments: RTree<RingSegment<T>>,
+    /// Rings made up of a single vertex, which have no segments
+    vertices: Vec<(Ring, Point<T>)>,
+    bbox: Option<Bbox<T>>,
+    /// Whether some polygon has a ring with no vertices, putting it at no distance from anything
+    empty_ring: bool,
+}
+
+impl<T> PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    pub(crate) fn new<'a, I>(polygons: I) -> PreparedGeometry<T>
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a Polygon<T>>,
+    {
+        let mut segments = vec![];
+        let mut vertices = vec![];
+        let mut bbox: Option<Bbox<T>> = None;
+        let mut empty_ring = false;
+        for (polygon, p) in polygons.into_iter().enumerate() {
+            let rings = ::std::iter::once(&p.exterior).chain(p.interiors.iter());
+            for (ring, linestring) in rings.enumerate() {
+                let ring = Ring { polygon, ring };
+                match linestring.0.len() {
+                    0 => empty_ring = true,
+                    1 => vertices.push((ring, Point(linestring.0[0]))),
+                    _ => segments.extend(linestring.lines().map(|line| RingSegment { line, ring })),
+                }
+                bbox = match (bbox, linestring.bbox()) {
+                    (Some(a), Some(b)) => Some(a + b),
+                    (a, b) => a.or(b),
+                };
+            }
+        }
+        PreparedGeometry {
+            segments: RTree::bulk_load(segments),
+            vertices,
+            bbox,
+            empty_ring,
+        }
+    }
+
+    /// The bounding box of every ring of the geometry, or `None` if it has no vertices
+    pub fn bbox(&self) -> Option<Bbox<T>> {
+        self.bbox
+    }
+
+    /// The segments whose bounding boxes intersect that of `line`
+    fn segments_near(&self, line: &Line<T>) -> Vec<&RingSegment<T>> {
+        self.segments
+            .lookup_in_rectangle(&BoundingRect::from_corners(
+                &line.start_point(),
+                &line.end_point(),
+            ))
+    }
+
+    /// Where `point` lies with respect to each ring it's on, or which a ray cast from it
+    /// towards +x crosses
+    fn ring_positions(&self, point: &Point<T>) -> BTreeMap<Ring, RingPosition> {
+        let mut positions: BTreeMap<Ring, RingPosition> = BTreeMap::new();
+        for &(ring, vertex) in &self.vertices {
+            if Contains::contains(&vertex, point) {
+                positions.entry(ring).or_default().on_boundary = true;
+            }
+        }
+        let xmax = match self.bbox {
+            Some(bbox) if point.x() <= bbox.xmax => bbox.xmax,
+            _ => return positions,
+        };
+        let ray = Line::new(point.0, Point::new(xmax, point.y()).0);
+        for segment in self.segments_near(&ray) {
+            let line = segment.line;
+            let position = positions.entry(segment.ring).or_default();
+            if line.intersects(point) {
+                position.on_boundary = true;
+            }
+            if point.y() > line.start.y.min(line.end.y)
+                && point.y() <= line.start.y.max(line.end.y)
+                && point.x() <= line.start.x.max(line.end.x)
+            {
+                let (lower, upper) = if line.start.y < line.end.y {
+                    (line.start, line.end)
+                } else {
+                    (line.end, line.start)
+                };
+                if orient2d(lower, upper, point.0) != Orientation::Clockwise {
+                    position.crossings += 1;
+                }
+            }
+        }
+        positions
+    }
+
+    /// The polygons containing `point`, in the sense of `Contains<Point<T>> for Polygon<T>`
+    fn containing_polygons(&self, point: &Point<T>) -> BTreeSet<usize> {
+        match self.bbox {
+            Some(bbox) if bbox.contains(point) => containing_polygons(&self.ring_positions(point)),
+            _ => BTreeSet::new(),
+        }
+    }
+}
+
+fn containing_polygons(positions: &BTreeMap<Ring, RingPosition>) -> BTreeSet<usize> {
+    let mut inside = BTreeMap::new();
+    // each polygon's exterior is visited before its interiors
+    for (ring, position) in positions {
+        let in_ring = !position.on_boundary && position.crossings % 2 == 1;
+        if ring.ring == 0 {
+            inside.insert(ring.polygon, in_ring);
+        } else if position.on_boundary || in_ring {
+            inside.insert(ring.polygon, false);
+        }
+    }
+    inside
+        .into_iter()
+        .filter(|&(_, inside)| inside)
+        .map(|(polygon, _)| polygon)
+        .collect()
+}
+
+impl<T> From<&Polygon<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn from(polygon: &Polygon<T>) -> PreparedGeometry<T> {
+        PreparedGeometry::new(::std::iter::once(polygon))
+    }
+}
+
+impl<T> From<&MultiPolygon<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn from(multi_polygon: &MultiPolygon<T>) -> PreparedGeometry<T> {
+        PreparedGeometry::new(&multi_polygon.0)
+    }
+}
+
+impl<T> BoundingBox<T> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    type Output = Option<Bbox<T>>;
+
+    /// Return the BoundingBox of every ring of the geometry
+    fn bbox(&self) -> Self::Output {
+        self.bbox
+    }
+}
+
+impl<T> Contains<Point<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn contains(&self, point: &Point<T>) -> bool {
+        !self.containing_polygons(point).is_empty()
+    }
+}
+
+impl<T> Contains<Line<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn contains(&self, line: &Line<T>) -> bool {
+        let start = self.containing_polygons(&line.start_point());
+        if start.is_empty() {
+            return false;
+        }
+        let end = self.containing_polygons(&line.end_point());
+        let crossed: BTreeSet<usize> = self
+            .segments_near(line)
+            .into_iter()
+            .filter(|segment| segment.line.intersects(line))
+            .map(|segment| segment.ring.polygon)
+            .collect();
+        start
+            .intersection(&end)
+            .any(|polygon| !crossed.contains(polygon))
+    }
+}
+
+impl<T> Intersects<Line<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn intersects(&self, line: &Line<T>) -> bool {
+        let envelope = line.bbox();
+        match self.bbox {
+            Some(bbox)
+                if bbox.xmin <= envelope.xmax
+                    && envelope.xmin <= bbox.xmax
+                    && bbox.ymin <= envelope.ymax
+                    && envelope.ymin <= bbox.ymax =>
+            {
+                self.segments_near(line)
+                    .iter()
+                    .any(|segment| segment.line.intersects(line))
+                    || self.contains(&line.start_point())
+                    || self.contains(&line.end_point())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T> Intersects<LineString<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn intersects(&self, linestring: &LineString<T>) -> bool {
+        linestring.lines().any(|line| {
+            self.segments_near(&line)
+                .iter()
+                .any(|segment| segment.line.intersects(&line))
+        }) || linestring.points_iter().any(|point| self.contains(&point))
+    }
+}
+
+impl<T> EuclideanDistance<T, Point<T>> for PreparedGeometry<T>
+where
+    T: Float + SpadeFloat,
+{
+    /// Minimum distance from a prepared geometry to a Point
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        if self.empty_ring {
+            return T::zero();
+        }
+        let positions = self.ring_positions(point);
+        // the distance to an interior is measured as that to a LineString, so it's zero on the
+        // ring itself
+        if positions
+            .iter()
+            .any(|(ring, position)| ring.ring > 0 && position.on_boundary)
+            || !containing_polygons(&positions).is_empty()
+        {
+            return T::zero();
+        }
+        match self.segments.nearest_neighbor(point) {
+            Some(segment) => segment.line.euclidean_distance(point),
+            None => T::max_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Coordinate;
+
+    fn polygon_with_hole() -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (0., 0.),
+                (8., 0.),
+                (8., 6.),
+                (4., 3.),
+                (0., 6.),
+                (0., 0.),
+            ]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (3., 1.),
+                (3., 2.),
+                (1., 2.),
+                (1., 1.),
+            ])],
+        )
+    }
+
+    fn grid() -> Vec<Point<f64>> {
+        let mut points = vec![];
+        for i in -4..38 {
+            for j in -4..30 {
+                points.push(Point::new(i as f64 / 4., j as f64 / 4.));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn contains_point_matches_polygon_test() {
+        let polygon = polygon_with_hole();
+        let prepared = PreparedGeometry::from(&polygon);
+        for point in grid() {
+            assert_eq!(
+                prepared.contains(&point),
+                polygon.contains(&point),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn distance_matches_polygon_test() {
+        let polygon = polygon_with_hole();
+        let prepared = PreparedGeometry::from(&polygon);
+        for point in grid() {
+            assert_eq!(
+                prepared.euclidean_distance(&point),
+                polygon.euclidean_distance(&point),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn lines_match_polygon_test() {
+        let polygon = polygon_with_hole();
+        let prepared = PreparedGeometry::from(&polygon);
+        let points: Vec<_> = grid().into_iter().step_by(7).collect();
+        for a in &points {
+            for b in points.iter().step_by(5) {
+                let line = Line::new(a.0, b.0);
+                assert_eq!(
+                    prepared.contains(&line),
+                    polygon.contains(&line),
+                    "{:?}",
+                    line
+                );
+                assert_eq!(
+                    prepared.intersects(&line),
+                    polygon.intersects(&line),
+                    "{:?}",
+                    line
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn linestring_test() {
+        let prepared = PreparedGeometry::from(&polygon_with_hole());
+        let crossing = LineString::from(vec![(-1., 4.), (4., 4.)]);
+        let in_hole = LineString::from(vec![(1.5, 1.5), (2.5, 1.5)]);
+        let outside = LineString::from(vec![(4., 4.), (4., 7.), (9., 7.)]);
+        assert!(prepared.intersects(&crossing));
+        assert!(!prepared.intersects(&in_hole));
+        assert!(!prepared.intersects(&outside));
+    }
+
+    #[test]
+    fn multipolygon_test() {
+        let square = |x: f64| {
+            Polygon::new(
+                LineString::from(vec![(x, 0.), (x + 1., 0.), (x + 1., 1.), (x, 1.), (x, 0.)]),
+                vec![],
+            )
+        };
+        let multi_polygon = MultiPolygon(vec![square(0.), square(2.)]);
+        let prepared = PreparedGeometry::from(&multi_polygon);
+        assert!(prepared.contains(&Point::new(0.5, 0.5)));
+        assert!(prepared.contains(&Point::new(2.5, 0.5)));
+        assert!(!prepared.contains(&Point::new(1.5, 0.5)));
+        assert!(prepared.contains(&Line::from([(2.2, 0.5), (2.8, 0.5)])));
+        assert!(!prepared.contains(&Line::from([(0.5, 0.5), (2.5, 0.5)])));
+        assert!(prepared.intersects(&Line::from([(0.5, 2.), (2.5, -2.)])));
+        assert_eq!(prepared.euclidean_distance(&Point::new(1.5, 0.5)), 0.5);
+        assert_eq!(
+            prepared.euclidean_distance(&Point::new(1.5, 0.5)),
+            multi_polygon.euclidean_distance(&Point::new(1.5, 0.5))
+        );
+    }
+
+    #[test]
+    fn empty_test() {
+        let prepared = PreparedGeometry::from(&MultiPolygon::<f64>(vec![]));
+        let point = Point(Coordinate { x: 1., y: 1. });
+        assert!(!prepared.contains(&point));
+        assert!(!prepared.intersects(&Line::new(point.0, point.0)));
+        assert_eq!(prepared.euclidean_distance(&point), f64::MAX);
+        assert_eq!(prepared.bbox(), None);
+    }
+}
@@ -0,0 +1,847 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound::{Excluded, Included, Unbounded};
+use std::rc::Rc;
+
+use algorithm::line_intersection::{line_intersection, LineIntersection};
+use algorithm::predicates::{orient2d, ExactPoint, Orientation};
+use num_traits::Float;
+use {Line, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// An intersection between two segments, identified by their indices
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SegmentIntersection<T>
+where
+    T: Float,
+{
+    /// The index of the first segment
+    pub first: usize,
+    /// The index of the second segment
+    pub second: usize,
+    /// Where the two segments meet
+    pub intersection: LineIntersection<T>,
+}
+
+/// Finds every intersecting pair of segments in a slice of `Line`s.
+///
+/// This is an implementation of the Bentley–Ottmann sweep-line algorithm, taking
+/// O((n + k) log n) time for n segments meeting in k pairs. Every pair of segments which touch,
+/// cross or overlap is reported once, with `first < second`, ordered by `first` and then by
+/// `second`. Segments having a non-finite coordinate are ignored.
+///
+/// The sweep is driven by exact orientation tests, and every pair it finds is confirmed with
+/// [`line_intersection`](../line_intersection/fn.line_intersection.html), so no false positives
+/// are returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Line, Point};
+/// use geo::algorithm::line_intersection::LineIntersection;
+/// use geo::algorithm::segment_intersections::segment_intersections;
+///
+/// let segments = vec![
+///     Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. }),
+///     Line::new(Coordinate { x: 0., y: 4. }, Coordinate { x: 4., y: 0. }),
+///     Line::new(Coordinate { x: 5., y: 0. }, Coordinate { x: 5., y: 4. }),
+/// ];
+/// let intersections = segment_intersections(&segments);
+/// assert_eq!(intersections.len(), 1);
+/// assert_eq!((intersections[0].first, intersections[0].second), (0, 1));
+/// assert_eq!(
+///     intersections[0].intersection,
+///     LineIntersection::SinglePoint {
+///         intersection: Point::new(2., 2.),
+///         is_proper: true,
+///     }
+/// );
+/// ```
+pub fn segment_intersections<T>(segments: &[Line<T>]) -> Vec<SegmentIntersection<T>>
+where
+    T: Float,
+{
+    sweep(segments, |_, _| true)
+}
+
+/// Access to the segments of a linear or areal geometry
+pub trait Segments<T>
+where
+    T: Float,
+{
+    /// Returns the segments making up the geometry.
+    ///
+    /// `LineString`s contribute their `lines()` in order. Polygons contribute their exterior
+    /// followed by each of their interiors, and multi-geometries contribute each of their
+    /// members in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Coordinate, Line, LineString, Polygon};
+    /// use geo::algorithm::segment_intersections::Segments;
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (4., 0.), (0., 4.), (0., 0.)]),
+    ///     vec![LineString::from(vec![(1., 1.), (2., 1.), (1., 2.), (1., 1.)])],
+    /// );
+    /// let segments = polygon.segments();
+    /// assert_eq!(segments.len(), 6);
+    /// assert_eq!(
+    ///     segments[3],
+    ///     Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 2., y: 1. })
+    /// );
+    /// ```
+    fn segments(&self) -> Vec<Line<T>>;
+}
+
+impl<T> Segments<T> for Line<T>
+where
+    T: Float,
+{
+    fn segments(&self) -> Vec<Line<T>> {
+        vec![*self]
+    }
+}
+
+impl<T> Segments<T> for LineString<T>
+where
+    T: Float,
+{
+    fn segments(&self) -> Vec<Line<T>> {
+        self.lines().collect()
+    }
+}
+
+impl<T> Segments<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn segments(&self) -> Vec<Line<T>> {
+        self.0.iter().flat_map(|ls| ls.lines()).collect()
+    }
+}
+
+impl<T> Segments<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn segments(&self) -> Vec<Line<T>> {
+        rings(self).flat_map(|ring| ring.lines()).collect()
+    }
+}
+
+impl<T> Segments<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn segments(&self) -> Vec<Line<T>> {
+        self.0.iter().flat_map(|p| p.segments()).collect()
+    }
+}
+
+/// Finds the places where a geometry intersects itself
+pub trait SelfIntersections<T>
+where
+    T: Float,
+{
+    /// Returns every pair of segments of the geometry which touch, cross or overlap, other
+    /// than consecutive segments meeting only at their shared vertex.
+    ///
+    /// Segments are indexed in the order given by
+    /// [`Segments::segments`](trait.Segments.html#tymethod.segments). Zero-length segments
+    /// are skipped, so the segments either side of a repeated vertex count as consecutive; the
+    /// first and last segments of a closed `LineString` are consecutive as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::line_intersection::LineIntersection;
+    /// use geo::algorithm::segment_intersections::SelfIntersections;
+    ///
+    /// // a bow tie
+    /// let linestring = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+    /// let intersections = linestring.self_intersections();
+    /// assert_eq!(intersections.len(), 1);
+    /// assert_eq!((intersections[0].first, intersections[0].second), (0, 2));
+    /// assert_eq!(
+    ///     intersections[0].intersection,
+    ///     LineIntersection::SinglePoint {
+    ///         intersection: Point::new(1., 1.),
+    ///         is_proper: true,
+    ///     }
+    /// );
+    /// ```
+    fn self_intersections(&self) -> Vec<SegmentIntersection<T>>;
+}
+
+impl<T> SelfIntersections<T> for LineString<T>
+where
+    T: Float,
+{
+    fn self_intersections(&self) -> Vec<SegmentIntersection<T>> {
+        chain_self_intersections(&[self])
+    }
+}
+
+impl<T> SelfIntersections<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn self_intersections(&self) -> Vec<SegmentIntersection<T>> {
+        chain_self_intersections(&self.0.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T> SelfIntersections<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn self_intersections(&self) -> Vec<SegmentIntersection<T>> {
+        chain_self_intersections(&rings(self).collect::<Vec<_>>())
+    }
+}
+
+impl<T> SelfIntersections<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn self_intersections(&self) -> Vec<SegmentIntersection<T>> {
+        chain_self_intersections(&self.0.iter().flat_map(|p| rings(p)).collect::<Vec<_>>())
+    }
+}
+
+/// Finds the places where two geometries intersect each other
+pub trait Intersections<T, Rhs = Self>
+where
+    T: Float,
+{
+    /// Returns every pair of intersecting segments taken one from each geometry.
+    ///
+    /// `first` indexes the segments of `self` and `second` those of `rhs`, in the order given
+    /// by [`Segments::segments`](trait.Segments.html#tymethod.segments).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::line_intersection::LineIntersection;
+    /// use geo::algorithm::segment_intersections::Intersections;
+    ///
+    /// let zigzag = LineString::from(vec![(0., 0.), (1., 2.), (2., 0.), (3., 2.)]);
+    /// let horizontal = LineString::from(vec![(0., 1.), (4., 1.)]);
+    /// let intersections = zigzag.intersections(&horizontal);
+    /// assert_eq!(intersections.len(), 3);
+    /// assert_eq!((intersections[1].first, intersections[1].second), (1, 0));
+    /// assert_eq!(
+    ///     intersections[1].intersection,
+    ///     LineIntersection::SinglePoint {
+    ///         intersection: Point::new(1.5, 1.),
+    ///         is_proper: true,
+    ///     }
+    /// );
+    /// ```
+    fn intersections(&self, rhs: &Rhs) -> Vec<SegmentIntersection<T>>;
+}
+
+impl<T, G, H> Intersections<T, H> for G
+where
+    T: Float,
+    G: Segments<T>,
+    H: Segments<T>,
+{
+    fn intersections(&self, rhs: &H) -> Vec<SegmentIntersection<T>> {
+        let mut segments = self.segments();
+        let split = segments.len();
+        segments.extend(rhs.segments());
+        sweep(&segments, |a, b| (a < split) != (b < split))
+            .into_iter()
+            .map(|i| SegmentIntersection {
+                second: i.second - split,
+                ..i
+            })
+            .collect()
+    }
+}
+
+fn rings<T>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>>
+where
+    T: Float,
+{
+    ::std::iter::once(&polygon.exterior).chain(polygon.interiors.iter())
+}
+
+/// Self-intersections of a sequence of line strings, indexed by their concatenated segments
+fn chain_self_intersections<T>(chains: &[&LineString<T>]) -> Vec<SegmentIntersection<T>>
+where
+    T: Float,
+{
+    let mut segments = vec![];
+    // For each non-degenerate segment: its index, and those of its predecessor and successor
+    let mut neighbours = vec![];
+    for chain in chains {
+        let offset = segments.len();
+        segments.extend(chain.lines());
+        let indices: Vec<usize> = (offset..segments.len())
+            .filter(|&i| segments[i].start != segments[i].end)
+            .collect();
+        let closed = chain.0.first() == chain.0.last();
+        for (pos, &i) in indices.iter().enumerate() {
+            let prev = if pos > 0 {
+                Some(indices[pos - 1])
+            } else if closed {
+                indices.last().cloned()
+            } else {
+                None
+            };
+            let next = if pos + 1 < indices.len() {
+                Some(indices[pos + 1])
+            } else if closed {
+                indices.first().cloned()
+            } else {
+                None
+            };
+            neighbours.push((i, prev, next));
+        }
+    }
+    let mut adjacent = BTreeSet::new();
+    for (i, prev, next) in neighbours {
+        for j in prev.into_iter().chain(next) {
+            if i != j {
+                adjacent.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    sweep(&segments, |a, b| {
+        segments[a].start != segments[a].end && segments[b].start != segments[b].end
+    })
+    .into_iter()
+    .filter(|i| match i.intersection {
+        LineIntersection::SinglePoint { .. } => !adjacent.contains(&(i.first, i.second)),
+        LineIntersection::Collinear { .. } => true,
+    })
+    .collect()
+}
+
+/// The part of an input segment to the right of `left`
+struct Piece<T>
+where
+    T: Float,
+{
+    left: Rc<ExactPoint>,
+    /// The whole segment, running left to right in sweep order
+    line: Line<T>,
+    segment: usize,
+}
+
+/// The pieces directly below and above a position in the sweep line status
+type Neighbours<T> = (Option<Rc<Piece<T>>>, Option<Rc<Piece<T>>>);
+
+/// An entry in the sweep line status, ordered from bottom to top
+enum Active<T>
+where
+    T: Float,
+{
+    Piece(Rc<Piece<T>>),
+    /// Sorts below every piece through the point, and above every other piece below it
+    Below(Rc<ExactPoint>),
+    /// Sorts above every piece through the point, and below every other piece above it
+    Above(Rc<ExactPoint>),
+}
+
+impl<T> Active<T>
+where
+    T: Float,
+{
+    fn piece(&self) -> Option<&Rc<Piece<T>>> {
+        match *self {
+            Active::Piece(ref piece) => Some(piece),
+            _ => None,
+        }
+    }
+}
+
+/// The position of a point relative to a piece, with `on_piece` used if the point lies on it
+fn cmp_point_piece<T>(point: &Rc<ExactPoint>, piece: &Piece<T>, on_piece: Ordering) -> Ordering
+where
+    T: Float,
+{
+    if Rc::ptr_eq(point, &piece.left) {
+        return on_piece;
+    }
+    match point.orientation(piece.line.start, piece.line.end) {
+        Orientation::CounterClockwise => Ordering::Greater,
+        Orientation::Clockwise => Ordering::Less,
+        Orientation::Collinear => on_piece,
+    }
+}
+
+/// Orders two pieces which are simultaneously active, and do not cross.
+///
+/// This doesn't depend on the position of the sweep line: the order of the status remains
+/// consistent because pieces are cut wherever they meet, before the sweep line passes.
+fn cmp_pieces<T>(a: &Piece<T>, b: &Piece<T>) -> Ordering
+where
+    T: Float,
+{
+    if a.segment == b.segment {
+        return Ordering::Equal;
+    }
+    // Compare the piece starting later against the line through the other one
+    let (later, earlier, flip) = if a.left >= b.left {
+        (a, b, false)
+    } else {
+        (b, a, true)
+    };
+    // pieces cut at the same point both pass through it
+    let mut orientation = if Rc::ptr_eq(&later.left, &earlier.left) {
+        Orientation::Collinear
+    } else {
+        later.left.orientation(earlier.line.start, earlier.line.end)
+    };
+    if orientation == Orientation::Collinear {
+        orientation = orient2d(earlier.line.start, earlier.line.end, later.line.end);
+    }
+    let ordering = match orientation {
+        Orientation::CounterClockwise => Ordering::Greater,
+        Orientation::Clockwise => Ordering::Less,
+        // collinear overlapping pieces: any consistent order will do
+        Orientation::Collinear => later.segment.cmp(&earlier.segment),
+    };
+    if flip {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+impl<T> Ord for Active<T>
+where
+    T: Float,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Active::Piece(a), Active::Piece(b)) => cmp_pieces(a, b),
+            (Active::Below(p), Active::Piece(piece)) => cmp_point_piece(p, piece, Ordering::Less),
+            (Active::Above(p), Active::Piece(piece)) => {
+                cmp_point_piece(p, piece, Ordering::Greater)
+            }
+            (Active::Piece(_), _) => other.cmp(self).reverse(),
+            (Active::Below(_), Active::Above(_)) => Ordering::Less,
+            (Active::Above(_), Active::Below(_)) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+impl<T> PartialOrd for Active<T>
+where
+    T: Float,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Active<T>
+where
+    T: Float,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Active<T> where T: Float {}
+
+struct Sweep<T, F>
+where
+    T: Float,
+{
+    /// The piece of each segment currently in the status
+    current: Vec<Option<Rc<Piece<T>>>>,
+    status: BTreeSet<Active<T>>,
+    /// Event points, with the segments starting at each of them
+    events: BTreeMap<ExactPoint, Vec<usize>>,
+    /// Pairs of segment indices which intersect
+    found: BTreeSet<(usize, usize)>,
+    include: F,
+}
+
+impl<T, F> Sweep<T, F>
+where
+    T: Float,
+    F: Fn(usize, usize) -> bool,
+{
+    fn record(&mut self, a: usize, b: usize) {
+        let (a, b) = (a.min(b), a.max(b));
+        if a != b && (self.include)(a, b) {
+            self.found.insert((a, b));
+        }
+    }
+
+    fn insert(&mut self, piece: Piece<T>) -> Rc<Piece<T>> {
+        let piece = Rc::new(piece);
+        self.current[piece.segment] = Some(piece.clone());
+        self.status.insert(Active::Piece(piece.clone()));
+        piece
+    }
+
+    fn remove(&mut self, piece: &Rc<Piece<T>>) {
+        self.status.remove(&Active::Piece(piece.clone()));
+        self.current[piece.segment] = None;
+    }
+
+    /// Compares two neighbouring pieces, adding an event where they meet ahead of the sweep line
+    fn check(&mut self, a: &Piece<T>, b: &Piece<T>, point: &ExactPoint) {
+        let meeting = match line_intersection(a.line, b.line) {
+            None => return,
+            Some(LineIntersection::SinglePoint {
+                is_proper: true, ..
+            }) => ExactPoint::intersection(a.line.start, a.line.end, b.line.start, b.line.end)
+                .expect("lines crossing at a single point can't be parallel"),
+            // an endpoint of one segment, which is returned exactly
+            Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                ExactPoint::new(intersection.0)
+            }
+            Some(LineIntersection::Collinear { .. }) => {
+                self.record(a.segment, b.segment);
+                return;
+            }
+        };
+        if meeting > *point {
+            self.events.entry(meeting).or_default();
+        } else {
+            self.record(a.segment, b.segment);
+        }
+    }
+
+    fn neighbours(&self, active: &Active<T>) -> Neighbours<T> {
+        let below = self
+            .status
+            .range((Unbounded, Excluded(active)))
+            .next_back()
+            .and_then(|a| a.piece().cloned());
+        let above = self
+            .status
+            .range((Excluded(active), Unbounded))
+            .next()
+            .and_then(|a| a.piece().cloned());
+        (below, above)
+    }
+
+    fn handle_event(&mut self, point: ExactPoint, starts: Vec<usize>, lines: &[Line<T>]) {
+        let point = Rc::new(point);
+        let below = Active::Below(point.clone());
+        let above = Active::Above(point.clone());
+        // Every active piece passing through the point
+        let through: Vec<Rc<Piece<T>>> = self
+            .status
+            .range((Included(&below), Included(&above)))
+            .filter_map(|a| a.piece().cloned())
+            .collect();
+        let involved: Vec<usize> = through
+            .iter()
+            .map(|piece| piece.segment)
+            .chain(starts.iter().cloned())
+            .collect();
+        for (i, &a) in involved.iter().enumerate() {
+            for &b in &involved[i + 1..] {
+                self.record(a, b);
+            }
+        }
+
+        // Cut the pieces through the point, and continue them from it
+        for piece in &through {
+            self.remove(piece);
+        }
+        let continuing = through
+            .iter()
+            .map(|piece| (piece.line, piece.segment))
+            .chain(starts.iter().map(|&segment| (lines[segment], segment)))
+            .filter(|&(line, _)| ExactPoint::new(line.end) > *point)
+            .collect::<Vec<_>>();
+        let inserted: Vec<Rc<Piece<T>>> = continuing
+            .into_iter()
+            .map(|(line, segment)| {
+                self.insert(Piece {
+                    left: point.clone(),
+                    line,
+                    segment,
+                })
+            })
+            .collect();
+
+        if inserted.is_empty() {
+            // the pieces either side of the point have become neighbours
+            if let (Some(low), Some(high)) = (self.neighbours(&below).0, self.neighbours(&above).1)
+            {
+                self.check(&low, &high, &point);
+            }
+        }
+        for piece in inserted {
+            let (low, high) = self.neighbours(&Active::Piece(piece.clone()));
+            if let Some(low) = low {
+                self.check(&low, &piece, &point);
+            }
+            if let Some(high) = high {
+                self.check(&piece, &high, &point);
+            }
+        }
+    }
+}
+
+/// Runs the sweep over `segments`, reporting the intersecting pairs for which `include` holds
+fn sweep<T, F>(segments: &[Line<T>], include: F) -> Vec<SegmentIntersection<T>>
+where
+    T: Float,
+    F: Fn(usize, usize) -> bool,
+{
+    let mut sweep = Sweep {
+        current: vec![None; segments.len()],
+        status: BTreeSet::new(),
+        events: BTreeMap::new(),
+        found: BTreeSet::new(),
+        include,
+    };
+    // Each segment, running left to right
+    let mut lines = Vec::with_capacity(segments.len());
+    for (segment, line) in segments.iter().enumerate() {
+        if ![line.start.x, line.start.y, line.end.x, line.end.y]
+            .iter()
+            .all(|c| c.is_finite())
+        {
+            lines.push(*line);
+            continue;
+        }
+        let (start, end) = (ExactPoint::new(line.start), ExactPoint::new(line.end));
+        lines.push(if start <= end {
+            *line
+        } else {
+            Line::new(line.end, line.start)
+        });
+        let (left, right) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        sweep.events.entry(left).or_default().push(segment);
+        // make sure the segment is removed once the sweep reaches its end
+        sweep.events.entry(right).or_default();
+    }
+
+    while let Some(point) = sweep.events.keys().next().cloned() {
+        let starts = sweep.events.remove(&point).unwrap();
+        sweep.handle_event(point, starts, &lines);
+    }
+
+    sweep
+        .found
+        .iter()
+        .filter_map(|&(first, second)| {
+            line_intersection(segments[first], segments[second]).map(|intersection| {
+                SegmentIntersection {
+                    first,
+                    second,
+                    intersection,
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Coordinate, Point};
+
+    fn brute_force(segments: &[Line<f64>]) -> Vec<SegmentIntersection<f64>> {
+        let mut result = vec![];
+        for i in 0..segments.len() {
+            for j in i + 1..segments.len() {
+                if let Some(intersection) = line_intersection(segments[i], segments[j]) {
+                    result.push(SegmentIntersection {
+                        first: i,
+                        second: j,
+                        intersection,
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// Deterministic pseudo-random segments, with coordinates scaled from integers in 0..range
+    fn random_segments(count: usize, range: u64, scale: f64, mut seed: u64) -> Vec<Line<f64>> {
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((seed >> 33) % range) as f64 * scale
+        };
+        (0..count)
+            .map(|_| {
+                let start = Coordinate {
+                    x: next(),
+                    y: next(),
+                };
+                Line::new(
+                    start,
+                    Coordinate {
+                        x: next(),
+                        y: next(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crossing_test() {
+        let segments = vec![
+            Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. }),
+            Line::new(Coordinate { x: 4., y: 0. }, Coordinate { x: 0., y: 4. }),
+            Line::new(Coordinate { x: 2., y: -1. }, Coordinate { x: 2., y: 5. }),
+            Line::new(Coordinate { x: 6., y: 0. }, Coordinate { x: 6., y: 1. }),
+        ];
+        // three segments crossing at a single point
+        let result = segment_intersections(&segments);
+        let pairs: Vec<_> = result.iter().map(|i| (i.first, i.second)).collect();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+        for i in result {
+            assert_eq!(
+                i.intersection,
+                LineIntersection::SinglePoint {
+                    intersection: Point::new(2., 2.),
+                    is_proper: true,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate_segments_test() {
+        let segments = vec![
+            // collinear overlapping, horizontal and vertical
+            Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 0. }),
+            Line::new(Coordinate { x: 2., y: 0. }, Coordinate { x: 6., y: 0. }),
+            Line::new(Coordinate { x: 3., y: -2. }, Coordinate { x: 3., y: 2. }),
+            Line::new(Coordinate { x: 3., y: 1. }, Coordinate { x: 3., y: 3. }),
+            // a zero-length segment on another segment
+            Line::new(Coordinate { x: 5., y: 0. }, Coordinate { x: 5., y: 0. }),
+            // ignored
+            Line::new(
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 4., y: f64::NAN },
+            ),
+        ];
+        assert_eq!(
+            segment_intersections(&segments),
+            brute_force(&segments[..5])
+        );
+        assert_eq!(segment_intersections(&segments).len(), 5);
+    }
+
+    #[test]
+    fn matches_brute_force_on_grid_test() {
+        // small integer grids produce many shared endpoints, overlaps and multiple crossings
+        for seed in 0..20 {
+            let segments = random_segments(60, 8, 1., seed);
+            assert_eq!(segment_intersections(&segments), brute_force(&segments));
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_test() {
+        for seed in 0..10 {
+            let segments = random_segments(60, 1 << 20, 0.1, seed);
+            assert_eq!(segment_intersections(&segments), brute_force(&segments));
+        }
+    }
+
+    #[test]
+    fn linestring_self_intersections_test() {
+        // a simple open linestring, and one with a repeated vertex
+        let simple = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+        assert!(simple.self_intersections().is_empty());
+        let repeated = LineString::from(vec![(0., 0.), (1., 0.), (1., 0.), (1., 1.)]);
+        assert!(repeated.self_intersections().is_empty());
+        // closing the linestring adds no intersection
+        let closed = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+        assert!(closed.self_intersections().is_empty());
+        // but touching the start does
+        let touching = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0.5, 0.)]);
+        let result = touching.self_intersections();
+        assert_eq!(result.len(), 1);
+        assert_eq!((result[0].first, result[0].second), (0, 3));
+        assert!(!result[0].intersection.is_proper());
+        // doubling back on itself
+        let spike = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.)]);
+        let result = spike.self_intersections();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].intersection,
+            LineIntersection::Collinear {
+                intersection: Line::new(Coordinate { x: 1., y: 0. }, Coordinate { x: 2., y: 0. }),
+            }
+        );
+    }
+
+    #[test]
+    fn polygon_self_intersections_test() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let inside = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)]);
+        let escaping = LineString::from(vec![(3., 1.), (5., 1.), (5., 2.), (3., 1.)]);
+        let polygon = Polygon::new(exterior.clone(), vec![inside.clone()]);
+        assert!(polygon.self_intersections().is_empty());
+        let polygon = Polygon::new(exterior, vec![inside, escaping]);
+        let pairs: Vec<_> = polygon
+            .self_intersections()
+            .iter()
+            .map(|i| (i.first, i.second))
+            .collect();
+        // the exterior's second segment crosses two edges of the second interior
+        assert_eq!(pairs, vec![(1, 7), (1, 9)]);
+    }
+
+    #[test]
+    fn multilinestring_self_intersections_test() {
+        let roads = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (2., 0.), (4., 0.)]),
+            LineString::from(vec![(1., -1.), (1., 1.), (3., 1.), (3., -1.)]),
+            LineString::from(vec![(4., 0.), (5., 1.)]),
+        ]);
+        let pairs: Vec<_> = roads
+            .self_intersections()
+            .iter()
+            .map(|i| (i.first, i.second))
+            .collect();
+        assert_eq!(pairs, vec![(0, 2), (1, 4), (1, 5)]);
+    }
+
+    #[test]
+    fn intersections_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+            vec![],
+        );
+        let line = Line::new(Coordinate { x: -1., y: 2. }, Coordinate { x: 5., y: 2. });
+        let pairs: Vec<_> = polygon
+            .intersections(&line)
+            .iter()
+            .map(|i| (i.first, i.second))
+            .collect();
+        assert_eq!(pairs, vec![(1, 0), (3, 0)]);
+        let pairs: Vec<_> = line
+            .intersections(&polygon)
+            .iter()
+            .map(|i| (i.first, i.second))
+            .collect();
+        assert_eq!(pairs, vec![(0, 1), (0, 3)]);
+        // intersections within either geometry are not included
+        let bow_tie = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+        let line = Line::new(Coordinate { x: -1., y: 1.5 }, Coordinate { x: 5., y: 1.5 });
+        assert_eq!(bow_tie.self_intersections().len(), 1);
+        assert_eq!(bow_tie.intersections(&line).len(), 4);
+    }
+}
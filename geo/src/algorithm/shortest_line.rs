@@ -0,0 +1,431 @@
+use algorithm::closest_point::ClosestPoint;
+use algorithm::intersects::Intersects;
+use algorithm::line_intersection::{line_intersection, LineIntersection};
+use num_traits::Float;
+use {
+    Closest, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+/// Returns the shortest line between two geometries.
+pub trait ShortestLine<T, Rhs = Self>
+where
+    T: Float,
+{
+    /// Returns the shortest line from `self` to `rhs`, which starts on `self` and ends on `rhs`.
+    ///
+    /// Its length is the distance between the geometries. As with `ClosestPoint`, geometries
+    /// which intersect, including one inside a polygon, give a line of zero length at a point
+    /// they share, like `Closest::Intersection`, and the result is `None`, like
+    /// `Closest::Indeterminate`, if either geometry is empty. When several lines are equally
+    /// short, any one of them may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Line, LineString, Point, Polygon};
+    /// use geo::algorithm::shortest_line::ShortestLine;
+    ///
+    /// let path = LineString::from(vec![(0., 0.), (2., 3.), (4., 0.)]);
+    /// let field = Polygon::new(
+    ///     LineString::from(vec![(0., 5.), (4., 5.), (4., 8.), (0., 8.), (0., 5.)]),
+    ///     vec![],
+    /// );
+    ///
+    /// let connector = path.shortest_line(&field).unwrap();
+    /// assert_eq!(connector, Line::from([(2., 3.), (2., 5.)]));
+    ///
+    /// let inside = Point::new(1., 6.);
+    /// assert_eq!(field.shortest_line(&inside), Some(Line::from([(1., 6.), (1., 6.)])));
+    /// ```
+    fn shortest_line(&self, rhs: &Rhs) -> Option<Line<T>>;
+}
+
+/// The parts of a geometry, gathered from all of its members
+struct Parts<'a, T>
+where
+    T: Float + 'a,
+{
+    /// Points, and line strings with a single vertex
+    points: Vec<Point<T>>,
+    /// Segments, including those of polygon rings
+    lines: Vec<Line<T>>,
+    polygons: Vec<&'a Polygon<T>>,
+}
+
+impl<'a, T> Parts<'a, T>
+where
+    T: Float,
+{
+    fn add_linestring(&mut self, linestring: &LineString<T>) {
+        if linestring.0.len() == 1 {
+            self.points.push(Point(linestring.0[0]));
+        }
+        self.lines.extend(linestring.lines());
+    }
+
+    fn add_polygon(&mut self, polygon: &'a Polygon<T>) {
+        self.add_linestring(&polygon.exterior);
+        for ring in &polygon.interiors {
+            self.add_linestring(ring);
+        }
+        self.polygons.push(polygon);
+    }
+
+    fn add_geometry(&mut self, geometry: &'a Geometry<T>) {
+        match *geometry {
+            Geometry::Point(ref g) => g.add_parts(self),
+            Geometry::Line(ref g) => g.add_parts(self),
+            Geometry::LineString(ref g) => g.add_parts(self),
+            Geometry::Polygon(ref g) => g.add_parts(self),
+            Geometry::MultiPoint(ref g) => g.add_parts(self),
+            Geometry::MultiLineString(ref g) => g.add_parts(self),
+            Geometry::MultiPolygon(ref g) => g.add_parts(self),
+            Geometry::GeometryCollection(ref g) => g.add_parts(self),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.points.is_empty() && self.lines.is_empty()
+    }
+
+    /// A vertex of `other` which lies in one of these polygons, if any does
+    fn covered_vertex(&self, other: &Parts<T>) -> Option<Point<T>> {
+        if self.polygons.is_empty() {
+            return None;
+        }
+        let mut vertices = other
+            .points
+            .iter()
+            .cloned()
+            .chain(other.lines.iter().map(|line| line.start_point()));
+        vertices.find(|vertex| {
+            self.polygons
+                .iter()
+                .any(|polygon| polygon.intersects(vertex))
+        })
+    }
+}
+
+/// Gathers the parts of a geometry for finding the shortest line to another
+trait ShortestLineParts<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>);
+
+    fn parts<'a>(&'a self) -> Parts<'a, T> {
+        let mut parts = Parts {
+            points: vec![],
+            lines: vec![],
+            polygons: vec![],
+        };
+        self.add_parts(&mut parts);
+        parts
+    }
+}
+
+impl<T> ShortestLineParts<T> for Point<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.points.push(*self);
+    }
+}
+
+impl<T> ShortestLineParts<T> for Line<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.lines.push(*self);
+    }
+}
+
+impl<T> ShortestLineParts<T> for LineString<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.add_linestring(self);
+    }
+}
+
+impl<T> ShortestLineParts<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.add_polygon(self);
+    }
+}
+
+impl<T> ShortestLineParts<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.points.extend(self.0.iter().cloned());
+    }
+}
+
+impl<T> ShortestLineParts<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        for linestring in &self.0 {
+            parts.add_linestring(linestring);
+        }
+    }
+}
+
+impl<T> ShortestLineParts<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        for polygon in &self.0 {
+            parts.add_polygon(polygon);
+        }
+    }
+}
+
+impl<T> ShortestLineParts<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        for geometry in &self.0 {
+            parts.add_geometry(geometry);
+        }
+    }
+}
+
+impl<T> ShortestLineParts<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn add_parts<'a>(&'a self, parts: &mut Parts<'a, T>) {
+        parts.add_geometry(self);
+    }
+}
+
+/// The point of a segment closest to `p`, which may be either end of a segment of zero length
+fn closest_on_segment<T>(segment: Line<T>, p: Point<T>) -> Point<T>
+where
+    T: Float,
+{
+    match segment.closest_point(&p) {
+        Closest::Intersection(c) | Closest::SinglePoint(c) => c,
+        Closest::Indeterminate => segment.start_point(),
+    }
+}
+
+/// The shortest line between two segments
+fn segment_to_segment<T>(a: Line<T>, b: Line<T>) -> Line<T>
+where
+    T: Float,
+{
+    if let Some(intersection) = line_intersection(a, b) {
+        let shared = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => intersection.0,
+            LineIntersection::Collinear { intersection } => intersection.start,
+        };
+        return Line::new(shared, shared);
+    }
+    // otherwise, one end of the shortest line is an end of a segment
+    let candidates = [
+        Line::new(a.start, closest_on_segment(b, a.start_point()).0),
+        Line::new(a.end, closest_on_segment(b, a.end_point()).0),
+        Line::new(closest_on_segment(a, b.start_point()).0, b.start),
+        Line::new(closest_on_segment(a, b.end_point()).0, b.end),
+    ];
+    shortest(candidates.iter().cloned()).unwrap()
+}
+
+/// The first of the shortest lines
+fn shortest<T, I>(lines: I) -> Option<Line<T>>
+where
+    T: Float,
+    I: IntoIterator<Item = Line<T>>,
+{
+    let squared = |line: &Line<T>| line.dx() * line.dx() + line.dy() * line.dy();
+    lines.into_iter().fold(None, |best, line| match best {
+        Some(best) if squared(&best) <= squared(&line) => Some(best),
+        _ => Some(line),
+    })
+}
+
+fn shortest_line<T>(a: &Parts<T>, b: &Parts<T>) -> Option<Line<T>>
+where
+    T: Float,
+{
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    // a geometry which overlaps a polygon has a vertex inside it, unless their boundaries cross
+    if let Some(vertex) = a.covered_vertex(b).or_else(|| b.covered_vertex(a)) {
+        return Some(Line::new(vertex.0, vertex.0));
+    }
+    let point_to_point = a
+        .points
+        .iter()
+        .flat_map(|p| b.points.iter().map(move |q| Line::new(p.0, q.0)));
+    let point_to_line = a.points.iter().flat_map(|&p| {
+        b.lines
+            .iter()
+            .map(move |&line| Line::new(p.0, closest_on_segment(line, p).0))
+    });
+    let line_to_point = a.lines.iter().flat_map(|&line| {
+        b.points
+            .iter()
+            .map(move |&p| Line::new(closest_on_segment(line, p).0, p.0))
+    });
+    let line_to_line = a
+        .lines
+        .iter()
+        .flat_map(|&l| b.lines.iter().map(move |&m| segment_to_segment(l, m)));
+    shortest(
+        point_to_point
+            .chain(point_to_line)
+            .chain(line_to_point)
+            .chain(line_to_line),
+    )
+}
+
+macro_rules! shortest_line_impl {
+    ($($from:ident),*) => {
+        shortest_line_impl!(@each [$($from),*] [$($from),*]);
+    };
+    (@each [$($from:ident),*] $to:tt) => {
+        $(shortest_line_impl!(@pairs $from $to);)*
+    };
+    (@pairs $from:ident [$($to:ident),*]) => {
+        $(
+            impl<T> ShortestLine<T, $to<T>> for $from<T>
+            where
+                T: Float,
+            {
+                fn shortest_line(&self, rhs: &$to<T>) -> Option<Line<T>> {
+                    shortest_line(&self.parts(), &rhs.parts())
+                }
+            }
+        )*
+    };
+}
+shortest_line_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::euclidean_distance::EuclideanDistance;
+    use algorithm::euclidean_length::EuclideanLength;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn points_test() {
+        let a = Point::new(0., 0.);
+        let b = MultiPoint(vec![Point::new(5., 5.), Point::new(3., 4.)]);
+        assert_eq!(a.shortest_line(&b), Some(Line::from([(0., 0.), (3., 4.)])));
+        assert_eq!(b.shortest_line(&a), Some(Line::from([(3., 4.), (0., 0.)])));
+    }
+
+    #[test]
+    fn segments_test() {
+        // the nearest point of the first is in the middle of it
+        let a = Line::from([(-1., 0.), (1., 0.)]);
+        let b = LineString::from(vec![(0., 2.), (0., 5.), (3., 5.)]);
+        assert_eq!(a.shortest_line(&b), Some(Line::from([(0., 0.), (0., 2.)])));
+        // crossing
+        let c = Line::from([(0., -1.), (0., 1.)]);
+        assert_eq!(a.shortest_line(&c), Some(Line::from([(0., 0.), (0., 0.)])));
+    }
+
+    #[test]
+    fn polygon_test() {
+        let mut donut = square(0., 0., 10.);
+        donut.interiors.push(square(3., 3., 4.).exterior);
+        // inside the hole
+        let point = Point::new(4., 5.);
+        assert_eq!(
+            point.shortest_line(&donut),
+            Some(Line::from([(4., 5.), (3., 5.)]))
+        );
+        // inside the polygon itself
+        let inside = LineString::from(vec![(1., 1.), (2., 1.)]);
+        assert_eq!(
+            inside.shortest_line(&donut),
+            Some(Line::from([(1., 1.), (1., 1.)]))
+        );
+        // a polygon containing another
+        let small = square(1., 1., 1.);
+        assert_eq!(
+            donut.shortest_line(&small),
+            Some(Line::from([(1., 1.), (1., 1.)]))
+        );
+        let apart = square(12., 4., 1.);
+        assert_eq!(
+            donut.shortest_line(&apart),
+            Some(Line::from([(10., 4.), (12., 4.)]))
+        );
+    }
+
+    #[test]
+    fn matches_distance_test() {
+        let shapes: Vec<Geometry<f64>> = vec![
+            Geometry::Polygon(square(0., 0., 2.)),
+            Geometry::Polygon(square(5., 1., 3.)),
+            Geometry::LineString(LineString::from(vec![(-3., 4.), (1., 7.), (9., 5.)])),
+            Geometry::Line(Line::from([(3., -2.), (4., 3.)])),
+            Geometry::Point(Point::new(-1., -1.)),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(10., 0.), Point::new(6., 2.)])),
+        ];
+        for a in &shapes {
+            for b in &shapes {
+                let line = a.shortest_line(b).unwrap();
+                let distance = a.euclidean_distance(b);
+                assert_relative_eq!(line.euclidean_length(), distance, epsilon = 1e-12);
+                assert_relative_eq!(
+                    line.start_point().euclidean_distance(a),
+                    0.,
+                    epsilon = 1e-12
+                );
+                assert_relative_eq!(line.end_point().euclidean_distance(b), 0., epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_test() {
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.shortest_line(&Point::new(1., 1.)), None);
+        assert_eq!(
+            Point::new(1., 1.).shortest_line(&MultiPolygon(vec![])),
+            None
+        );
+    }
+}
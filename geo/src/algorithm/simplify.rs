@@ -1,5 +1,9 @@
+use algorithm::contains::Contains;
 use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::line_intersection::{line_intersection, LineIntersection};
 use num_traits::Float;
+use spade::rtree::RTree;
+use spade::{BoundingRect, SpadeFloat, SpatialObject};
 use {Line, LineString, MultiLineString, MultiPolygon, Point, Polygon};
 
 // Ramer–Douglas-Peucker line simplification algorithm
@@ -108,9 +112,300 @@ where
     }
 }
 
+/// A segment of one of the parts being simplified: `start` is the index of its first
+/// coordinate in the part
+#[derive(PartialEq, Clone, Debug)]
+struct TaggedSegment<T>
+where
+    T: Float,
+{
+    line: Line<T>,
+    part: usize,
+    start: usize,
+}
+
+impl<T> SpatialObject for TaggedSegment<T>
+where
+    T: Float + SpadeFloat,
+{
+    type Point = Point<T>;
+
+    fn mbr(&self) -> BoundingRect<Point<T>> {
+        BoundingRect::from_corners(&self.line.start_point(), &self.line.end_point())
+    }
+
+    fn distance2(&self, point: &Point<T>) -> T {
+        let d = self.line.euclidean_distance(point);
+        d * d
+    }
+}
+
+/// Douglas-Peucker simplification of several lines and rings at once, which share an R* tree
+/// of their current segments.
+// A section of a part is only replaced by a single segment if that segment doesn't cross any
+// other segment, and if no other part lies in the area between the section and the segment;
+// otherwise the section is split at its farthest point, as it would be if that point were
+// further than epsilon from the segment.
+struct TopologySimplifier<'a, T>
+where
+    T: 'a + Float + SpadeFloat,
+{
+    parts: &'a [&'a LineString<T>],
+    epsilon: T,
+    tree: RTree<TaggedSegment<T>>,
+    kept: Vec<Vec<bool>>,
+    remaining: Vec<usize>,
+    min_points: Vec<usize>,
+}
+
+impl<'a, T> TopologySimplifier<'a, T>
+where
+    T: 'a + Float + SpadeFloat,
+{
+    fn new(parts: &'a [&'a LineString<T>], epsilon: T) -> TopologySimplifier<'a, T> {
+        let mut segments = vec![];
+        for (part, linestring) in parts.iter().enumerate() {
+            for (start, line) in linestring.lines().enumerate() {
+                segments.push(TaggedSegment { line, part, start });
+            }
+        }
+        TopologySimplifier {
+            parts,
+            epsilon,
+            tree: RTree::bulk_load(segments),
+            kept: parts.iter().map(|ls| vec![true; ls.0.len()]).collect(),
+            remaining: parts.iter().map(|ls| ls.0.len()).collect(),
+            // closed rings must keep at least four points, and lines two
+            min_points: parts
+                .iter()
+                .map(|ls| {
+                    if ls.0.len() > 2 && ls.0.first() == ls.0.last() {
+                        4
+                    } else {
+                        2
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn simplify(mut self) -> Vec<LineString<T>> {
+        for part in 0..self.parts.len() {
+            let len = self.parts[part].0.len();
+            if len > 2 {
+                self.simplify_section(part, 0, len - 1);
+            }
+        }
+        self.parts
+            .iter()
+            .zip(self.kept.iter())
+            .map(|(ls, kept)| {
+                LineString(
+                    ls.0.iter()
+                        .zip(kept.iter())
+                        .filter_map(|(c, keep)| if *keep { Some(*c) } else { None })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn simplify_section(&mut self, part: usize, i: usize, j: usize) {
+        if j < i + 2 {
+            return;
+        }
+        let coords = &self.parts[part].0;
+        let candidate = Line::new(coords[i], coords[j]);
+        let mut dmax = T::zero();
+        let mut index = i + 1;
+        for (k, c) in coords.iter().enumerate().take(j).skip(i + 1) {
+            let distance = Point(*c).euclidean_distance(&candidate);
+            if distance > dmax {
+                index = k;
+                dmax = distance;
+            }
+        }
+        let removed = j - i - 1;
+        if dmax <= self.epsilon
+            && self.remaining[part] >= self.min_points[part] + removed
+            && self.can_replace(part, i, j)
+        {
+            for start in i..j {
+                self.tree.remove(&TaggedSegment {
+                    line: Line::new(coords[start], coords[start + 1]),
+                    part,
+                    start,
+                });
+            }
+            self.tree.insert(TaggedSegment {
+                line: candidate,
+                part,
+                start: i,
+            });
+            for keep in &mut self.kept[part][i + 1..j] {
+                *keep = false;
+            }
+            self.remaining[part] -= removed;
+        } else {
+            self.simplify_section(part, i, index);
+            self.simplify_section(part, index, j);
+        }
+    }
+
+    /// Can the section of `part` from `i` to `j` be replaced by a single segment?
+    fn can_replace(&self, part: usize, i: usize, j: usize) -> bool {
+        let coords = &self.parts[part].0;
+        let candidate = Line::new(coords[i], coords[j]);
+        let crosses = self
+            .tree
+            .lookup_in_rectangle(&BoundingRect::from_corners(
+                &candidate.start_point(),
+                &candidate.end_point(),
+            ))
+            .iter()
+            .filter(|segment| !(segment.part == part && i <= segment.start && segment.start < j))
+            .any(|segment| match line_intersection(candidate, segment.line) {
+                // the segment's ends are kept, so anything touching them already did
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    intersection.0 != candidate.start && intersection.0 != candidate.end
+                }
+                Some(LineIntersection::Collinear { .. }) => true,
+                None => false,
+            });
+        if crosses {
+            return false;
+        }
+        // Without crossing the new segment, another part can only change sides if it lies
+        // entirely between the section and the segment, so checking one of its points will do
+        let section = &coords[i..j + 1];
+        let mut bbox = BoundingRect::from_point(Point(coords[i]));
+        for c in section {
+            bbox.add_point(Point(*c));
+        }
+        // the section closed by the new segment
+        let mut ring = section.to_vec();
+        ring.push(coords[i]);
+        let swept = Polygon::new(LineString(ring), vec![]);
+        !self
+            .parts
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != part)
+            .filter_map(|(_, ls)| ls.0.first())
+            .any(|c| bbox.contains_point(&Point(*c)) && swept.contains(&Point(*c)))
+    }
+}
+
+/// Simplifies a geometry, preserving its topology.
+///
+/// A variant of the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) which never creates
+/// new intersections: the simplified lines and rings don't cross themselves or each other,
+/// Polygon interiors stay inside their exteriors, and rings are never reduced below four
+/// points. All the lines or rings of a geometry are simplified together, so the polygons of a
+/// MultiPolygon, for instance, won't come to overlap either.
+pub trait SimplifyPreserve<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, using a topology-preserving variant
+    /// of the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm.
+    ///
+    /// The current segments of the geometry are stored in an
+    /// [R* tree](../../../spade/rtree/struct.RTree.html), which is used to find those near a
+    /// candidate segment. Where replacing a section of a line by a single segment would cause
+    /// an intersection, move another part of the geometry to its other side, or leave a ring
+    /// with fewer than four points, the section is split at its farthest point instead, as if
+    /// that point were further than `epsilon` from the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::simplify::{Simplify, SimplifyPreserve};
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (5., 11.), (0., 10.), (0., 0.)]),
+    ///     vec![LineString::from(vec![(4., 9.), (6., 9.), (5., 10.5), (4., 9.)])],
+    /// );
+    ///
+    /// // The peak at (5., 11.) is within epsilon of the exterior, but removing it would cut
+    /// // through the interior
+    /// let simplified = polygon.simplify(&2.);
+    /// assert_eq!(simplified.exterior.0.len(), 5);
+    /// let simplified = polygon.simplify_preserve(&2.);
+    /// assert_eq!(simplified, polygon);
+    /// ```
+    fn simplify_preserve(&self, epsilon: &T) -> Self
+    where
+        T: Float + SpadeFloat;
+}
+
+impl<T> SimplifyPreserve<T> for LineString<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> LineString<T> {
+        TopologySimplifier::new(&[self], *epsilon)
+            .simplify()
+            .pop()
+            .unwrap()
+    }
+}
+
+impl<T> SimplifyPreserve<T> for MultiLineString<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> MultiLineString<T> {
+        let parts: Vec<_> = self.0.iter().collect();
+        MultiLineString(TopologySimplifier::new(&parts, *epsilon).simplify())
+    }
+}
+
+impl<T> SimplifyPreserve<T> for Polygon<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> Polygon<T> {
+        let parts: Vec<_> = Some(&self.exterior)
+            .into_iter()
+            .chain(self.interiors.iter())
+            .collect();
+        let mut rings = TopologySimplifier::new(&parts, *epsilon).simplify();
+        let exterior = rings.remove(0);
+        Polygon::new(exterior, rings)
+    }
+}
+
+impl<T> SimplifyPreserve<T> for MultiPolygon<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> MultiPolygon<T> {
+        let parts: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|p| Some(&p.exterior).into_iter().chain(p.interiors.iter()))
+            .collect();
+        let mut rings = TopologySimplifier::new(&parts, *epsilon)
+            .simplify()
+            .into_iter();
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|p| {
+                    let exterior = rings.next().unwrap();
+                    let interiors = rings.by_ref().take(p.interiors.len()).collect();
+                    Polygon::new(exterior, interiors)
+                })
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use algorithm::is_simple::IsSimple;
 
     #[test]
     fn rdp_test() {
@@ -219,4 +514,135 @@ mod test {
             )])
         );
     }
+
+    #[test]
+    fn preserve_matches_rdp_test() {
+        let ls = LineString::from(vec![
+            (0.0, 0.0),
+            (5.0, 4.0),
+            (11.0, 5.5),
+            (17.3, 3.2),
+            (27.8, 0.1),
+        ]);
+        assert_eq!(ls.simplify_preserve(&1.0), ls.simplify(&1.0));
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.simplify_preserve(&1.0), empty);
+    }
+
+    #[test]
+    fn preserve_self_intersection_test() {
+        let ls = LineString::from(vec![
+            (0., 0.),
+            (5., 1.),
+            (10., 0.),
+            (10., -3.),
+            (7., -3.2),
+            (4., -3.),
+            (4., 0.5),
+        ]);
+        assert!(ls.is_simple());
+        // dropping (5., 1.) would cut the hook off
+        assert!(!ls.simplify(&1.5).is_simple());
+        let simplified = ls.simplify_preserve(&1.5);
+        assert!(simplified.is_simple());
+        assert_eq!(
+            simplified,
+            LineString::from(vec![
+                (0., 0.),
+                (5., 1.),
+                (10., 0.),
+                (10., -3.),
+                (4., -3.),
+                (4., 0.5),
+            ])
+        );
+    }
+
+    #[test]
+    fn preserve_polygon_test() {
+        // a hole close to the exterior's peak would end up outside it
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (0., 0.),
+                (10., 0.),
+                (10., 10.),
+                (5., 11.),
+                (0., 10.),
+                (0., 0.),
+            ]),
+            vec![LineString::from(vec![
+                (4., 10.1),
+                (6., 10.1),
+                (5., 10.4),
+                (4., 10.1),
+            ])],
+        );
+        let shell = |p: &Polygon<f64>| Polygon::new(p.exterior.clone(), vec![]);
+        assert!(shell(&polygon).contains(&polygon.interiors[0]));
+        assert!(!shell(&polygon.simplify(&2.)).contains(&polygon.interiors[0]));
+        assert_eq!(polygon.simplify_preserve(&2.), polygon);
+        let simplified = polygon.simplify_preserve(&100.);
+        assert_eq!(simplified.interiors, polygon.interiors);
+        assert!(shell(&simplified).contains(&polygon.interiors[0]));
+        // rings keep four points, however large epsilon is
+        assert_eq!(shell(&polygon).simplify(&100.).exterior.0.len(), 2);
+        assert_eq!(shell(&polygon).simplify_preserve(&100.).exterior.0.len(), 4);
+    }
+
+    #[test]
+    fn preserve_swept_hole_test() {
+        // dropping the corner at (0., 10.) would sweep over the hole, without the new segment
+        // crossing it
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10., 10.),
+                (0., 10.),
+                (0., 0.),
+                (10., 0.),
+                (10., -30.),
+                (40., -30.),
+                (40., 10.),
+                (10., 10.),
+            ]),
+            vec![LineString::from(vec![
+                (4., 4.),
+                (6., 4.),
+                (6., 6.),
+                (4., 6.),
+                (4., 4.),
+            ])],
+        );
+        let shell = |p: &Polygon<f64>| Polygon::new(p.exterior.clone(), vec![]);
+        assert!(!shell(&polygon.simplify(&12.)).contains(&polygon.interiors[0]));
+        let simplified = polygon.simplify_preserve(&12.);
+        assert!(shell(&simplified).contains(&polygon.interiors[0]));
+        assert!(shell(&simplified).contains(&simplified.interiors[0]));
+    }
+
+    #[test]
+    fn preserve_multipolygon_test() {
+        // the first polygon's dent would swallow the second
+        let mpoly = MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![
+                    (0., 0.),
+                    (10., 0.),
+                    (10., 10.),
+                    (5., 9.),
+                    (0., 10.),
+                    (0., 0.),
+                ]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![(4., 9.5), (6., 9.5), (5., 9.8), (4., 9.5)]),
+                vec![],
+            ),
+        ]);
+        let simplified = mpoly.simplify(&2.);
+        assert!(simplified.0[0].contains(&simplified.0[1]));
+        assert_eq!(mpoly.simplify_preserve(&2.), mpoly);
+        let lines = MultiLineString(mpoly.0.iter().map(|p| p.exterior.clone()).collect());
+        assert_eq!(lines.simplify_preserve(&2.), lines);
+    }
 }
@@ -79,35 +79,36 @@ struct GeomSettings {
     geomtype: GeomType,
 }
 
-/// Simplify a line using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
+/// The order in which the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263)
+/// algorithm eliminates the points of a line, along with their effective areas. The first and
+/// last points are never eliminated.
 //
-// epsilon is the minimum triangle area
 // The paper states that:
 // If [the new triangle's] calculated area is less than that of the last point to be
 // eliminated, use the latter's area instead.
 // (This ensures that the current point cannot be eliminated
 // without eliminating previously eliminated points)
 // (Visvalingam and Whyatt 2013, p47)
-// However, this does *not* apply if you're using a user-defined epsilon;
-// It's OK to remove triangles with areas below the epsilon,
-// then recalculate the new triangle area and push it onto the heap
+// The elimination order itself is based on the triangles' calculated areas, so that
+// eliminating the points with an effective area below a user-defined epsilon gives the
+// same result as eliminating them while their calculated area is below it.
 // based on Huon Wilson's original implementation:
 // https://github.com/huonw/isrustfastyet/blob/25e7a68ff26673a8556b170d3c9af52e1c818288/mem/line_simplify.rs
-fn visvalingam<T>(orig: &LineString<T>, epsilon: &T) -> Vec<Coordinate<T>>
+fn visvalingam_order<T>(orig: &[Coordinate<T>]) -> Vec<(usize, T)>
 where
     T: Float,
 {
     // No need to continue without at least three points
-    if orig.0.len() < 3 {
-        return orig.0.to_vec();
+    if orig.len() < 3 {
+        return vec![];
     }
 
-    let max = orig.0.len();
+    let max = orig.len();
 
     // Adjacent retained points. Simulating the points in a
     // linked list with indices into `orig`. Big number (larger than or equal to
     // `max`) means no next element, and (0, 0) means deleted element.
-    let mut adjacent: Vec<(_)> = (0..orig.0.len())
+    let mut adjacent: Vec<(_)> = (0..orig.len())
         .map(|i| {
             if i == 0 {
                 (-1_i32, 1_i32)
@@ -125,36 +126,33 @@ where
     let mut pq = BinaryHeap::new();
     // Compute the initial triangles, i.e. take all consecutive groups
     // of 3 points and form triangles from them
-    for (i, triangle) in orig.triangles().enumerate() {
+    for (i, triangle) in orig.windows(3).enumerate() {
         pq.push(VScore {
-            area: triangle.area().abs(),
+            area: Triangle(triangle[0], triangle[1], triangle[2]).area().abs(),
             current: i + 1,
             left: i,
             right: i + 2,
             intersector: false,
         });
     }
-    // While there are still points for which the associated triangle
-    // has an area below the epsilon
+    let mut order = Vec::with_capacity(max - 2);
+    let mut effective_area = T::zero();
+    // Eliminate the point with the smallest triangle, until only the ends are left
     while let Some(smallest) = pq.pop() {
-        // This triangle's area is above epsilon, so skip it
-        if smallest.area > *epsilon {
-            continue;
-        }
-        //  This triangle's area is below epsilon: eliminate the associated point
         let (left, right) = adjacent[smallest.current];
         // A point in this triangle has been removed since this VScore
         // was created, so skip it
         if left as i32 != smallest.left as i32 || right as i32 != smallest.right as i32 {
             continue;
         }
-        // We've got a valid triangle, and its area is smaller than epsilon, so
-        // remove it from the simulated "linked list"
+        // We've got a valid triangle, so remove its point from the simulated "linked list"
         let (ll, _) = adjacent[left as usize];
         let (_, rr) = adjacent[right as usize];
         adjacent[left as usize] = (ll, right);
         adjacent[right as usize] = (left, rr);
         adjacent[smallest.current as usize] = (0, 0);
+        effective_area = effective_area.max(smallest.area);
+        order.push((smallest.current, effective_area));
 
         // Now recompute the adjacent triangle(s), using left and right adjacent points
         let choices = [(ll, left, right), (left, right, rr)];
@@ -164,9 +162,9 @@ where
                 continue;
             }
             let area = Triangle(
-                orig.0[ai as usize],
-                orig.0[current_point as usize],
-                orig.0[bi as usize],
+                orig[ai as usize],
+                orig[current_point as usize],
+                orig[bi as usize],
             ).area().abs();
             pq.push(VScore {
                 area: area,
@@ -177,12 +175,137 @@ where
             });
         }
     }
-    // Filter out the points that have been deleted, returning remaining points
-    orig.0
+    order
+}
+
+/// The points of `orig` which aren't among the `eliminated`
+fn retained<T>(orig: &[Coordinate<T>], eliminated: &[(usize, T)]) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let mut keep = vec![true; orig.len()];
+    for &(i, _) in eliminated {
+        keep[i] = false;
+    }
+    orig.iter()
+        .zip(keep.iter())
+        .filter_map(|(c, keep)| if *keep { Some(*c) } else { None })
+        .collect()
+}
+
+/// Simplify a line using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
+//
+// epsilon is the minimum triangle area
+fn visvalingam<T>(orig: &LineString<T>, epsilon: &T) -> Vec<Coordinate<T>>
+where
+    T: Float,
+{
+    let order = visvalingam_order(&orig.0);
+    // effective areas only ever increase along the elimination order
+    let eliminated = order
         .iter()
-        .zip(adjacent.iter())
-        .filter_map(|(tup, adj)| if *adj != (0, 0) { Some(*tup) } else { None })
-        .collect::<Vec<Coordinate<T>>>()
+        .take_while(|&&(_, area)| area <= *epsilon)
+        .count();
+    retained(&orig.0, &order[..eliminated])
+}
+
+/// Eliminates points from several lines, in order of their effective areas, until no more than
+/// `max_points` are left, or each line is down to `min_points`
+fn visvalingam_to_count<T>(
+    lines: &[&LineString<T>],
+    min_points: usize,
+    max_points: usize,
+) -> Vec<LineString<T>>
+where
+    T: Float,
+{
+    let orders: Vec<_> = lines.iter().map(|l| visvalingam_order(&l.0)).collect();
+    let mut candidates: Vec<(T, usize, usize)> = orders
+        .iter()
+        .enumerate()
+        .flat_map(|(line, order)| {
+            order
+                .iter()
+                .enumerate()
+                .map(move |(rank, &(_, area))| (area, line, rank))
+        })
+        .collect();
+    // within a line, effective areas never decrease along the elimination order
+    candidates.sort_by(|a, b| {
+        a.0.partial_cmp(&b.0)
+            .unwrap()
+            .then(a.1.cmp(&b.1))
+            .then(a.2.cmp(&b.2))
+    });
+    let mut eliminated = vec![0; lines.len()];
+    let mut remaining: usize = lines.iter().map(|l| l.0.len()).sum();
+    for (_, line, rank) in candidates {
+        if remaining <= max_points {
+            break;
+        }
+        // points must be eliminated in order, so once a line stops, it stays stopped
+        if rank == eliminated[line] && lines[line].0.len() - rank > min_points {
+            eliminated[line] += 1;
+            remaining -= 1;
+        }
+    }
+    lines
+        .iter()
+        .zip(orders.iter().zip(eliminated.iter()))
+        .map(|(l, (order, &n))| LineString(retained(&l.0, &order[..n])))
+        .collect()
+}
+
+/// The number of points making up `percentage` of `total`
+fn percentage_of<T>(total: usize, percentage: T) -> usize
+where
+    T: Float,
+{
+    let hundred = T::from(100).unwrap();
+    let percentage = percentage.max(T::zero()).min(hundred);
+    (T::from(total).unwrap() * percentage / hundred)
+        .round()
+        .to_usize()
+        .unwrap_or(0)
+}
+
+/// The effective area of each point of a line, with the ends' being infinite
+fn effective_areas<T>(orig: &[Coordinate<T>]) -> Vec<T>
+where
+    T: Float,
+{
+    let mut areas = vec![T::infinity(); orig.len()];
+    for (i, area) in visvalingam_order(orig) {
+        areas[i] = area;
+    }
+    areas
+}
+
+/// The exteriors and interiors of some polygons, in order
+fn polygon_rings<T>(polygons: &[Polygon<T>]) -> Vec<&LineString<T>>
+where
+    T: Float,
+{
+    polygons
+        .iter()
+        .flat_map(|p| Some(&p.exterior).into_iter().chain(p.interiors.iter()))
+        .collect()
+}
+
+/// Puts polygons back together from their simplified rings, as listed by `polygon_rings`
+fn from_rings<T>(polygons: &[Polygon<T>], rings: Vec<LineString<T>>) -> Vec<Polygon<T>>
+where
+    T: Float,
+{
+    let mut rings = rings.into_iter();
+    polygons
+        .iter()
+        .map(|p| {
+            let exterior = rings.next().unwrap();
+            let interiors = rings.by_ref().take(p.interiors.len()).collect();
+            Polygon::new(exterior, interiors)
+        })
+        .collect()
 }
 
 /// Wrap the actual VW function so the R* Tree can be shared.
@@ -486,6 +609,122 @@ pub trait SimplifyVWPreserve<T, Epsilon = T> {
         T: Float + SpadeFloat;
 }
 
+/// Simplifies a geometry to a given number of points, eliminating them in the order the
+/// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
+/// would.
+///
+/// Points are ranked across all the lines or rings of a geometry by their effective area, as
+/// returned by [`VWEffectiveAreas`](trait.VWEffectiveAreas.html), and the least significant are
+/// eliminated first. Lines keep at least two points, and Polygon rings at least four, so a
+/// geometry may be left with more points than asked for. This has no guarantee of preserving
+/// topology.
+pub trait SimplifyVWToCount<T>
+where
+    T: Float,
+{
+    /// Returns the geometry with no more than `max_points` points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::simplifyvw::SimplifyVWToCount;
+    ///
+    /// let linestring = LineString::from(vec![
+    ///     (5.0, 2.0),
+    ///     (3.0, 8.0),
+    ///     (6.0, 20.0),
+    ///     (7.0, 25.0),
+    ///     (10.0, 10.0),
+    /// ]);
+    ///
+    /// let simplified = linestring.simplifyvw_to_count(4);
+    /// assert_eq!(
+    ///     simplified,
+    ///     LineString::from(vec![(5.0, 2.0), (3.0, 8.0), (7.0, 25.0), (10.0, 10.0)])
+    /// );
+    /// ```
+    fn simplifyvw_to_count(&self, max_points: usize) -> Self;
+
+    /// Returns the geometry with `percentage` of its points, rounded to the nearest point, as
+    /// mapshaper's `-simplify percentage=` does. `percentage` runs from 0 to 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::simplifyvw::SimplifyVWToCount;
+    ///
+    /// let linestring = LineString::from(vec![
+    ///     (5.0, 2.0),
+    ///     (3.0, 8.0),
+    ///     (6.0, 20.0),
+    ///     (7.0, 25.0),
+    ///     (10.0, 10.0),
+    /// ]);
+    ///
+    /// let simplified = linestring.simplifyvw_to_percentage(60.);
+    /// assert_eq!(
+    ///     simplified,
+    ///     LineString::from(vec![(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)])
+    /// );
+    /// ```
+    fn simplifyvw_to_percentage(&self, percentage: T) -> Self;
+}
+
+/// Computes the effective area of each point of a geometry, as used by the
+/// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm.
+///
+/// A point's effective area is the area of the triangle it forms with its neighbours when it is
+/// eliminated, or that of the point eliminated before it, whichever is larger. The ends of each
+/// line or ring are never eliminated, and have an infinite effective area.
+pub trait VWEffectiveAreas<T>
+where
+    T: Float,
+{
+    /// The effective areas, laid out like the points of the geometry: a `Vec<T>` for a
+    /// `LineString`, a `Vec` of those for a `MultiLineString` or for the exterior and
+    /// interiors of a `Polygon`, and a `Vec` of those for a `MultiPolygon`.
+    type Output;
+
+    /// Returns the effective area of each point.
+    ///
+    /// Keeping the points whose effective area is greater than `epsilon` gives the same result
+    /// as `simplifyvw(&epsilon)`, so the areas can be stored alongside a geometry, and used
+    /// to simplify it progressively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::simplifyvw::{SimplifyVW, VWEffectiveAreas};
+    ///
+    /// let linestring = LineString::from(vec![
+    ///     (5.0, 2.0),
+    ///     (3.0, 8.0),
+    ///     (6.0, 20.0),
+    ///     (7.0, 25.0),
+    ///     (10.0, 10.0),
+    /// ]);
+    ///
+    /// let areas = linestring.vw_effective_areas();
+    /// assert_eq!(areas, vec![std::f64::INFINITY, 29.0, 1.5, 49.5, std::f64::INFINITY]);
+    ///
+    /// for epsilon in &[1.0, 30.0, 100.0] {
+    ///     let simplified: LineString<_> = linestring
+    ///         .0
+    ///         .iter()
+    ///         .zip(areas.iter())
+    ///         .filter(|&(_, area)| area > epsilon)
+    ///         .map(|(c, _)| *c)
+    ///         .collect::<Vec<_>>()
+    ///         .into();
+    ///     assert_eq!(simplified, linestring.simplifyvw(epsilon));
+    /// }
+    /// ```
+    fn vw_effective_areas(&self) -> Self::Output;
+}
+
 impl<T> SimplifyVWPreserve<T> for LineString<T>
 where
     T: Float + SpadeFloat,
@@ -588,11 +827,118 @@ where
     }
 }
 
+impl<T> SimplifyVWToCount<T> for LineString<T>
+where
+    T: Float,
+{
+    fn simplifyvw_to_count(&self, max_points: usize) -> LineString<T> {
+        visvalingam_to_count(&[self], 2, max_points).pop().unwrap()
+    }
+
+    fn simplifyvw_to_percentage(&self, percentage: T) -> LineString<T> {
+        self.simplifyvw_to_count(percentage_of(self.0.len(), percentage))
+    }
+}
+
+impl<T> SimplifyVWToCount<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn simplifyvw_to_count(&self, max_points: usize) -> MultiLineString<T> {
+        let lines: Vec<_> = self.0.iter().collect();
+        MultiLineString(visvalingam_to_count(&lines, 2, max_points))
+    }
+
+    fn simplifyvw_to_percentage(&self, percentage: T) -> MultiLineString<T> {
+        let total = self.0.iter().map(|l| l.0.len()).sum();
+        self.simplifyvw_to_count(percentage_of(total, percentage))
+    }
+}
+
+impl<T> SimplifyVWToCount<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn simplifyvw_to_count(&self, max_points: usize) -> Polygon<T> {
+        let polygons = [self.clone()];
+        let rings = visvalingam_to_count(&polygon_rings(&polygons), 4, max_points);
+        from_rings(&polygons, rings).pop().unwrap()
+    }
+
+    fn simplifyvw_to_percentage(&self, percentage: T) -> Polygon<T> {
+        let total = self.exterior.0.len() + self.interiors.iter().map(|r| r.0.len()).sum::<usize>();
+        self.simplifyvw_to_count(percentage_of(total, percentage))
+    }
+}
+
+impl<T> SimplifyVWToCount<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn simplifyvw_to_count(&self, max_points: usize) -> MultiPolygon<T> {
+        let rings = visvalingam_to_count(&polygon_rings(&self.0), 4, max_points);
+        MultiPolygon(from_rings(&self.0, rings))
+    }
+
+    fn simplifyvw_to_percentage(&self, percentage: T) -> MultiPolygon<T> {
+        let total = polygon_rings(&self.0).iter().map(|r| r.0.len()).sum();
+        self.simplifyvw_to_count(percentage_of(total, percentage))
+    }
+}
+
+impl<T> VWEffectiveAreas<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = Vec<T>;
+
+    fn vw_effective_areas(&self) -> Vec<T> {
+        effective_areas(&self.0)
+    }
+}
+
+impl<T> VWEffectiveAreas<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Vec<Vec<T>>;
+
+    fn vw_effective_areas(&self) -> Vec<Vec<T>> {
+        self.0.iter().map(|l| l.vw_effective_areas()).collect()
+    }
+}
+
+impl<T> VWEffectiveAreas<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Vec<Vec<T>>;
+
+    fn vw_effective_areas(&self) -> Vec<Vec<T>> {
+        Some(&self.exterior)
+            .into_iter()
+            .chain(self.interiors.iter())
+            .map(|r| r.vw_effective_areas())
+            .collect()
+    }
+}
+
+impl<T> VWEffectiveAreas<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = Vec<Vec<Vec<T>>>;
+
+    fn vw_effective_areas(&self) -> Vec<Vec<Vec<T>>> {
+        self.0.iter().map(|p| p.vw_effective_areas()).collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{
         cartesian_intersect, visvalingam, vwp_wrapper, GeomSettings, GeomType, SimplifyVW,
-        SimplifyVWPreserve,
+        SimplifyVWPreserve, SimplifyVWToCount, VWEffectiveAreas,
     };
     use {Coordinate, LineString, MultiLineString, MultiPolygon, Point, Polygon};
 
@@ -861,4 +1207,84 @@ mod test {
             )])
         );
     }
+
+    #[test]
+    fn effective_areas_test() {
+        let ls: LineString<f64> = (0..40)
+            .map(|i| {
+                let x = i as f64;
+                (x, (x * 0.7).sin() * 10. + (x * 2.3).cos() * 3.)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let areas = ls.vw_effective_areas();
+        assert_eq!(areas.len(), 40);
+        assert!(areas[0].is_infinite() && areas[39].is_infinite());
+        for epsilon in &[0., 0.5, 1., 2., 5., 10., 20., 100.] {
+            let kept = ls.0.iter().zip(&areas).filter(|&(_, area)| area > epsilon);
+            let simplified: Vec<_> = kept.map(|(c, _)| *c).collect();
+            assert_eq!(LineString(simplified), ls.simplifyvw(epsilon));
+        }
+        let poly = Polygon::new(ls.clone(), vec![ls.clone()]);
+        assert_eq!(poly.vw_effective_areas(), vec![areas.clone(), areas]);
+    }
+
+    #[test]
+    fn to_count_test() {
+        let ls: LineString<f64> = (0..40)
+            .map(|i| {
+                let x = i as f64;
+                (x, (x * 0.7).sin() * 10.)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        for n in 2..41 {
+            assert_eq!(ls.simplifyvw_to_count(n).0.len(), n);
+        }
+        // fewer than two points aren't possible
+        assert_eq!(ls.simplifyvw_to_count(0).0.len(), 2);
+        assert_eq!(ls.simplifyvw_to_percentage(25.).0.len(), 10);
+        assert_eq!(ls.simplifyvw_to_percentage(150.), ls);
+        // points are ranked across lines: a straight line loses its points first
+        let straight: LineString<f64> = (0..10).map(|i| (i as f64, 0.)).collect::<Vec<_>>().into();
+        let mls = MultiLineString(vec![ls.clone(), straight]);
+        let simplified = mls.simplifyvw_to_count(42);
+        assert_eq!(simplified.0[0], ls);
+        assert_eq!(simplified.0[1].0.len(), 2);
+    }
+
+    #[test]
+    fn polygon_to_count_test() {
+        let square = LineString::from(vec![
+            (0., 0.),
+            (5., 0.1),
+            (10., 0.),
+            (10., 10.),
+            (5., 9.8),
+            (0., 10.),
+            (0., 0.),
+        ]);
+        let hole = LineString::from(vec![(4., 4.), (6., 4.), (5., 6.), (4., 4.)]);
+        let poly = Polygon::new(square, vec![hole.clone()]);
+        let simplified = poly.simplifyvw_to_count(9);
+        assert_eq!(
+            simplified.exterior,
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)])
+        );
+        // rings keep four points
+        let simplified = poly.simplifyvw_to_count(0);
+        assert_eq!(simplified.exterior.0.len(), 4);
+        assert_eq!(simplified.interiors, vec![hole.clone()]);
+        let mpoly = MultiPolygon(vec![poly.clone(), poly.clone()]);
+        // half the points would leave some rings with fewer than four
+        let simplified = mpoly.simplifyvw_to_percentage(50.);
+        assert_eq!(
+            simplified
+                .0
+                .iter()
+                .map(|p| p.exterior.0.len() + p.interiors[0].0.len())
+                .sum::<usize>(),
+            16
+        );
+    }
 }
@@ -0,0 +1,349 @@
+use algorithm::boundingbox::BoundingBox;
+use algorithm::euclidean_distance::EuclideanDistance;
+use num_traits::Float;
+use spade::rtree::RTree;
+use spade::{BoundingRect, SpadeFloat, SpatialObject};
+use {Bbox, Geometry, Point};
+
+/// A geometry stored in a [`SpatialIndex`](struct.SpatialIndex.html), along with its payload
+#[derive(PartialEq, Clone, Debug)]
+pub struct IndexedGeometry<T, P>
+where
+    T: Float,
+{
+    geometry: Geometry<T>,
+    payload: P,
+    bbox: Option<Bbox<T>>,
+}
+
+impl<T, P> IndexedGeometry<T, P>
+where
+    T: Float,
+{
+    fn new(geometry: Geometry<T>, payload: P) -> IndexedGeometry<T, P> {
+        let bbox = geometry.bbox();
+        IndexedGeometry {
+            geometry,
+            payload,
+            bbox,
+        }
+    }
+
+    /// The indexed geometry
+    pub fn geometry(&self) -> &Geometry<T> {
+        &self.geometry
+    }
+
+    /// The payload stored alongside the geometry
+    pub fn payload(&self) -> &P {
+        &self.payload
+    }
+
+    /// The bounding box of the geometry, or `None` if it has no coordinates
+    pub fn bbox(&self) -> Option<Bbox<T>> {
+        self.bbox
+    }
+
+    /// The minimum Euclidean distance from the geometry to a `Point`
+    pub fn distance(&self, point: &Point<T>) -> T {
+        self.geometry.euclidean_distance(point)
+    }
+}
+
+impl<T, P> SpatialObject for IndexedGeometry<T, P>
+where
+    T: Float + SpadeFloat,
+{
+    type Point = Point<T>;
+
+    fn mbr(&self) -> BoundingRect<Point<T>> {
+        // empty geometries are never stored in the tree
+        let bbox = self.bbox.unwrap();
+        BoundingRect::from_corners(
+            &Point::new(bbox.xmin, bbox.ymin),
+            &Point::new(bbox.xmax, bbox.ymax),
+        )
+    }
+
+    fn distance2(&self, point: &Point<T>) -> T {
+        let d = self.distance(point);
+        d * d
+    }
+}
+
+/// An [R* tree](../../../spade/rtree/struct.RTree.html) of geometries and their payloads.
+///
+/// Geometries are indexed by their bounding boxes, and nearest-neighbour and distance queries
+/// are answered using the true `EuclideanDistance` from each geometry. Geometries with no
+/// coordinates can be stored, but are only returned by [`iter`](#method.iter).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Bbox, Geometry, LineString, Point};
+/// use geo::algorithm::spatial_index::SpatialIndex;
+///
+/// let index = SpatialIndex::bulk_load(vec![
+///     (Geometry::Point(Point::new(0., 0.)), "origin"),
+///     (Geometry::LineString(LineString::from(vec![(2., 0.), (2., 4.)])), "fence"),
+///     (Geometry::Point(Point::new(5., 5.)), "tree"),
+/// ]);
+///
+/// let nearest = index.nearest(&Point::new(1.8, 3.)).unwrap();
+/// assert_eq!(*nearest.payload(), "fence");
+///
+/// let found = index.query_envelope(&Bbox {
+///     xmin: 4.,
+///     xmax: 6.,
+///     ymin: 4.,
+///     ymax: 6.,
+/// });
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(*found[0].payload(), "tree");
+/// ```
+pub struct SpatialIndex<T, P>
+where
+    T: Float + SpadeFloat,
+{
+    tree: RTree<IndexedGeometry<T, P>>,
+    empty: Vec<IndexedGeometry<T, P>>,
+}
+
+impl<T, P> SpatialIndex<T, P>
+where
+    T: Float + SpadeFloat,
+{
+    /// Creates an empty index.
+    pub fn new() -> SpatialIndex<T, P> {
+        SpatialIndex {
+            tree: RTree::new(),
+            empty: vec![],
+        }
+    }
+
+    /// Builds an index from geometries and their payloads all at once.
+    ///
+    /// This is quicker than inserting them one at a time, and yields a better-balanced tree.
+    pub fn bulk_load<G, I>(entries: I) -> SpatialIndex<T, P>
+    where
+        P: Clone,
+        G: Into<Geometry<T>>,
+        I: IntoIterator<Item = (G, P)>,
+    {
+        let (tree, empty): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .map(|(geometry, payload)| IndexedGeometry::new(geometry.into(), payload))
+            .partition(|entry| entry.bbox.is_some());
+        SpatialIndex {
+            tree: RTree::bulk_load(tree),
+            empty,
+        }
+    }
+
+    /// The number of geometries in the index.
+    pub fn len(&self) -> usize {
+        self.tree.size() + self.empty.len()
+    }
+
+    /// Returns `true` if the index holds no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a geometry and its payload to the index.
+    pub fn insert<G>(&mut self, geometry: G, payload: P)
+    where
+        G: Into<Geometry<T>>,
+    {
+        let entry = IndexedGeometry::new(geometry.into(), payload);
+        if entry.bbox.is_some() {
+            self.tree.insert(entry);
+        } else {
+            self.empty.push(entry);
+        }
+    }
+
+    /// Removes one entry equal to the given geometry and payload, returning `true` if there
+    /// was one.
+    pub fn remove<G>(&mut self, geometry: G, payload: P) -> bool
+    where
+        G: Into<Geometry<T>>,
+        P: PartialEq,
+    {
+        let entry = IndexedGeometry::new(geometry.into(), payload);
+        if entry.bbox.is_some() {
+            return self.tree.remove(&entry);
+        }
+        match self.empty.iter().position(|e| *e == entry) {
+            Some(i) => {
+                self.empty.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over every entry in the index, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &IndexedGeometry<T, P>> {
+        self.tree.iter().chain(self.empty.iter())
+    }
+
+    /// Returns the entries whose bounding boxes intersect `envelope`.
+    pub fn query_envelope(&self, envelope: &Bbox<T>) -> Vec<&IndexedGeometry<T, P>> {
+        self.tree.lookup_in_rectangle(&BoundingRect::from_corners(
+            &Point::new(envelope.xmin, envelope.ymin),
+            &Point::new(envelope.xmax, envelope.ymax),
+        ))
+    }
+
+    /// Returns the entry whose geometry is closest to `point`, or `None` if there are no
+    /// non-empty geometries in the index.
+    pub fn nearest(&self, point: &Point<T>) -> Option<&IndexedGeometry<T, P>> {
+        self.tree.nearest_neighbor(point)
+    }
+
+    /// Returns up to `k` entries, in increasing order of the distance from their geometries to
+    /// `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::spatial_index::SpatialIndex;
+    ///
+    /// let index = SpatialIndex::bulk_load((0..10).map(|i| (Point::new(i as f64, 0.), i)));
+    /// let nearest: Vec<i32> = index
+    ///     .nearest_k(&Point::new(6.8, 1.), 3)
+    ///     .iter()
+    ///     .map(|entry| *entry.payload())
+    ///     .collect();
+    /// assert_eq!(nearest, vec![7, 6, 8]);
+    /// ```
+    pub fn nearest_k(&self, point: &Point<T>, k: usize) -> Vec<&IndexedGeometry<T, P>> {
+        self.tree.nearest_n_neighbors(point, k)
+    }
+
+    /// Returns the entries whose geometries lie within `distance` of `point`.
+    pub fn within_distance(&self, point: &Point<T>, distance: T) -> Vec<&IndexedGeometry<T, P>> {
+        let envelope = Bbox {
+            xmin: point.x() - distance,
+            xmax: point.x() + distance,
+            ymin: point.y() - distance,
+            ymax: point.y() + distance,
+        };
+        self.query_envelope(&envelope)
+            .into_iter()
+            .filter(|entry| entry.distance(point) <= distance)
+            .collect()
+    }
+}
+
+impl<T, P> Default for SpatialIndex<T, P>
+where
+    T: Float + SpadeFloat,
+{
+    fn default() -> SpatialIndex<T, P> {
+        SpatialIndex::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {GeometryCollection, LineString, MultiPoint, Polygon};
+
+    fn square(x: f64, y: f64) -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + 1., y),
+                (x + 1., y + 1.),
+                (x, y + 1.),
+                (x, y),
+            ]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn insert_remove_test() {
+        let mut index = SpatialIndex::new();
+        assert!(index.is_empty());
+        index.insert(square(0., 0.), 1);
+        index.insert(square(2., 0.), 2);
+        index.insert(MultiPoint::<f64>(vec![]), 3);
+        assert_eq!(index.len(), 3);
+        assert!(!index.remove(square(0., 0.), 2));
+        assert!(index.remove(square(0., 0.), 1));
+        assert!(index.remove(MultiPoint(vec![]), 3));
+        assert_eq!(index.len(), 1);
+        let payloads: Vec<_> = index.iter().map(|e| *e.payload()).collect();
+        assert_eq!(payloads, vec![2]);
+        assert_eq!(index.nearest(&Point::new(0., 0.)).unwrap().payload(), &2);
+    }
+
+    #[test]
+    fn nearest_uses_true_distance_test() {
+        // the diagonal line's bounding box contains the query point, but the square is nearer
+        let index = SpatialIndex::bulk_load(vec![
+            (
+                Geometry::LineString(LineString::from(vec![(0., 0.), (10., 10.)])),
+                "line",
+            ),
+            (Geometry::Polygon(square(8., 0.)), "square"),
+        ]);
+        let point = Point::new(8.5, 0.5);
+        assert_eq!(*index.nearest(&point).unwrap().payload(), "square");
+        let nearest = index.nearest_k(&point, 5);
+        assert_eq!(nearest.len(), 2);
+        assert_eq!(*nearest[0].payload(), "square");
+        assert_relative_eq!(nearest[0].distance(&point), 0.);
+        assert_relative_eq!(nearest[1].distance(&point), 8. / 2f64.sqrt());
+    }
+
+    #[test]
+    fn within_distance_test() {
+        let index = SpatialIndex::bulk_load((0..5).map(|i| (square(2. * i as f64, 0.), i)));
+        let mut found: Vec<_> = index
+            .within_distance(&Point::new(4.5, 2.), 1.5)
+            .iter()
+            .map(|e| *e.payload())
+            .collect();
+        found.sort();
+        // squares 1 and 3 are about 1.8 from the point, at their nearest corners
+        assert_eq!(found, vec![2]);
+        let mut found: Vec<_> = index
+            .within_distance(&Point::new(4.5, 2.), 1.85)
+            .iter()
+            .map(|e| *e.payload())
+            .collect();
+        found.sort();
+        assert_eq!(found, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn geometry_collection_test() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::Point(Point::new(4., 4.)),
+        ]);
+        let index = SpatialIndex::bulk_load(vec![(Geometry::GeometryCollection(collection), ())]);
+        let found = index.query_envelope(&Bbox {
+            xmin: 1.,
+            xmax: 2.,
+            ymin: 1.,
+            ymax: 2.,
+        });
+        assert_eq!(found.len(), 1);
+        assert_relative_eq!(found[0].distance(&Point::new(3., 4.)), 1.);
+        assert_eq!(
+            found[0].bbox(),
+            Some(Bbox {
+                xmin: 0.,
+                xmax: 4.,
+                ymin: 0.,
+                ymax: 4.,
+            })
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::intersects::Intersects;
+use num_traits::Float;
+#[cfg(feature = "use-rayon")]
+use rayon::prelude::*;
+use spade::rtree::RTree;
+use spade::{BoundingRect, SpadeFloat, SpatialObject};
+use {Bbox, Point};
+
+/// A condition on which pairs of geometries are joined by [`spatial_join`](fn.spatial_join.html)
+pub trait JoinPredicate<T, A, B>
+where
+    T: Float,
+{
+    /// How far apart the bounding boxes of a matching pair may be
+    fn margin(&self) -> T;
+
+    /// Checks whether `a` and `b` should be joined
+    fn matches(&self, a: &A, b: &B) -> bool;
+}
+
+/// Joins pairs which intersect, using the `Intersects` trait
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Intersecting;
+
+impl<T, A, B> JoinPredicate<T, A, B> for Intersecting
+where
+    T: Float,
+    A: Intersects<B>,
+{
+    fn margin(&self) -> T {
+        T::zero()
+    }
+
+    fn matches(&self, a: &A, b: &B) -> bool {
+        a.intersects(b)
+    }
+}
+
+/// Joins pairs where the first geometry contains the second, using the `Contains` trait
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Containing;
+
+impl<T, A, B> JoinPredicate<T, A, B> for Containing
+where
+    T: Float,
+    A: Contains<B>,
+{
+    fn margin(&self) -> T {
+        T::zero()
+    }
+
+    fn matches(&self, a: &A, b: &B) -> bool {
+        a.contains(b)
+    }
+}
+
+/// Joins pairs no further apart than the given `EuclideanDistance`
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct WithinDistance<T>(pub T);
+
+impl<T, A, B> JoinPredicate<T, A, B> for WithinDistance<T>
+where
+    T: Float,
+    A: EuclideanDistance<T, B>,
+{
+    fn margin(&self) -> T {
+        self.0
+    }
+
+    fn matches(&self, a: &A, b: &B) -> bool {
+        a.euclidean_distance(b) <= self.0
+    }
+}
+
+/// Finds every pair of geometries, one from each slice, satisfying a predicate.
+///
+/// Returns the `(left, right)` indices of each matching pair, sorted. The bounding boxes of
+/// the shorter slice are loaded into an [R* tree](../../../spade/rtree/struct.RTree.html), so
+/// the predicate is only evaluated for pairs whose bounding boxes lie within its
+/// [`margin`](trait.JoinPredicate.html#tymethod.margin) of each other. Geometries without a
+/// bounding box never match.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{LineString, Point, Polygon};
+/// use geo::algorithm::spatial_join::{spatial_join, Containing, WithinDistance};
+///
+/// let square = |x: f64| {
+///     Polygon::new(
+///         LineString::from(vec![(x, 0.), (x + 1., 0.), (x + 1., 1.), (x, 1.), (x, 0.)]),
+///         vec![],
+///     )
+/// };
+/// let tracts = vec![square(0.), square(2.)];
+/// let events = vec![
+///     Point::new(2.5, 0.5),
+///     Point::new(1.5, 0.5),
+///     Point::new(0.5, 0.5),
+///     Point::new(2.1, 0.2),
+/// ];
+///
+/// let joined = spatial_join(&tracts, &events, &Containing);
+/// assert_eq!(joined, vec![(0, 2), (1, 0), (1, 3)]);
+///
+/// let nearby = spatial_join(&tracts, &events[1..2], &WithinDistance(0.5));
+/// assert_eq!(nearby, vec![(0, 0), (1, 0)]);
+/// ```
+pub fn spatial_join<T, A, B, P>(left: &[A], right: &[B], predicate: &P) -> Vec<(usize, usize)>
+where
+    T: Float + SpadeFloat,
+    A: BoundingBox<T>,
+    A::Output: Into<Option<Bbox<T>>>,
+    B: BoundingBox<T>,
+    B::Output: Into<Option<Bbox<T>>>,
+    P: JoinPredicate<T, A, B>,
+{
+    let join = Join::new(left, right, predicate.margin());
+    let mut pairs: Vec<(usize, usize)> = (0..join.queries.len())
+        .flat_map(|query| join.candidates(query))
+        .filter(|&(a, b)| predicate.matches(&left[a], &right[b]))
+        .collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// A parallel version of [`spatial_join`](fn.spatial_join.html), using
+/// [rayon](https://docs.rs/rayon).
+///
+/// The results are the same as those of `spatial_join`. This requires the `use-rayon`
+/// feature.
+#[cfg(feature = "use-rayon")]
+pub fn par_spatial_join<T, A, B, P>(left: &[A], right: &[B], predicate: &P) -> Vec<(usize, usize)>
+where
+    T: Float + SpadeFloat + Send + Sync,
+    A: BoundingBox<T> + Sync,
+    A::Output: Into<Option<Bbox<T>>>,
+    B: BoundingBox<T> + Sync,
+    B::Output: Into<Option<Bbox<T>>>,
+    P: JoinPredicate<T, A, B> + Sync,
+{
+    let join = Join::new(left, right, predicate.margin());
+    let mut pairs: Vec<(usize, usize)> = (0..join.queries.len())
+        .into_par_iter()
+        .flat_map(|query| join.candidates(query))
+        .filter(|&(a, b)| predicate.matches(&left[a], &right[b]))
+        .collect();
+    pairs.par_sort_unstable();
+    pairs
+}
+
+/// The bounding box of a geometry, and its index in its slice
+#[derive(Clone)]
+struct Envelope<T>
+where
+    T: Float,
+{
+    bbox: Bbox<T>,
+    index: usize,
+}
+
+impl<T> SpatialObject for Envelope<T>
+where
+    T: Float + SpadeFloat,
+{
+    type Point = Point<T>;
+
+    fn mbr(&self) -> BoundingRect<Point<T>> {
+        BoundingRect::from_corners(
+            &Point::new(self.bbox.xmin, self.bbox.ymin),
+            &Point::new(self.bbox.xmax, self.bbox.ymax),
+        )
+    }
+
+    fn distance2(&self, point: &Point<T>) -> T {
+        self.mbr().min_dist2(point)
+    }
+}
+
+/// The candidate pairs of a spatial join: an R* tree of the bounding boxes from one slice,
+/// to be queried with those of the other
+struct Join<T>
+where
+    T: Float + SpadeFloat,
+{
+    tree: RTree<Envelope<T>>,
+    queries: Vec<Option<Bbox<T>>>,
+    /// Whether the tree holds the left slice, and the queries come from the right
+    flipped: bool,
+    margin: T,
+}
+
+impl<T> Join<T>
+where
+    T: Float + SpadeFloat,
+{
+    fn new<A, B>(left: &[A], right: &[B], margin: T) -> Join<T>
+    where
+        A: BoundingBox<T>,
+        A::Output: Into<Option<Bbox<T>>>,
+        B: BoundingBox<T>,
+        B::Output: Into<Option<Bbox<T>>>,
+    {
+        let flipped = left.len() < right.len();
+        let (indexed, queries) = if flipped {
+            (bboxes(left), bboxes(right))
+        } else {
+            (bboxes(right), bboxes(left))
+        };
+        let envelopes = indexed
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, bbox)| bbox.map(|bbox| Envelope { bbox, index }))
+            .collect();
+        Join {
+            tree: RTree::bulk_load(envelopes),
+            queries,
+            flipped,
+            margin,
+        }
+    }
+
+    /// The `(left, right)` pairs whose bounding boxes are within `margin` of those of the
+    /// query geometry
+    fn candidates(&self, query: usize) -> Vec<(usize, usize)> {
+        let bbox = match self.queries[query] {
+            Some(bbox) => bbox,
+            None => return vec![],
+        };
+        let rect = BoundingRect::from_corners(
+            &Point::new(bbox.xmin - self.margin, bbox.ymin - self.margin),
+            &Point::new(bbox.xmax + self.margin, bbox.ymax + self.margin),
+        );
+        self.tree
+            .lookup_in_rectangle(&rect)
+            .into_iter()
+            .map(|envelope| {
+                if self.flipped {
+                    (envelope.index, query)
+                } else {
+                    (query, envelope.index)
+                }
+            })
+            .collect()
+    }
+}
+
+fn bboxes<T, G>(geometries: &[G]) -> Vec<Option<Bbox<T>>>
+where
+    T: Float,
+    G: BoundingBox<T>,
+    G::Output: Into<Option<Bbox<T>>>,
+{
+    geometries.iter().map(|g| g.bbox().into()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::prepared_geometry::PreparedGeometry;
+    use {Line, LineString, Polygon};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]),
+            vec![],
+        )
+    }
+
+    fn brute_force<A, B, P>(left: &[A], right: &[B], predicate: &P) -> Vec<(usize, usize)>
+    where
+        P: JoinPredicate<f64, A, B>,
+    {
+        let mut pairs = vec![];
+        for (i, a) in left.iter().enumerate() {
+            for (j, b) in right.iter().enumerate() {
+                if predicate.matches(a, b) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn points() -> Vec<Point<f64>> {
+        (0..400)
+            .map(|i| Point::new((i % 20) as f64 * 0.55, (i / 20) as f64 * 0.45))
+            .collect()
+    }
+
+    fn squares() -> Vec<Polygon<f64>> {
+        (0..16)
+            .map(|i| square((i % 4) as f64 * 2.5, (i / 4) as f64 * 2.5, 2.))
+            .collect()
+    }
+
+    #[test]
+    fn containing_test() {
+        let (squares, points) = (squares(), points());
+        let joined = spatial_join(&squares, &points, &Containing);
+        assert!(!joined.is_empty());
+        assert_eq!(joined, brute_force(&squares, &points, &Containing));
+    }
+
+    #[test]
+    fn prepared_test() {
+        let (squares, points) = (squares(), points());
+        let prepared: Vec<_> = squares.iter().map(PreparedGeometry::from).collect();
+        assert_eq!(
+            spatial_join(&prepared, &points, &Containing),
+            spatial_join(&squares, &points, &Containing)
+        );
+    }
+
+    #[test]
+    fn within_distance_test() {
+        let (squares, points) = (squares(), points());
+        let predicate = WithinDistance(0.3);
+        let joined = spatial_join(&points, &squares, &predicate);
+        assert_eq!(joined, brute_force(&points, &squares, &predicate));
+        // points in the gaps between squares are near more than one of them
+        assert!(joined.len() > points.len());
+    }
+
+    #[test]
+    fn intersecting_test() {
+        let lines: Vec<_> = (0..30)
+            .map(|i| {
+                let x = i as f64 * 0.37;
+                Line::from([(x, 0.), (10. - x, 10.)])
+            })
+            .collect();
+        let squares = squares();
+        let joined = spatial_join(&lines, &squares, &Intersecting);
+        assert_eq!(joined, brute_force(&lines, &squares, &Intersecting));
+        let joined = spatial_join(&lines, &lines[..3], &Intersecting);
+        assert_eq!(joined, brute_force(&lines, &lines[..3], &Intersecting));
+    }
+
+    #[cfg(feature = "use-rayon")]
+    #[test]
+    fn parallel_test() {
+        let (squares, points) = (squares(), points());
+        assert_eq!(
+            par_spatial_join(&points, &squares, &WithinDistance(0.3)),
+            spatial_join(&points, &squares, &WithinDistance(0.3))
+        );
+        assert_eq!(
+            par_spatial_join(&squares, &points, &Containing),
+            spatial_join(&squares, &points, &Containing)
+        );
+    }
+}
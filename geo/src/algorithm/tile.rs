@@ -0,0 +1,352 @@
+use std::f64::consts::PI;
+
+use algorithm::intersects::Intersects;
+use num_traits::Float;
+use {Bbox, Polygon};
+
+/// The radius of the sphere used by Web Mercator, in meters
+const MERCATOR_RADIUS: f64 = 6_378_137.0;
+
+/// The furthest latitude from the equator that Web Mercator tiles reach
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The deepest zoom level a `TileId` can have, at which a tile's column and row still fit in
+/// a `u32`
+pub const MAX_ZOOM: u8 = 31;
+
+/// A slippy map tile: its column, its row counting down from the north, and its zoom level.
+///
+/// At zoom level `z`, the world is covered by 2<sup>z</sup> by 2<sup>z</sup> tiles, in the
+/// Web Mercator projection. Zoom levels go up to [`MAX_ZOOM`](constant.MAX_ZOOM.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::tile::TileId;
+///
+/// let tile = TileId::from_lon_lat(-9., 53.2, 10).unwrap();
+/// assert_eq!(tile, TileId::new(486, 332, 10).unwrap());
+/// assert_eq!(tile.quadkey(), "0313102310");
+/// assert_eq!(TileId::from_quadkey("0313102310"), Some(tile));
+///
+/// let bounds = tile.bounds::<f64>();
+/// assert_eq!((bounds.xmin, bounds.xmax), (-9.140625, -8.7890625));
+/// assert_eq!(tile.parent(), TileId::new(243, 166, 9));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct TileId {
+    x: u32,
+    y: u32,
+    z: u8,
+}
+
+impl TileId {
+    /// The tile in column `x` and row `y` at zoom level `z`, or `None` if `z` is deeper than
+    /// `MAX_ZOOM`, or the column or row is beyond the edge of the map
+    pub fn new(x: u32, y: u32, z: u8) -> Option<TileId> {
+        if z > MAX_ZOOM || u64::from(x) >> z != 0 || u64::from(y) >> z != 0 {
+            return None;
+        }
+        Some(TileId { x, y, z })
+    }
+
+    /// The tile at zoom level `z` containing a longitude and latitude, in degrees, or `None`
+    /// if `z` is deeper than `MAX_ZOOM`.
+    ///
+    /// Points beyond the edges of the map are given the nearest tile.
+    pub fn from_lon_lat<T>(lon: T, lat: T, z: u8) -> Option<TileId>
+    where
+        T: Float,
+    {
+        if z > MAX_ZOOM {
+            return None;
+        }
+        let (lon, lat) = (lon.to_f64().unwrap(), lat.to_f64().unwrap());
+        let n = f64::from(1u32 << z);
+        let lat = if lat.abs() > MAX_LATITUDE {
+            MAX_LATITUDE * lat.signum()
+        } else {
+            lat
+        }
+        .to_radians();
+        let x = (lon + 180.) / 360. * n;
+        let y = (1. - (lat.tan() + 1. / lat.cos()).ln() / PI) / 2. * n;
+        let clamp = |v: f64| v.floor().max(0.).min(n - 1.) as u32;
+        TileId::new(clamp(x), clamp(y), z)
+    }
+
+    /// The tile's column, counting east from the antimeridian
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// The tile's row, counting south from the top of the map
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// The tile's zoom level
+    pub fn z(&self) -> u8 {
+        self.z
+    }
+
+    /// The tile's bounds, as longitudes and latitudes in degrees
+    pub fn bounds<T>(&self) -> Bbox<T>
+    where
+        T: Float,
+    {
+        let n = f64::from(1u32 << self.z);
+        let lon = |x: u32| T::from(f64::from(x) / n * 360. - 180.).unwrap();
+        let lat = |y: u32| {
+            T::from(
+                (PI * (1. - 2. * f64::from(y) / n))
+                    .sinh()
+                    .atan()
+                    .to_degrees(),
+            )
+            .unwrap()
+        };
+        Bbox {
+            xmin: lon(self.x),
+            xmax: lon(self.x + 1),
+            ymin: lat(self.y + 1),
+            ymax: lat(self.y),
+        }
+    }
+
+    /// The tile's bounds in Web Mercator (EPSG:3857) coordinates, in meters
+    pub fn mercator_bounds<T>(&self) -> Bbox<T>
+    where
+        T: Float,
+    {
+        let half = PI * MERCATOR_RADIUS;
+        let size = 2. * half / f64::from(1u32 << self.z);
+        let at = |i: u32| f64::from(i) * size;
+        Bbox {
+            xmin: T::from(at(self.x) - half).unwrap(),
+            xmax: T::from(at(self.x + 1) - half).unwrap(),
+            ymin: T::from(half - at(self.y + 1)).unwrap(),
+            ymax: T::from(half - at(self.y)).unwrap(),
+        }
+    }
+
+    /// The tile's quadkey: one digit for each zoom level, as used by Bing Maps
+    pub fn quadkey(&self) -> String {
+        (1..=self.z)
+            .rev()
+            .map(|i| {
+                let mask = 1 << (i - 1);
+                let digit = ((self.x & mask) != 0) as u8 + 2 * ((self.y & mask) != 0) as u8;
+                (b'0' + digit) as char
+            })
+            .collect()
+    }
+
+    /// The tile with the given quadkey, or `None` if it contains anything but the digits 0
+    /// to 3, or is longer than `MAX_ZOOM`
+    pub fn from_quadkey(quadkey: &str) -> Option<TileId> {
+        if quadkey.len() > usize::from(MAX_ZOOM) {
+            return None;
+        }
+        let mut tile = TileId { x: 0, y: 0, z: 0 };
+        for digit in quadkey.chars() {
+            let digit = digit.to_digit(4)?;
+            tile = TileId {
+                x: 2 * tile.x + (digit & 1),
+                y: 2 * tile.y + (digit >> 1),
+                z: tile.z + 1,
+            };
+        }
+        Some(tile)
+    }
+
+    /// The tile one zoom level out which contains this one, or `None` at zoom level 0
+    pub fn parent(&self) -> Option<TileId> {
+        if self.z == 0 {
+            return None;
+        }
+        Some(TileId {
+            x: self.x / 2,
+            y: self.y / 2,
+            z: self.z - 1,
+        })
+    }
+
+    /// The four tiles one zoom level in which make up this one: the top left, top right,
+    /// bottom right, and bottom left. Returns `None` at `MAX_ZOOM`.
+    pub fn children(&self) -> Option<[TileId; 4]> {
+        if self.z >= MAX_ZOOM {
+            return None;
+        }
+        let (x, y, z) = (2 * self.x, 2 * self.y, self.z + 1);
+        Some([
+            TileId { x, y, z },
+            TileId { x: x + 1, y, z },
+            TileId {
+                x: x + 1,
+                y: y + 1,
+                z,
+            },
+            TileId { x, y: y + 1, z },
+        ])
+    }
+}
+
+/// Lists the slippy map tiles which a geometry touches.
+pub trait TileCover<T>
+where
+    T: Float,
+{
+    /// Returns every tile at zoom level `z` which the geometry intersects, in order.
+    ///
+    /// Coordinates are longitudes and latitudes in degrees. Each tile is checked against the
+    /// geometry itself, rather than its bounding box, so a diagonal line only covers the tiles
+    /// it passes through. Tiles include their edges, so a geometry which only touches a tile
+    /// covers it. Parts of a geometry nearer the poles than about 85.05° are in no tile. There
+    /// are no tiles deeper than `MAX_ZOOM`, so none are returned for such a `z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::tile::{TileCover, TileId};
+    ///
+    /// // a diagonal line across the four tiles at zoom level 1
+    /// let line = LineString::from(vec![(-90., -45.), (-1., 44.), (1., 44.)]);
+    ///
+    /// let tile = |x, y| TileId::new(x, y, 1).unwrap();
+    /// assert_eq!(line.tile_cover(1), vec![tile(0, 0), tile(0, 1), tile(1, 0)]);
+    /// ```
+    fn tile_cover(&self, z: u8) -> Vec<TileId>;
+}
+
+impl<T, G> TileCover<T> for G
+where
+    T: Float,
+    G: Intersects<Polygon<T>>,
+{
+    fn tile_cover(&self, z: u8) -> Vec<TileId> {
+        let mut tiles = vec![];
+        if z <= MAX_ZOOM {
+            cover(self, TileId { x: 0, y: 0, z: 0 }, z, &mut tiles);
+        }
+        tiles.sort();
+        tiles
+    }
+}
+
+/// Adds the tiles at zoom level `z` within `tile` which `geometry` intersects
+fn cover<T, G>(geometry: &G, tile: TileId, z: u8, tiles: &mut Vec<TileId>)
+where
+    T: Float,
+    G: Intersects<Polygon<T>>,
+{
+    if !geometry.intersects(&tile.bounds().to_polygon()) {
+        return;
+    }
+    match tile.children() {
+        Some(children) if tile.z < z => {
+            for &child in &children {
+                cover(geometry, child, z, tiles);
+            }
+        }
+        _ => tiles.push(tile),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Geometry, LineString, MultiPoint, Point};
+
+    fn tile(x: u32, y: u32, z: u8) -> TileId {
+        TileId::new(x, y, z).unwrap()
+    }
+
+    #[test]
+    fn lon_lat_test() {
+        assert_eq!(
+            TileId::from_lon_lat(20.6852, 40.1222, 9),
+            Some(tile(285, 193, 9))
+        );
+        assert_eq!(TileId::from_lon_lat(0., 0., 0), Some(tile(0, 0, 0)));
+        // beyond the edges of the map
+        assert_eq!(TileId::from_lon_lat(180., -90., 2), Some(tile(3, 3, 2)));
+        assert_eq!(TileId::from_lon_lat(-200., 90., 2), Some(tile(0, 0, 2)));
+
+        let bounds = tile(486, 332, 10).bounds::<f64>();
+        assert_relative_eq!(bounds.ymin, 53.120405283106564, epsilon = 1e-12);
+        assert_relative_eq!(bounds.ymax, 53.33087298301705, epsilon = 1e-12);
+        let world = tile(0, 0, 0).bounds::<f64>();
+        assert_relative_eq!(world.ymax, MAX_LATITUDE, epsilon = 1e-12);
+        assert_relative_eq!(world.ymin, -MAX_LATITUDE, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn max_zoom_test() {
+        let last = (1 << MAX_ZOOM) - 1;
+        let corner = tile(last, last, MAX_ZOOM);
+        assert_eq!(corner.bounds::<f64>().xmax, 180.);
+        assert_eq!(corner.children(), None);
+        assert_eq!(TileId::from_lon_lat(180., -90., MAX_ZOOM), Some(corner));
+        assert_eq!(TileId::from_lon_lat(0., 0., MAX_ZOOM + 1), None);
+        assert_eq!(TileId::new(0, 0, 255), None);
+        // beyond the edge of the map
+        assert_eq!(TileId::new(4, 0, 2), None);
+        assert_eq!(Point::new(0., 0.).tile_cover(MAX_ZOOM + 1), vec![]);
+    }
+
+    #[test]
+    fn mercator_bounds_test() {
+        let bounds = tile(486, 332, 10).mercator_bounds::<f64>();
+        assert_relative_eq!(bounds.xmin, -1017529.7205322646, epsilon = 1e-6);
+        assert_relative_eq!(bounds.xmax, -978393.9620502554, epsilon = 1e-6);
+        assert_relative_eq!(bounds.ymin, 7005300.768279834, epsilon = 1e-6);
+        assert_relative_eq!(bounds.ymax, 7044436.526761843, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn quadkey_test() {
+        assert_eq!(tile(0, 0, 0).quadkey(), "");
+        assert_eq!(tile(3, 5, 3).quadkey(), "213");
+        assert_eq!(TileId::from_quadkey(""), Some(tile(0, 0, 0)));
+        assert_eq!(TileId::from_quadkey("213"), Some(tile(3, 5, 3)));
+        assert_eq!(TileId::from_quadkey("214"), None);
+    }
+
+    #[test]
+    fn parent_children_test() {
+        let parent = tile(3, 5, 3);
+        for child in &parent.children().unwrap() {
+            assert_eq!(child.parent(), Some(parent));
+            assert!(child.quadkey().starts_with(&parent.quadkey()));
+        }
+        assert_eq!(tile(0, 0, 0).parent(), None);
+    }
+
+    #[test]
+    fn cover_test() {
+        // a hollow square, whose hole contains whole tiles
+        let exterior = tile(0, 0, 2).bounds::<f64>();
+        let interior = tile(1, 1, 4).bounds::<f64>();
+        let shrink = |b: Bbox<f64>, by: f64| {
+            LineString::from(vec![
+                (b.xmin + by, b.ymin + by),
+                (b.xmax - by, b.ymin + by),
+                (b.xmax - by, b.ymax - by),
+                (b.xmin + by, b.ymax - by),
+                (b.xmin + by, b.ymin + by),
+            ])
+        };
+        let polygon = Polygon::new(shrink(exterior, 1.), vec![shrink(interior, -1.)]);
+        let cover = polygon.tile_cover(4);
+        assert_eq!(cover.len(), 4 * 4 - 1);
+        assert!(!cover.contains(&tile(1, 1, 4)));
+        assert!(cover.contains(&tile(0, 0, 4)));
+        assert!(cover.contains(&tile(3, 3, 4)));
+
+        let points: Geometry<f64> =
+            MultiPoint(vec![Point::new(-179., 84.), Point::new(179., -84.)]).into();
+        assert_eq!(points.tile_cover(4), vec![tile(0, 0, 4), tile(15, 15, 4)]);
+    }
+}
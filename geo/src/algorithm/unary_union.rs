@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+use algorithm::coordinate_key::{key, Key};
+use algorithm::interior_point::InteriorPoint;
+use algorithm::line_intersection::LineIntersection;
+use algorithm::polygonize::Polygonize;
+use algorithm::segment_intersections::{segment_intersections, Segments};
+use num_traits::Float;
+use {Bbox, Coordinate, Line, MultiPolygon, Polygon};
+
+/// How many geometries are unioned together at each level of the hierarchy
+const NODE_CAPACITY: usize = 4;
+
+/// Merges polygons into a single `MultiPolygon` covering the same area.
+///
+/// The inputs may be `Polygon`s or `MultiPolygon`s, which may overlap or touch each other, or
+/// themselves. Rather than merging them one at a time, which is slow for many inputs, they are
+/// grouped as the nodes of an R-tree, packed using the Sort-Tile-Recursive algorithm, so that
+/// nearby polygons are merged together first. The merged groups are then grouped and merged in
+/// turn, until only one is left. Polygons which cannot overlap any others in their group are
+/// passed up the tree untouched.
+///
+/// The result has its exteriors wound counter-clockwise, and its interiors clockwise. Where
+/// polygons touch or overlap, their boundaries are split at the points where they meet, so
+/// merged rings may contain collinear vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{LineString, Polygon};
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::unary_union::unary_union;
+///
+/// let square = |x: f64, y: f64| {
+///     Polygon::new(
+///         LineString::from(vec![(x, y), (x + 2., y), (x + 2., y + 2.), (x, y + 2.), (x, y)]),
+///         vec![],
+///     )
+/// };
+/// // two overlapping squares, and one on its own
+/// let union = unary_union(vec![square(0., 0.), square(1., 1.), square(5., 5.)]);
+///
+/// assert_eq!(union.0.len(), 2);
+/// assert_eq!(union.area(), 11.);
+/// ```
+pub fn unary_union<T, I, G>(geometries: I) -> MultiPolygon<T>
+where
+    T: Float,
+    I: IntoIterator<Item = G>,
+    G: Into<MultiPolygon<T>>,
+{
+    // each input polygon starts out on its own, as the parts of a `MultiPolygon` may overlap
+    let mut level: Vec<Group<T>> = geometries
+        .into_iter()
+        .flat_map(|geometry| geometry.into().0)
+        .filter_map(|polygon| Group::new(vec![polygon]))
+        .collect();
+    loop {
+        let groups = pack(level);
+        let last = groups.len() <= 1;
+        level = groups.into_iter().filter_map(union_groups).collect();
+        if last {
+            return MultiPolygon(level.into_iter().flat_map(|group| group.polygons).collect());
+        }
+    }
+}
+
+/// Polygons whose interiors don't overlap, and their bounding box
+struct Group<T>
+where
+    T: Float,
+{
+    polygons: Vec<Polygon<T>>,
+    bbox: Bbox<T>,
+}
+
+impl<T> Group<T>
+where
+    T: Float,
+{
+    fn new(polygons: Vec<Polygon<T>>) -> Option<Group<T>> {
+        let bbox = polygons.iter().filter_map(|polygon| polygon.bbox()).fold(
+            None,
+            |acc: Option<Bbox<T>>, bbox| {
+                Some(acc.map_or(bbox, |acc| Bbox {
+                    xmin: acc.xmin.min(bbox.xmin),
+                    xmax: acc.xmax.max(bbox.xmax),
+                    ymin: acc.ymin.min(bbox.ymin),
+                    ymax: acc.ymax.max(bbox.ymax),
+                }))
+            },
+        )?;
+        Some(Group { polygons, bbox })
+    }
+
+    fn centre(&self) -> Coordinate<T> {
+        let two = T::one() + T::one();
+        Coordinate {
+            x: (self.bbox.xmin + self.bbox.xmax) / two,
+            y: (self.bbox.ymin + self.bbox.ymax) / two,
+        }
+    }
+}
+
+fn overlaps<T>(a: &Bbox<T>, b: &Bbox<T>) -> bool
+where
+    T: Float,
+{
+    a.xmin <= b.xmax && b.xmin <= a.xmax && a.ymin <= b.ymax && b.ymin <= a.ymax
+}
+
+/// Sorts groups into the nodes of an R-tree, each holding up to `NODE_CAPACITY` of them
+fn pack<T>(mut groups: Vec<Group<T>>) -> Vec<Vec<Group<T>>>
+where
+    T: Float,
+{
+    let nodes = (groups.len() as f64 / NODE_CAPACITY as f64).ceil();
+    let slices = nodes.sqrt().ceil() as usize;
+    let cmp = |a: T, b: T| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+    groups.sort_by(|a, b| cmp(a.centre().x, b.centre().x));
+    let mut packed = vec![];
+    let mut groups = groups.into_iter();
+    loop {
+        let mut slice: Vec<Group<T>> = groups.by_ref().take(slices * NODE_CAPACITY).collect();
+        if slice.is_empty() {
+            return packed;
+        }
+        slice.sort_by(|a, b| cmp(a.centre().y, b.centre().y));
+        let mut slice = slice.into_iter();
+        loop {
+            let node: Vec<Group<T>> = slice.by_ref().take(NODE_CAPACITY).collect();
+            if node.is_empty() {
+                break;
+            }
+            packed.push(node);
+        }
+    }
+}
+
+/// Merges the polygons of several groups
+fn union_groups<T>(groups: Vec<Group<T>>) -> Option<Group<T>>
+where
+    T: Float,
+{
+    // polygons within a group don't overlap, so only those meeting another group's bounding
+    // box need merging
+    let bboxes: Vec<Bbox<T>> = groups.iter().map(|group| group.bbox).collect();
+    let mut untouched = vec![];
+    let mut merging = vec![];
+    for (i, group) in groups.into_iter().enumerate() {
+        for polygon in group.polygons {
+            let touches = match polygon.bbox() {
+                Some(bbox) => bboxes
+                    .iter()
+                    .enumerate()
+                    .any(|(j, other)| i != j && overlaps(&bbox, other)),
+                None => false,
+            };
+            if touches {
+                merging.push(polygon);
+            } else {
+                untouched.push(polygon);
+            }
+        }
+    }
+    untouched.extend(overlay(merging));
+    Group::new(untouched)
+}
+
+/// Splits a segment at the given points, which lie on it
+fn split<T>(segment: &Line<T>, mut points: Vec<Coordinate<T>>) -> Vec<Line<T>>
+where
+    T: Float,
+{
+    let (start, dx, dy) = (segment.start, segment.dx(), segment.dy());
+    let along = |c: &Coordinate<T>| (c.x - start.x) * dx + (c.y - start.y) * dy;
+    let length = along(&segment.end);
+    points.retain(|c| along(c) > T::zero() && along(c) < length);
+    points.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+    points.insert(0, segment.start);
+    points.push(segment.end);
+    points.dedup();
+    points
+        .windows(2)
+        .map(|pair| Line::new(pair[0], pair[1]))
+        .collect()
+}
+
+/// Merges polygons, which may overlap, into polygons which don't
+fn overlay<T>(polygons: Vec<Polygon<T>>) -> Vec<Polygon<T>>
+where
+    T: Float,
+{
+    if polygons.is_empty() {
+        return polygons;
+    }
+    // split the polygons' boundaries wherever they meet, and find the faces they divide the
+    // plane into
+    let segments: Vec<Line<T>> = polygons
+        .iter()
+        .flat_map(|polygon| polygon.segments())
+        .filter(|segment| segment.start != segment.end)
+        .collect();
+    let mut splits = vec![vec![]; segments.len()];
+    for found in segment_intersections(&segments) {
+        let points = match found.intersection {
+            LineIntersection::SinglePoint { intersection, .. } => vec![intersection.0],
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        splits[found.first].extend(points.iter().cloned());
+        splits[found.second].extend(points);
+    }
+    let edges: Vec<Line<T>> = segments
+        .iter()
+        .zip(splits)
+        .flat_map(|(segment, points)| split(segment, points))
+        .collect();
+    let faces = edges.polygonize().polygons;
+
+    // keep the faces inside any of the polygons, and drop the edges between them
+    let bboxes: Vec<Option<Bbox<T>>> = polygons.iter().map(|polygon| polygon.bbox()).collect();
+    let inside = |face: &Polygon<T>| match face.interior_point() {
+        Some(point) => {
+            let (x, y) = (point.x(), point.y());
+            let at = Bbox {
+                xmin: x,
+                xmax: x,
+                ymin: y,
+                ymax: y,
+            };
+            polygons
+                .iter()
+                .zip(&bboxes)
+                .any(|(polygon, bbox)| match *bbox {
+                    Some(ref bbox) => overlaps(bbox, &at) && polygon.contains(&point),
+                    None => false,
+                })
+        }
+        None => false,
+    };
+    let mut boundary: BTreeMap<(Key, Key), Line<T>> = BTreeMap::new();
+    for face in faces.0.iter().filter(|face| inside(face)) {
+        let rings = ::std::iter::once(&face.exterior).chain(face.interiors.iter());
+        for edge in rings.flat_map(|ring| ring.0.windows(2)) {
+            let (a, b) = (key(&edge[0]), key(&edge[1]));
+            let edge_key = if a < b { (a, b) } else { (b, a) };
+            if boundary.remove(&edge_key).is_none() {
+                boundary.insert(edge_key, Line::new(edge[0], edge[1]));
+            }
+        }
+    }
+    // the remaining edges also bound the holes of the merged polygons, as faces of their own
+    let boundary: Vec<Line<T>> = boundary.values().cloned().collect();
+    boundary
+        .polygonize()
+        .polygons
+        .0
+        .into_iter()
+        .filter(|face| inside(face))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use {LineString, Point};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]),
+            vec![],
+        )
+    }
+
+    fn ring_area(ring: &LineString<f64>) -> f64 {
+        Polygon::new(ring.clone(), vec![]).area().abs()
+    }
+
+    #[test]
+    fn overlapping_test() {
+        let union = unary_union(vec![square(0., 0., 2.), square(1., 0., 2.)]);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].interiors.len(), 0);
+        assert_eq!(ring_area(&union.0[0].exterior), 6.);
+        assert!(union.0[0].contains(&Point::new(2.5, 1.)));
+    }
+
+    #[test]
+    fn hole_test() {
+        // a ring of squares around a gap
+        let squares: Vec<Polygon<f64>> = (0..3)
+            .flat_map(|x| (0..3).map(move |y| (x, y)))
+            .filter(|&(x, y)| (x, y) != (1, 1))
+            .map(|(x, y)| square(x as f64, y as f64, 1.))
+            .collect();
+        let union = unary_union(squares);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].interiors.len(), 1);
+        assert_eq!(ring_area(&union.0[0].exterior), 9.);
+        assert_eq!(ring_area(&union.0[0].interiors[0]), 1.);
+        assert!(!union.0[0].contains(&Point::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn many_test() {
+        // a grid of overlapping squares, some in multipolygons, making two blocks
+        let mut geometries = vec![];
+        for x in 0..10 {
+            let mut column = vec![];
+            for y in 0..10 {
+                column.push(square(x as f64, y as f64, 1.5));
+                column.push(square(x as f64 + 20., y as f64, 1.5));
+            }
+            geometries.push(MultiPolygon(column));
+        }
+        let union = unary_union(geometries);
+        assert_eq!(union.0.len(), 2);
+        for polygon in &union.0 {
+            assert_eq!(polygon.interiors.len(), 0);
+            assert_eq!(ring_area(&polygon.exterior), 10.5 * 10.5);
+        }
+    }
+
+    #[test]
+    fn empty_test() {
+        let union = unary_union(Vec::<Polygon<f64>>::new());
+        assert_eq!(union, MultiPolygon(vec![]));
+    }
+}
@@ -1,3 +1,4 @@
+use algorithm::predicates::{orient2d, Orientation};
 use geo_types::line_string::PointsIter;
 use std::iter::Rev;
 use {CoordinateType, LineString, Point};
@@ -125,17 +126,56 @@ where
 {
     /// Returns the winding order of this line
     /// None if the winding order is undefined.
+    ///
+    /// The winding order is found from the orientation of the ring at its leftmost vertex,
+    /// taking the lowest of any tied for leftmost, which is always convex, so the result is
+    /// exact for simple rings. For a self-intersecting ring this gives the direction of the
+    /// loop through that vertex, which may differ from the sign of the ring's signed area.
     fn winding_order(&self) -> Option<WindingOrder> {
-        let shoelace = twice_signed_ring_area(self);
-        if shoelace < T::zero() {
-            Some(WindingOrder::Clockwise)
-        } else if shoelace > T::zero() {
-            Some(WindingOrder::CounterClockwise)
-        } else if shoelace == T::zero() {
-            None
-        } else {
-            // make compiler stop complaining
-            unreachable!()
+        // a closed ring repeats its first coordinate; treat it as a cycle
+        let ring = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) if self.0.len() > 1 && first == last => {
+                &self.0[..self.0.len() - 1]
+            }
+            _ => &self.0[..],
+        };
+        if ring.len() < 3 {
+            return None;
+        }
+
+        let mut extreme = 0;
+        for (i, c) in ring.iter().enumerate() {
+            let e = ring[extreme];
+            if c.x < e.x || (c.x == e.x && c.y < e.y) {
+                extreme = i;
+            }
+        }
+        let n = ring.len();
+        // the nearest vertices on either side which don't coincide with the extreme vertex
+        let prev = (1..n)
+            .map(|step| ring[(extreme + n - step) % n])
+            .find(|c| *c != ring[extreme]);
+        let next = (1..n)
+            .map(|step| ring[(extreme + step) % n])
+            .find(|c| *c != ring[extreme]);
+        let orientation = match (prev, next) {
+            (Some(prev), Some(next)) => orient2d(prev, ring[extreme], next),
+            _ => return None,
+        };
+        match orientation {
+            Orientation::CounterClockwise => Some(WindingOrder::CounterClockwise),
+            Orientation::Clockwise => Some(WindingOrder::Clockwise),
+            // the ring doubles back on itself at the extreme vertex; fall back to its area
+            Orientation::Collinear => {
+                let shoelace = twice_signed_ring_area(self);
+                if shoelace < T::zero() {
+                    Some(WindingOrder::Clockwise)
+                } else if shoelace > T::zero() {
+                    Some(WindingOrder::CounterClockwise)
+                } else {
+                    None
+                }
+            }
         }
     }
 
@@ -243,4 +283,28 @@ mod test {
         assert_ne!(new_line2, cw_line);
         assert_eq!(new_line2, ccw_line);
     }
+    #[test]
+    fn winding_order_degenerate() {
+        // too few distinct points
+        assert_eq!(LineString::<f64>(vec![]).winding_order(), None);
+        assert_eq!(
+            LineString::from(vec![(0., 0.), (1., 1.), (0., 0.)]).winding_order(),
+            None
+        );
+        // all points collinear
+        assert_eq!(
+            LineString::from(vec![(0., 0.), (1., 1.), (2., 2.), (0., 0.)]).winding_order(),
+            None
+        );
+    }
+    #[test]
+    fn winding_order_nearly_degenerate() {
+        // a very thin sliver, whose apex is a single ulp above the line y = x
+        let apex = 12. + 2f64.powi(-49);
+        let ccw_line = LineString::from(vec![(0.5, 0.5), (24., 24.), (12., apex), (0.5, 0.5)]);
+        assert_eq!(ccw_line.winding_order(), Some(WindingOrder::CounterClockwise));
+        let mut cw_line = ccw_line.clone();
+        cw_line.0.reverse();
+        assert_eq!(cw_line.winding_order(), Some(WindingOrder::Clockwise));
+    }
 }
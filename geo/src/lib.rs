@@ -9,6 +9,8 @@ extern crate failure;
 extern crate postgis;
 #[cfg(feature = "use-proj")]
 extern crate proj;
+#[cfg(feature = "use-rayon")]
+extern crate rayon;
 extern crate spade;
 
 pub use algorithm::*;
@@ -48,27 +50,44 @@ pub mod prelude {
     pub use algorithm::area::Area;
     pub use algorithm::bearing::Bearing;
     pub use algorithm::boundingbox::BoundingBox;
+    pub use algorithm::catmull_rom::CatmullRom;
     pub use algorithm::centroid::Centroid;
+    pub use algorithm::chaikin_smoothing::ChaikinSmoothing;
+    pub use algorithm::clip_to_rect::ClipToRect;
     pub use algorithm::closest_point::ClosestPoint;
     pub use algorithm::contains::Contains;
     pub use algorithm::convexhull::ConvexHull;
+    pub use algorithm::coverage_simplify::CoverageSimplify;
+    pub use algorithm::densify::{Densify, DensifyHaversine};
     pub use algorithm::euclidean_distance::EuclideanDistance;
     pub use algorithm::euclidean_length::EuclideanLength;
     pub use algorithm::extremes::ExtremePoints;
+    pub use algorithm::frechet_distance::FrechetDistance;
     #[cfg(feature = "postgis-integration")]
     pub use algorithm::from_postgis::FromPostgis;
+    pub use algorithm::geohash::GeohashCover;
+    pub use algorithm::hausdorff_distance::HausdorffDistance;
     pub use algorithm::haversine_destination::HaversineDestination;
     pub use algorithm::haversine_distance::HaversineDistance;
     pub use algorithm::haversine_intermediate::HaversineIntermediate;
     pub use algorithm::haversine_length::HaversineLength;
+    pub use algorithm::interior_point::InteriorPoint;
     pub use algorithm::intersects::Intersects;
+    pub use algorithm::is_simple::IsSimple;
+    pub use algorithm::line_merge::LineMerge;
     pub use algorithm::map_coords::MapCoords;
+    pub use algorithm::minimum_enclosing_circle::MinimumEnclosingCircle;
+    pub use algorithm::minimum_rotated_rect::MinimumRotatedRect;
     pub use algorithm::orient::Orient;
+    pub use algorithm::polygonize::Polygonize;
+    pub use algorithm::polylabel::Polylabel;
     #[cfg(feature = "use-proj")]
     pub use algorithm::proj::Proj;
     pub use algorithm::rotate::{Rotate, RotatePoint};
-    pub use algorithm::simplify::Simplify;
-    pub use algorithm::simplifyvw::SimplifyVW;
+    pub use algorithm::shortest_line::ShortestLine;
+    pub use algorithm::simplify::{Simplify, SimplifyPreserve};
+    pub use algorithm::simplifyvw::{SimplifyVW, SimplifyVWToCount, VWEffectiveAreas};
+    pub use algorithm::tile::TileCover;
     #[cfg(feature = "postgis-integration")]
     pub use algorithm::to_postgis::ToPostgis;
     pub use algorithm::translate::Translate;
@@ -2,7 +2,7 @@ use std::ops::Add;
 use std::ops::AddAssign;
 
 use num_traits::{Float, ToPrimitive};
-use {CoordinateType, Point};
+use {CoordinateType, LineString, Point, Polygon};
 
 pub static COORD_PRECISION: f32 = 1e-1; // 0.1m
 
@@ -19,6 +19,25 @@ where
     pub ymax: T,
 }
 
+impl<T> Bbox<T>
+where
+    T: CoordinateType,
+{
+    /// The rectangle covered by the bounding box, as a `Polygon`
+    pub(crate) fn to_polygon(self) -> Polygon<T> {
+        Polygon::new(
+            LineString::from(vec![
+                (self.xmin, self.ymin),
+                (self.xmin, self.ymax),
+                (self.xmax, self.ymax),
+                (self.xmax, self.ymin),
+                (self.xmin, self.ymin),
+            ]),
+            vec![],
+        )
+    }
+}
+
 /// A container for indices of the minimum and maximum points of a [`Geometry`](enum.Geometry.html)
 #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -53,6 +72,17 @@ where
     pub xmin: Point<T>,
 }
 
+/// A circle, such as the minimum enclosing circle of a [`Geometry`](enum.Geometry.html)
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Circle<T>
+where
+    T: CoordinateType,
+{
+    pub center: Point<T>,
+    pub radius: T,
+}
+
 impl<T> Add for Bbox<T>
 where
     T: CoordinateType + ToPrimitive,